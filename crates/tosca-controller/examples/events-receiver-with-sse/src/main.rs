@@ -29,10 +29,9 @@ use tower_http::services::ServeDir;
 use tracing::{error, info};
 use tracing_subscriber::filter::LevelFilter;
 
-use tosca::events::Events;
-
 use tosca_controller::controller::Controller;
 use tosca_controller::discovery::{Discovery, TransportProtocol};
+use tosca_controller::events::EventPayload;
 
 const THROTTLE: Duration = Duration::from_secs(1);
 const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(1);
@@ -74,16 +73,13 @@ impl DevicesConsoles {
 #[derive(Clone)]
 struct AppState {
     devices_consoles: DevicesConsoles,
-    devices_receivers: Arc<HashMap<usize, Receiver<Events>>>,
+    devices_receivers: Arc<HashMap<String, Receiver<EventPayload>>>,
 }
 
 impl AppState {
-    fn new(devices_receivers: HashMap<usize, Receiver<Events>>) -> Self {
+    fn new(devices_receivers: HashMap<String, Receiver<EventPayload>>) -> Self {
         let devices_consoles = DevicesConsoles::new(
-            devices_receivers
-                .keys()
-                .map(std::string::ToString::to_string)
-                .collect(),
+            devices_receivers.keys().cloned().collect(),
         );
         Self {
             devices_consoles,
@@ -103,7 +99,7 @@ async fn index(State(state): State<AppState>) -> impl IntoResponse {
 }
 
 async fn event_stream(
-    Path(device_id): Path<usize>,
+    Path(device_id): Path<String>,
     State(state): State<AppState>,
 ) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ErrorResponse> {
     let receiver = state.devices_receivers.get(&device_id).ok_or_else(|| {
@@ -118,20 +114,20 @@ async fn event_stream(
 
     // Convert the stream into SSE events
     let sse_stream = stream
-        .filter_map(move |events| {
-            let events = match events {
-                Ok(events) => events,
+        .filter_map(move |payload| {
+            let payload = match payload {
+                Ok(payload) => payload,
                 Err(e) => {
                     error!("Failed to receive the events: {e}");
                     return None;
                 }
             };
 
-            info!("{events}");
+            info!("{payload}");
 
             Some(Ok(Event::default()
-                .id(device_id.to_string())
-                .data(format!("{events}"))))
+                .id(device_id.clone())
+                .data(format!("{}", payload.events))))
         })
         .throttle(THROTTLE);
 
@@ -209,13 +205,12 @@ async fn main() -> Result<(), Error> {
     }
 
     let mut devices_receivers = HashMap::new();
-    // FIXME: Using usize is an hack because IDs have not implemented yet.
-    for (id, device) in devices.iter_mut().enumerate() {
+    for device in devices.iter_mut() {
         let receiver = device
-            .start_event_receiver(id, 100)
+            .start_event_receiver(100)
             .await
             .map_err(Error::Tosca)?;
-        devices_receivers.insert(id, receiver);
+        devices_receivers.insert(device.id().to_string(), receiver);
     }
 
     let state = AppState::new(devices_receivers);
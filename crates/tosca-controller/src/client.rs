@@ -0,0 +1,247 @@
+use std::time::Duration;
+
+use tosca::encoding::Encoding;
+use tosca::route::RestKind;
+
+use reqwest::{Client, RequestBuilder, Response, header::ACCEPT};
+
+use tokio::time::sleep;
+
+use tracing::{error, warn};
+
+// Default timeout to establish a connection with a device.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+// Default timeout to receive a complete response from a device.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+// Default number of retries for an idempotent request.
+const RETRIES: u8 = 2;
+// Default waiting time before the first retry.
+const RETRY_BACKOFF: Duration = Duration::from_millis(200);
+
+/// Configuration of the `HTTP` client shared by all controller requests.
+///
+/// Connections towards a device are reused across requests, while
+/// idempotent requests are retried with an exponential backoff when a
+/// device cannot be reached.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClientConfig {
+    connect_timeout: Duration,
+    request_timeout: Duration,
+    retries: u8,
+    retry_backoff: Duration,
+    encoding: Encoding,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClientConfig {
+    /// Creates a [`ClientConfig`] with default values.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            connect_timeout: CONNECT_TIMEOUT,
+            request_timeout: REQUEST_TIMEOUT,
+            retries: RETRIES,
+            retry_backoff: RETRY_BACKOFF,
+            encoding: Encoding::Json,
+        }
+    }
+
+    /// Sets the timeout to establish a connection with a device.
+    #[must_use]
+    pub const fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Sets the timeout to receive a complete response from a device.
+    ///
+    /// Byte stream responses are not subject to this timeout.
+    #[must_use]
+    pub const fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Sets the number of retries for an idempotent request.
+    ///
+    /// When `0`, requests are never retried.
+    #[must_use]
+    pub const fn retries(mut self, retries: u8) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets the waiting time before the first retry.
+    ///
+    /// The waiting time doubles at each subsequent retry.
+    #[must_use]
+    pub const fn retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Sets the preferred encoding of device responses.
+    ///
+    /// Responses are encoded in `JSON` by default, while the compact `CBOR`
+    /// encoding must be explicitly preferred. Devices which do not support
+    /// the preferred encoding respond in `JSON`, which is always accepted.
+    #[must_use]
+    pub const fn encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    // Returns the `Accept` header value of the requests.
+    const fn accept(&self) -> &'static str {
+        match self.encoding {
+            Encoding::Json => "application/json",
+            Encoding::Cbor => "application/cbor, application/json;q=0.9",
+        }
+    }
+}
+
+// An `HTTP` client shared among all requests.
+//
+// Cloning it is cheap, since the connection pool is shared.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpClient {
+    client: Client,
+    config: ClientConfig,
+}
+
+impl PartialEq for HttpClient {
+    fn eq(&self, other: &Self) -> bool {
+        self.config == other.config
+    }
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self::new(ClientConfig::new())
+    }
+}
+
+impl HttpClient {
+    pub(crate) fn new(config: ClientConfig) -> Self {
+        let client = Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .unwrap_or_else(|e| {
+                error!("Impossible to configure the HTTP client, using the default one: {e}");
+                Client::new()
+            });
+
+        Self { client, config }
+    }
+
+    // Builds a request, applying the request timeout when asked.
+    //
+    // The request declares the response encodings accepted by the controller.
+    pub(crate) fn request(&self, kind: RestKind, url: &str, with_timeout: bool) -> RequestBuilder {
+        let request_builder = match kind {
+            RestKind::Get => self.client.get(url),
+            RestKind::Post => self.client.post(url),
+            RestKind::Put => self.client.put(url),
+            RestKind::Delete => self.client.delete(url),
+        }
+        .header(ACCEPT, self.config.accept());
+
+        if with_timeout {
+            request_builder.timeout(self.config.request_timeout)
+        } else {
+            request_builder
+        }
+    }
+
+    // Sends the request produced by the builder function.
+    //
+    // Idempotent requests are retried with an exponential backoff when
+    // the connection fails or times out.
+    pub(crate) async fn send<F>(&self, build: F, kind: RestKind) -> reqwest::Result<Response>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        // `POST` is the only non-idempotent method.
+        let retries = if kind == RestKind::Post {
+            0
+        } else {
+            self.config.retries
+        };
+
+        let mut backoff = self.config.retry_backoff;
+        let mut attempt = 0;
+        loop {
+            match build().send().await {
+                Err(e) if attempt < retries && (e.is_connect() || e.is_timeout()) => {
+                    attempt += 1;
+                    warn!("Attempt {attempt} failed, retrying in {backoff:?}: {e}");
+                    sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use tosca::encoding::Encoding;
+    use tosca::route::RestKind;
+
+    use super::{ClientConfig, HttpClient};
+
+    #[test]
+    fn client_config() {
+        let config = ClientConfig::new()
+            .connect_timeout(Duration::from_secs(1))
+            .request_timeout(Duration::from_secs(2))
+            .retries(5)
+            .retry_backoff(Duration::from_millis(10))
+            .encoding(Encoding::Cbor);
+
+        assert_eq!(config.connect_timeout, Duration::from_secs(1));
+        assert_eq!(config.request_timeout, Duration::from_secs(2));
+        assert_eq!(config.retries, 5);
+        assert_eq!(config.retry_backoff, Duration::from_millis(10));
+        assert_eq!(config.accept(), "application/cbor, application/json;q=0.9");
+        assert_eq!(ClientConfig::default(), ClientConfig::new());
+        assert_eq!(ClientConfig::new().accept(), "application/json");
+    }
+
+    #[tokio::test]
+    async fn retry_idempotent_requests() {
+        let backoff = Duration::from_millis(50);
+        let client = HttpClient::new(ClientConfig::new().retries(2).retry_backoff(backoff));
+
+        // No device listening on this port.
+        let url = "http://127.0.0.1:1";
+
+        // Two retries: 50ms + 100ms.
+        let start = Instant::now();
+        assert!(
+            client
+                .send(|| client.request(RestKind::Get, url, true), RestKind::Get)
+                .await
+                .is_err()
+        );
+        assert!(start.elapsed() >= backoff * 3);
+
+        // A `POST` request is never retried.
+        let start = Instant::now();
+        assert!(
+            client
+                .send(|| client.request(RestKind::Post, url, true), RestKind::Post)
+                .await
+                .is_err()
+        );
+        assert!(start.elapsed() < backoff);
+    }
+}
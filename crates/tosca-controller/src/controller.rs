@@ -1,21 +1,24 @@
 use std::borrow::Cow;
 
 use tosca::parameters::ParametersValues;
+use tosca::route::MandatoryRoute;
 
 use tokio::sync::mpsc::{self, Receiver};
+use tokio::task::JoinHandle;
+
+use tokio_util::sync::CancellationToken;
 
 use tracing::{error, warn};
 
-use crate::device::{Device, Devices};
-use crate::discovery::Discovery;
+use crate::client::{ClientConfig, HttpClient};
+use crate::device::{Device, DeviceId, Devices};
+use crate::discovery::{Discovery, DiscoveryEvent, DiscoveryUpdate, obtain_device_from_address};
 use crate::error::{Error, ErrorKind};
 use crate::events::{EventPayload, EventsRunner};
 use crate::policy::Policy;
 use crate::request::Request;
 use crate::response::Response;
 
-// TODO: Use the MAC address as id.
-
 fn sender_error(error: impl Into<Cow<'static, str>>) -> Error {
     Error::new(ErrorKind::Sender, error)
 }
@@ -24,6 +27,7 @@ fn sender_error(error: impl Into<Cow<'static, str>>) -> Error {
 #[derive(Debug, PartialEq)]
 pub struct RequestSender<'controller> {
     controller: &'controller Controller,
+    device: &'controller Device,
     request: &'controller Request,
     skip: bool,
 }
@@ -37,15 +41,25 @@ impl RequestSender<'_> {
     /// and affect the returned response as well.
     pub async fn send(&self) -> Result<Response, Error> {
         self.request
-            .retrieve_response(self.skip, || async { self.request.plain_send().await })
+            .retrieve_response(self.skip, || async {
+                self.request
+                    .plain_send(&self.controller.client, self.device)
+                    .await
+            })
             .await
     }
 
     /// Sends a request to a device with the given [`ParametersValues`]
     /// and returns a [`Response`].
     ///
+    /// Missing parameters are filled with their declared default values.
+    ///
     /// # Errors
     ///
+    /// An [`ErrorKind::InvalidParameter`] error is returned, without sending
+    /// the request, when a parameter does not exist, has a wrong type, or
+    /// violates its limits or step.
+    ///
     /// Network failures or timeouts may prevent the request from being sent
     /// and affect the returned response as well.
     pub async fn send_with_parameters(
@@ -59,7 +73,9 @@ impl RequestSender<'_> {
 
         self.request
             .retrieve_response(self.skip, || async {
-                self.request.create_response(parameters).await
+                self.request
+                    .create_response(&self.controller.client, self.device, parameters)
+                    .await
             })
             .await
     }
@@ -72,7 +88,6 @@ impl RequestSender<'_> {
 pub struct DeviceSender<'controller> {
     controller: &'controller Controller,
     device: &'controller Device,
-    id: usize,
 }
 
 impl DeviceSender<'_> {
@@ -91,17 +106,52 @@ impl DeviceSender<'_> {
             ))
         })?;
 
+        Ok(self.request_sender(request, route))
+    }
+
+    /// Builds a [`RequestSender`] for the given [`MandatoryRoute`].
+    ///
+    /// It allows to operate a device of a standard kind without knowing
+    /// its routes in advance.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the device **does** not implement the given
+    /// mandatory route.
+    pub fn mandatory_request(
+        &self,
+        mandatory_route: &MandatoryRoute,
+    ) -> Result<RequestSender<'_>, Error> {
+        let route = mandatory_route.path();
+        let request = self
+            .device
+            .mandatory_request(mandatory_route)
+            .ok_or_else(|| {
+                sender_error(format!(
+                    "Error in retrieving the mandatory request with route `{route}`."
+                ))
+            })?;
+
+        Ok(self.request_sender(request, route))
+    }
+
+    fn request_sender<'sender>(
+        &'sender self,
+        request: &'sender Request,
+        route: &str,
+    ) -> RequestSender<'sender> {
         let skip = if request.hazards.is_empty() {
             false
         } else {
             self.evaluate_privacy_policy(request, route)
         };
 
-        Ok(RequestSender {
+        RequestSender {
             controller: self.controller,
+            device: self.device,
             request,
             skip,
-        })
+        }
     }
 
     fn evaluate_privacy_policy(&self, request: &Request, route: &str) -> bool {
@@ -115,7 +165,7 @@ impl DeviceSender<'_> {
         let local_blocked_hazards = self
             .controller
             .privacy_policy
-            .local_blocked_hazards(self.id, &request.hazards);
+            .local_blocked_hazards(self.device.id(), &request.hazards);
 
         if !global_blocked_hazards.is_empty() {
             warn!(
@@ -133,24 +183,77 @@ impl DeviceSender<'_> {
             skip = true;
         }
 
+        if let Some(severity) = self
+            .controller
+            .privacy_policy
+            .exceeded_severity(&request.hazards, &request.risks)
+        {
+            warn!("The {route} is skipped because its hazards have a {severity} severity");
+            skip = true;
+        }
+
+        if let Some(risk_score) = self
+            .controller
+            .privacy_policy
+            .exceeded_risk_score(&request.hazards, &request.risks)
+        {
+            warn!("The {route} is skipped because its hazards have a risk score of {risk_score}");
+            skip = true;
+        }
+
         skip
     }
 }
 
+// A discovery task running in background.
+#[derive(Debug)]
+struct ContinuousDiscovery {
+    // Receiver of the discovery updates.
+    receiver: Receiver<DiscoveryUpdate>,
+    // The token used to cancel the discovery task.
+    cancellation_token: CancellationToken,
+    // The join handle for the discovery task.
+    handle: JoinHandle<()>,
+}
+
+impl ContinuousDiscovery {
+    async fn stop(self) {
+        // Stop the infinite loop
+        self.cancellation_token.cancel();
+
+        // Await the task.
+        if let Err(e) = self.handle.await {
+            error!("Failed to await the discovery task: {e}");
+        }
+    }
+}
+
 /// A controller for interacting with `tosca` devices.
 ///
 /// The main functionalities include:
 ///
-/// - Discovering `tosca` devices on the network and registering them in memory.
+/// - Discovering `tosca` devices on the network and registering them in memory,
+///   either once or continuously in background.
 /// - Sending requests to a specific device identified by its ID, awaiting a
 ///   response, and forwarding it directly to the caller.
 /// - Controlling request sending by allowing or blocking requests based on the
 ///   defined privacy policy.
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct Controller {
     discovery: Discovery,
     devices: Devices,
     privacy_policy: Policy,
+    client: HttpClient,
+    continuous_discovery: Option<ContinuousDiscovery>,
+}
+
+impl PartialEq for Controller {
+    fn eq(&self, other: &Self) -> bool {
+        self.discovery == other.discovery
+            && self.devices == other.devices
+            && self.privacy_policy == other.privacy_policy
+            && self.client == other.client
+    }
 }
 
 impl Controller {
@@ -162,6 +265,8 @@ impl Controller {
             discovery,
             devices: Devices::new(),
             privacy_policy: Policy::init(),
+            client: HttpClient::default(),
+            continuous_discovery: None,
         }
     }
 
@@ -176,9 +281,20 @@ impl Controller {
             discovery,
             devices,
             privacy_policy: Policy::init(),
+            client: HttpClient::default(),
+            continuous_discovery: None,
         }
     }
 
+    /// Defines a [`ClientConfig`] for the `HTTP` client shared by all
+    /// requests while constructing a [`Controller`].
+    #[must_use]
+    #[inline]
+    pub fn client_config(mut self, client_config: ClientConfig) -> Self {
+        self.client = HttpClient::new(client_config);
+        self
+    }
+
     /// Defines a [`Policy`] while constructing a [`Controller`].
     #[must_use]
     #[inline]
@@ -212,10 +328,122 @@ impl Controller {
     /// being sent and affect the returned response as well.
     #[inline]
     pub async fn discover(&mut self) -> Result<(), Error> {
-        self.devices = self.discovery.discover().await?;
+        self.devices = self.discovery.discover(&self.client).await?;
         Ok(())
     }
 
+    /// Adds the [`Device`] reachable at the given address, returning
+    /// its identifier.
+    ///
+    /// The address must be in the `scheme://host:port` form. Unlike
+    /// [`Self::discover`], no multicast traffic is involved, so devices can be
+    /// registered even on networks where mDNS is blocked.
+    ///
+    /// # Errors
+    ///
+    /// - The address is not valid
+    /// - The device is unreachable or its data cannot be parsed
+    /// - A device with the same identifier has already been added
+    pub async fn add_device(&mut self, address: &str) -> Result<DeviceId, Error> {
+        let device = obtain_device_from_address(&self.client, address).await?;
+        let id = device.id().clone();
+
+        if self.devices.get_by_id(&id).is_some() {
+            return Err(Error::new(
+                ErrorKind::Discovery,
+                format!("The device with identifier {id} has already been added"),
+            ));
+        }
+
+        self.devices.add(device);
+
+        Ok(id)
+    }
+
+    /// Adds the [`Device`]s reachable at the given static addresses.
+    ///
+    /// Each address is handled as in [`Self::add_device`], and the outcomes
+    /// are returned in the same order of the addresses, so an unreachable
+    /// device does not prevent the others from being added.
+    pub async fn add_devices<I, S>(&mut self, addresses: I) -> Vec<Result<DeviceId, Error>>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut outcomes = Vec::new();
+        for address in addresses {
+            outcomes.push(self.add_device(address.as_ref()).await);
+        }
+        outcomes
+    }
+
+    /// Starts a continuous discovery of [`Devices`] in background.
+    ///
+    /// Unlike [`Self::discover`], the network is browsed until
+    /// [`Self::stop_continuous_discovery`] is called, so devices powered up
+    /// or removed after startup are detected as well.
+    ///
+    /// The `buffer_size` parameter specifies how many discovery updates
+    /// the internal buffer can hold before [`Self::next_discovery_event`]
+    /// consumes them.
+    ///
+    /// # Errors
+    ///
+    /// - The continuous discovery has already been started
+    /// - Inability to connect to the network
+    /// - Failure to disable a particular network interface
+    pub fn start_continuous_discovery(&mut self, buffer_size: usize) -> Result<(), Error> {
+        if self.continuous_discovery.is_some() {
+            return Err(Error::new(
+                ErrorKind::Discovery,
+                "Continuous discovery already started",
+            ));
+        }
+
+        let (tx, rx) = mpsc::channel(buffer_size);
+        let cancellation_token = CancellationToken::new();
+
+        let handle = self.discovery.discover_continuously(
+            self.client.clone(),
+            tx,
+            cancellation_token.clone(),
+        )?;
+
+        self.continuous_discovery = Some(ContinuousDiscovery {
+            receiver: rx,
+            cancellation_token,
+            handle,
+        });
+
+        Ok(())
+    }
+
+    /// Waits for the next [`DiscoveryEvent`] produced by the continuous
+    /// discovery, updating [`Devices`] accordingly.
+    ///
+    /// The event receiver of a changed or removed [`Device`] is stopped.
+    ///
+    /// Returns [`None`] when the continuous discovery is not running or
+    /// has terminated.
+    pub async fn next_discovery_event(&mut self) -> Option<DiscoveryEvent> {
+        loop {
+            let update = self.continuous_discovery.as_mut()?.receiver.recv().await?;
+
+            if let Some(event) = self.update_devices(update) {
+                return Some(event);
+            }
+        }
+    }
+
+    /// Stops the continuous discovery.
+    ///
+    /// Already discovered [`Devices`] are preserved.
+    pub async fn stop_continuous_discovery(&mut self) {
+        if let Some(continuous_discovery) = self.continuous_discovery.take() {
+            continuous_discovery.stop().await;
+        }
+    }
+
     /// Starts asynchronous event receiver tasks for all [`Device`]s that
     /// support events.
     ///
@@ -243,7 +471,9 @@ impl Controller {
         let (tx, rx) = mpsc::channel(buffer_size);
 
         let mut started_count = 0;
-        for (id, device) in self.devices.iter_mut().enumerate() {
+        for device in &mut self.devices {
+            let id = device.id();
+
             if device.event_handle.is_some() {
                 warn!("Skip device with id `{id}`: event receiver already started");
                 continue;
@@ -254,7 +484,7 @@ impl Controller {
                 continue;
             };
 
-            EventsRunner::run_global_subscriber(events, id, tx.clone()).await?;
+            EventsRunner::run_global_subscriber(events, id.clone(), tx.clone()).await?;
 
             started_count += 1;
         }
@@ -285,14 +515,14 @@ impl Controller {
     ///
     /// # Errors
     ///
-    /// An error is returned if no devices are found or if the given index
-    /// **does** not exist.
-    pub fn device(&self, id: usize) -> Result<DeviceSender<'_>, Error> {
+    /// An error is returned if no devices are found or if the given
+    /// identifier **does** not exist.
+    pub fn device(&self, id: &DeviceId) -> Result<DeviceSender<'_>, Error> {
         if self.devices.is_empty() {
             return Err(sender_error("No devices found."));
         }
 
-        let device = self.devices.get(id).ok_or_else(|| {
+        let device = self.devices.get_by_id(id).ok_or_else(|| {
             sender_error(format!(
                 "Error in retrieving the device with identifier {id}."
             ))
@@ -300,10 +530,29 @@ impl Controller {
         Ok(DeviceSender {
             controller: self,
             device,
-            id,
         })
     }
 
+    /// Builds a [`DeviceSender`] for the [`Device`] with the given identifier,
+    /// revalidating its reachability first when it has not been verified yet.
+    ///
+    /// This method is meant for devices restored from a
+    /// [`crate::registry::Registry`], which are not contacted when loaded.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the given identifier **does** not exist or
+    /// if the device cannot be reached on any of its addresses.
+    pub async fn verified_device(&mut self, id: &DeviceId) -> Result<DeviceSender<'_>, Error> {
+        if let Some(device) = self.devices.get_mut_by_id(id)
+            && !device.is_verified()
+        {
+            device.revalidate(&self.client).await?;
+        }
+
+        self.device(id)
+    }
+
     /// Shuts down the [`Controller`], stopping all asynchronous tasks and
     /// releasing all associated resources.
     ///
@@ -312,6 +561,11 @@ impl Controller {
     /// For a graceful shutdown, this method must be called before dropping
     /// the [`Controller`].
     pub async fn shutdown(self) {
+        // Stop the continuous discovery task.
+        if let Some(continuous_discovery) = self.continuous_discovery {
+            continuous_discovery.stop().await;
+        }
+
         // Stop all events tasks.
         for device in self.devices {
             if let Some(events) = device.events {
@@ -327,6 +581,33 @@ impl Controller {
             }
         }
     }
+
+    fn update_devices(&mut self, update: DiscoveryUpdate) -> Option<DiscoveryEvent> {
+        match update {
+            DiscoveryUpdate::Resolved(device) => {
+                let id = device.id().clone();
+                if let Some(known_device) =
+                    self.devices.get_mut_by_name(&device.network_info().name)
+                {
+                    // mDNS may resolve a device more times, so notify
+                    // only actual changes.
+                    if *known_device == *device {
+                        return None;
+                    }
+                    std::mem::replace(known_device, *device).stop_event_receiver();
+                    Some(DiscoveryEvent::DeviceChanged(id))
+                } else {
+                    self.devices.add(*device);
+                    Some(DiscoveryEvent::DeviceAdded(id))
+                }
+            }
+            DiscoveryUpdate::Removed(name) => {
+                let device = self.devices.remove_by_name(&name)?;
+                device.stop_event_receiver();
+                Some(DiscoveryEvent::DeviceRemoved(device.id().clone()))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -338,13 +619,16 @@ mod tests {
     use tosca::hazards::{Hazard, Hazards};
     use tosca::parameters::ParametersValues;
     use tosca::response::{OkResponse, SerialResponse};
+    use tosca::route::{LightOnRoute, LockRoute};
 
     use serde::{Serialize, de::DeserializeOwned};
     use serde_json::json;
 
     use serial_test::serial;
 
-    use crate::device::Devices;
+    use crate::client::HttpClient;
+    use crate::device::{DeviceId, Devices};
+    use crate::discovery::{DiscoveryEvent, DiscoveryUpdate};
     use crate::error::Error;
     use crate::policy::Policy;
     use crate::response::Response;
@@ -365,11 +649,16 @@ mod tests {
                 discovery: configure_discovery(),
                 devices: Devices::new(),
                 privacy_policy: Policy::init(),
+                client: HttpClient::default(),
+                continuous_discovery: None,
             }
         );
 
         // No devices.
-        assert_eq!(controller.device(0), Err(sender_error("No devices found.")));
+        assert_eq!(
+            controller.device(&DeviceId::name("device")),
+            Err(sender_error("No devices found."))
+        );
     }
 
     #[test]
@@ -384,10 +673,57 @@ mod tests {
                 discovery: configure_discovery(),
                 devices: Devices::from_devices(vec![create_light(), create_unknown()]),
                 privacy_policy: Policy::init(),
+                client: HttpClient::default(),
+                continuous_discovery: None,
             }
         );
     }
 
+    #[test]
+    fn update_devices() {
+        let mut controller = Controller::new(configure_discovery());
+
+        let light = create_light();
+        let id = light.id().clone();
+        let name = light.network_info().name.clone();
+
+        // A new device is added.
+        assert_eq!(
+            controller.update_devices(DiscoveryUpdate::Resolved(Box::new(light))),
+            Some(DiscoveryEvent::DeviceAdded(id.clone()))
+        );
+        assert_eq!(controller.devices().len(), 1);
+
+        // The same device resolved again does not produce any event.
+        assert_eq!(
+            controller.update_devices(DiscoveryUpdate::Resolved(Box::new(create_light()))),
+            None
+        );
+
+        // A device with the same name, but different data, changes.
+        // Since it listens on another port, its identifier changes too.
+        let changed_id = create_unknown().id().clone();
+        assert_ne!(changed_id, id);
+        assert_eq!(
+            controller.update_devices(DiscoveryUpdate::Resolved(Box::new(create_unknown()))),
+            Some(DiscoveryEvent::DeviceChanged(changed_id.clone()))
+        );
+        assert_eq!(controller.devices().get(0), Some(&create_unknown()));
+
+        // An unknown device cannot be removed.
+        assert_eq!(
+            controller.update_devices(DiscoveryUpdate::Removed("unknown".into())),
+            None
+        );
+
+        // The device is removed.
+        assert_eq!(
+            controller.update_devices(DiscoveryUpdate::Removed(name)),
+            Some(DiscoveryEvent::DeviceRemoved(changed_id))
+        );
+        assert!(controller.devices().is_empty());
+    }
+
     async fn check_ok_response_plain(device_sender: &DeviceSender<'_>, route: &str) {
         check_ok_response(device_sender, route, async move |request_sender| {
             request_sender.send().await
@@ -486,14 +822,15 @@ mod tests {
     async fn controller_checks(controller: Controller) {
         // Wrong device id.
         assert_eq!(
-            controller.device(1),
+            controller.device(&DeviceId::name("wrong")),
             Err(sender_error(
-                "Error in retrieving the device with identifier 1."
+                "Error in retrieving the device with identifier wrong."
             ))
         );
 
         // Get device.
-        let device_sender = controller.device(0).unwrap();
+        let id = controller.devices().get(0).unwrap().id().clone();
+        let device_sender = controller.device(&id).unwrap();
 
         // Wrong request.
         assert_eq!(
@@ -503,6 +840,20 @@ mod tests {
             ))
         );
 
+        // Wrong mandatory request.
+        assert_eq!(
+            device_sender.mandatory_request(&LockRoute::MANDATORY),
+            Err(sender_error(
+                "Error in retrieving the mandatory request with route `/lock`."
+            ))
+        );
+
+        // A mandatory request is the request of its route.
+        assert_eq!(
+            device_sender.mandatory_request(&LightOnRoute::MANDATORY),
+            device_sender.request("/on")
+        );
+
         // Run "/on" request and get "Ok" response.
         check_ok_response_plain(&device_sender, "/on").await;
 
@@ -559,15 +910,18 @@ mod tests {
         // Local blocked hazards for a specific device.
         let local_hazards = Hazards::new().insert(Hazard::FireHazard);
 
-        // Create both a global policy and a local one.
-        let policy = Policy::new(global_hazards).block_device_on_hazards(0, local_hazards);
-
         // Create a controller.
-        let mut controller = Controller::new(configure_discovery()).policy(policy);
+        let mut controller = Controller::new(configure_discovery());
 
         // Run discovery process.
         controller.discover().await.unwrap();
 
+        // Create both a global policy and a local one for the
+        // discovered device.
+        let id = controller.devices().get(0).unwrap().id().clone();
+        controller
+            .change_policy(Policy::new(global_hazards).block_device_on_hazards(id, local_hazards));
+
         // Run controller checks.
         controller_checks(controller).await;
     }
@@ -591,6 +945,28 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn add_invalid_devices() {
+        let mut controller = Controller::new(configure_discovery());
+
+        let outcomes = controller
+            .add_devices([
+                // Not an address.
+                "light",
+                // Missing host.
+                "unix:/run/light",
+                // No device listening on this port.
+                "http://127.0.0.1:1",
+            ])
+            .await;
+
+        assert_eq!(outcomes.len(), 3);
+        assert!(outcomes.iter().all(Result::is_err));
+
+        // No devices have been added.
+        assert!(controller.devices().is_empty());
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     #[serial]
     async fn test_without_policy_controller() {
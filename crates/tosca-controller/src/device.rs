@@ -1,17 +1,23 @@
 use std::collections::{HashMap, HashSet};
 use std::net::IpAddr;
+use std::sync::{PoisonError, RwLock};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use tokio::sync::broadcast::{self, Receiver};
 use tokio::task::JoinHandle;
 
-use tosca::device::{DeviceEnvironment, DeviceKindId};
-use tosca::events::{Events as ToscaEvents, EventsDescription};
-use tosca::route::RouteConfigs;
+use tosca::device::{
+    Capabilities, Capability, DeviceEnvironment, DeviceIdentity, DeviceKind, DeviceKindId,
+    PROTOCOL_VERSION, ProtocolVersion,
+};
+use tosca::events::EventsDescription;
+use tosca::route::{MandatoryRoute, RouteConfigs};
 
+use crate::client::HttpClient;
+use crate::discovery::{create_device, retrieve_device_data};
 use crate::error::{Error, ErrorKind, Result};
-use crate::events::{Events, EventsRunner};
+use crate::events::{EventPayload, Events, EventsRunner};
 use crate::request::{Request, RequestInfo, create_requests};
 
 pub(crate) fn build_device_address(scheme: &str, address: &IpAddr, port: u16) -> String {
@@ -22,7 +28,7 @@ pub(crate) fn build_device_address(scheme: &str, address: &IpAddr, port: u16) ->
 ///
 /// It contains all the necessary data to contact a `tosca` device within
 /// a network.
-#[derive(Debug, PartialEq, Clone, Serialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct NetworkInformation {
     /// Full device name.
     pub name: String,
@@ -31,12 +37,12 @@ pub struct NetworkInformation {
     /// Device Wi-Fi MAC address.
     ///
     /// If [`None`], the Wi-Fi MAC address is not present.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wifi_mac: Option<[u8; 6]>,
     /// Device Ethernet MAC address.
     ///
     /// If [`None`], the Ethernet MAC address is not present.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ethernet_mac: Option<[u8; 6]>,
     /// The port on which the device is listening.
     pub port: u16,
@@ -84,10 +90,76 @@ impl NetworkInformation {
     }
 }
 
+/// A device identifier.
+///
+/// The identifier is derived from the Wi-Fi MAC address of a device or,
+/// when absent, from its Ethernet MAC address. If a device has no MAC
+/// addresses, its full name is used instead.
+///
+/// Devices running on the same machine share their MAC addresses, so a MAC
+/// address is always paired with the port on which the device is listening,
+/// which is unique on a machine.
+///
+/// Since the identifier does not depend on the position of a device within
+/// [`Devices`], it remains stable across discoveries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum DeviceId {
+    /// An identifier derived from a MAC address and a port.
+    Mac {
+        /// MAC address.
+        mac: [u8; 6],
+        /// The port on which the device is listening.
+        port: u16,
+    },
+    /// An identifier derived from the full device name.
+    Name(String),
+}
+
+impl std::fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mac { mac, port } => write!(
+                f,
+                "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}@{port}",
+                mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
+            ),
+            Self::Name(name) => name.fmt(f),
+        }
+    }
+}
+
+impl From<&NetworkInformation> for DeviceId {
+    fn from(network_info: &NetworkInformation) -> Self {
+        network_info
+            .wifi_mac
+            .or(network_info.ethernet_mac)
+            .map_or_else(
+                || Self::Name(network_info.name.clone()),
+                |mac| Self::mac(mac, network_info.port),
+            )
+    }
+}
+
+impl DeviceId {
+    /// Creates a [`DeviceId`] from a MAC address and the port on which
+    /// the device is listening.
+    #[must_use]
+    pub const fn mac(mac: [u8; 6], port: u16) -> Self {
+        Self::Mac { mac, port }
+    }
+
+    /// Creates a [`DeviceId`] from the full device name.
+    #[must_use]
+    #[inline]
+    pub fn name(name: impl Into<String>) -> Self {
+        Self::Name(name.into())
+    }
+}
+
 /// Device description.
 ///
 /// All properties defining a device.
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Description {
     /// Device kind.
     pub kind: DeviceKindId,
@@ -95,20 +167,31 @@ pub struct Description {
     pub environment: DeviceEnvironment,
     /// Device main route.
     pub main_route: String,
+    /// Protocol version implemented by the device.
+    #[serde(default)]
+    pub protocol_version: ProtocolVersion,
+    /// Device identity.
+    #[serde(default, skip_serializing_if = "DeviceIdentity::is_empty")]
+    pub identity: DeviceIdentity,
+    /// Device capabilities.
+    #[serde(default, skip_serializing_if = "Capabilities::is_empty")]
+    pub capabilities: Capabilities,
 }
 
 impl Description {
     /// Creates a [`Description`].
+    ///
+    /// The device is assumed to implement the same protocol version
+    /// of the controller.
     #[must_use]
-    pub const fn new(
-        kind: DeviceKindId,
-        environment: DeviceEnvironment,
-        main_route: String,
-    ) -> Self {
+    pub fn new(kind: DeviceKindId, environment: DeviceEnvironment, main_route: String) -> Self {
         Self {
             kind,
             environment,
             main_route,
+            protocol_version: PROTOCOL_VERSION,
+            identity: DeviceIdentity::new(),
+            capabilities: Capabilities::new(),
         }
     }
 }
@@ -116,10 +199,16 @@ impl Description {
 /// A `tosca` device.
 #[derive(Debug, Serialize)]
 pub struct Device {
+    // Device identifier.
+    #[serde(skip)]
+    id: DeviceId,
     // Information needed to contact a device in a network.
     network_info: NetworkInformation,
     // All data needed to describe a device.
     description: Description,
+    // All device route configurations.
+    #[serde(skip)]
+    pub(crate) route_configs: RouteConfigs,
     // All device requests.
     requests: HashMap<String, Request>,
     // All device events.
@@ -130,6 +219,14 @@ pub struct Device {
     // The join handle for the event task.
     #[serde(skip)]
     pub(crate) event_handle: Option<JoinHandle<()>>,
+    // Whether the device reachability has been verified.
+    #[serde(skip)]
+    pub(crate) verified: bool,
+    // The address on which the device has been reached the last time.
+    //
+    // It changes when a request fails over to another device address.
+    #[serde(skip)]
+    reachable_address: RwLock<String>,
 }
 
 impl PartialEq for Device {
@@ -146,30 +243,57 @@ impl Device {
     ///
     /// This method can be useful when creating a device from data stored
     /// in a database.
+    ///
+    /// The device is not contacted, so its reachability is unverified until
+    /// it is revalidated through
+    /// [`crate::controller::Controller::verified_device`].
     #[must_use]
     pub fn new(
         network_info: NetworkInformation,
         description: Description,
         route_configs: RouteConfigs,
     ) -> Self {
-        let requests = create_requests(
-            route_configs,
-            &network_info.last_reachable_address,
-            &description.main_route,
-            description.environment,
-        );
+        let mut device = Self::init(network_info, description, route_configs, None);
+        device.verified = false;
+        device
+    }
 
-        // TODO: Check if the last reachable address works or it is better to
-        // build a new one. Return a Result here, because we have to evaluate
-        // data validity.
+    // Revalidates the reachability of a device.
+    //
+    // The last reachable address is contacted first, then all the other
+    // device addresses. Once the device responds, its data is refreshed
+    // and its last reachable address updated.
+    //
+    // The event receiver of the device, if any, is stopped.
+    pub(crate) async fn revalidate(&mut self, client: &HttpClient) -> Result<()> {
+        let complete_addresses = self.candidate_addresses();
+
+        let unreachable_error = || {
+            Error::new(
+                ErrorKind::Request,
+                format!("The device with id `{}` is unreachable", self.id),
+            )
+        };
 
-        Self {
-            network_info,
-            description,
-            requests,
-            events: None,
-            event_handle: None,
-        }
+        let (complete_address, device_data) = retrieve_device_data(client, complete_addresses)
+            .await?
+            .ok_or_else(unreachable_error)?;
+
+        let mut network_info = self.network_info.clone();
+        network_info.last_reachable_address = complete_address;
+
+        let device = create_device(device_data, network_info).ok_or_else(unreachable_error)?;
+
+        self.stop_event_receiver();
+        *self = device;
+
+        Ok(())
+    }
+
+    /// Returns the [`DeviceId`].
+    #[must_use]
+    pub const fn id(&self) -> &DeviceId {
+        &self.id
     }
 
     /// Returns an immutable reference to [`NetworkInformation`].
@@ -178,6 +302,19 @@ impl Device {
         &self.network_info
     }
 
+    /// Returns the address on which the [`Device`] has been reached
+    /// the last time.
+    ///
+    /// It differs from [`NetworkInformation::last_reachable_address`] when
+    /// a request has failed over to another device address.
+    #[must_use]
+    pub fn reachable_address(&self) -> String {
+        self.reachable_address
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
     /// Returns an immutable reference to [`Description`].
     #[must_use]
     pub const fn description(&self) -> &Description {
@@ -220,6 +357,49 @@ impl Device {
         self.requests.get(route)
     }
 
+    /// Returns the [`Request`] which implements the given [`MandatoryRoute`].
+    ///
+    /// If [`None`], the device does not implement the mandatory route.
+    #[must_use]
+    #[inline]
+    pub fn mandatory_request(&self, mandatory_route: &MandatoryRoute) -> Option<&Request> {
+        self.requests
+            .get(mandatory_route.path())
+            .filter(|request| mandatory_route.rest_kinds().contains(&request.kind()))
+    }
+
+    /// Returns the standard [`DeviceKind`] of a [`Device`].
+    ///
+    /// If [`None`], the device kind is not standard, or the device does not
+    /// implement all the mandatory routes of its kind.
+    #[must_use]
+    pub fn standard_kind(&self) -> Option<DeviceKind> {
+        self.description
+            .kind
+            .standard_kind()
+            .filter(|kind| kind.routes().is_implemented_by(&self.route_configs))
+    }
+
+    /// Checks whether a [`Device`] supports the given [`Capability`].
+    ///
+    /// A capability is supported when the device declares it and
+    /// implements all the routes it requires.
+    #[must_use]
+    pub fn has_capability(&self, capability: Capability) -> bool {
+        self.description.capabilities.contains(&capability)
+            && capability.is_implemented_by(&self.route_configs)
+    }
+
+    /// Checks if the reachability of a [`Device`] has been verified.
+    ///
+    /// Discovered devices are always verified, while devices created
+    /// from stored data are verified only after
+    /// [`crate::controller::Controller::verified_device`].
+    #[must_use]
+    pub const fn is_verified(&self) -> bool {
+        self.verified
+    }
+
     /// Checks if a [`Device`] supports events.
     #[must_use]
     pub const fn has_events(&self) -> bool {
@@ -251,6 +431,9 @@ impl Device {
     /// When the returned [`Receiver`] is dropped, the event receiver task
     /// terminates automatically.
     ///
+    /// Each [`EventPayload`] reports the number of event frames dropped
+    /// since the previous one.
+    ///
     /// # Errors
     ///
     /// - The device does not support events
@@ -259,43 +442,97 @@ impl Device {
     #[inline]
     pub async fn start_event_receiver(
         &mut self,
-        id: usize,
         buffer_size: usize,
-    ) -> Result<Receiver<ToscaEvents>> {
+    ) -> Result<Receiver<EventPayload>> {
         if self.event_handle.is_some() {
             return Err(Error::new(
                 ErrorKind::Events,
-                format!("Event receiver already started for device with id `{id}`"),
+                format!(
+                    "Event receiver already started for device with id `{}`",
+                    self.id
+                ),
             ));
         }
 
         let Some(ref events) = self.events else {
             return Err(Error::new(
                 ErrorKind::Events,
-                format!("The device with `{id}` does not support events"),
+                format!("The device with id `{}` does not support events", self.id),
             ));
         };
 
         let (tx, _) = broadcast::channel(buffer_size);
 
-        let handle = EventsRunner::run_device_subscriber(events, id, tx.clone()).await?;
+        let handle =
+            EventsRunner::run_device_subscriber(events, self.id.clone(), tx.clone()).await?;
         self.event_handle = Some(handle);
 
         Ok(tx.subscribe())
     }
 
-    pub(crate) const fn init(
+    // Updates the address on which the device has been reached.
+    pub(crate) fn set_reachable_address(&self, address: String) {
+        *self
+            .reachable_address
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = address;
+    }
+
+    // Returns all the complete addresses of a device, starting from
+    // the one on which the device has been reached the last time.
+    pub(crate) fn candidate_addresses(&self) -> Vec<String> {
+        // If the scheme is not specified as a property,
+        // fall back to `http` as default.
+        let scheme = self
+            .network_info
+            .properties
+            .get("scheme")
+            .map_or("http", String::as_str);
+
+        let reachable_address = self.reachable_address();
+        let mut addresses = self
+            .network_info
+            .addresses
+            .iter()
+            .map(|address| build_device_address(scheme, address, self.network_info.port))
+            .filter(|address| *address != reachable_address)
+            .collect::<Vec<String>>();
+        // Sort addresses for a deterministic order.
+        addresses.sort();
+        addresses.insert(0, reachable_address);
+        addresses
+    }
+
+    // Stops the event receiver task, if any.
+    pub(crate) fn stop_event_receiver(&self) {
+        if let Some(ref events) = self.events {
+            events.cancellation_token.cancel();
+        }
+    }
+
+    pub(crate) fn init(
         network_info: NetworkInformation,
         description: Description,
-        requests: HashMap<String, Request>,
+        route_configs: RouteConfigs,
         events: Option<Events>,
     ) -> Self {
+        let requests = create_requests(
+            route_configs.clone(),
+            &network_info.last_reachable_address,
+            &description.main_route,
+            description.environment,
+        );
+
         Self {
+            id: DeviceId::from(&network_info),
+            reachable_address: RwLock::new(network_info.last_reachable_address.clone()),
             network_info,
             description,
+            route_configs,
             requests,
             events,
             event_handle: None,
+            verified: true,
         }
     }
 }
@@ -376,6 +613,43 @@ impl Devices {
         self.0.get(index)
     }
 
+    /// Retrieves a reference to the [`Device`] with the given [`DeviceId`].
+    #[must_use]
+    #[inline]
+    pub fn get_by_id(&self, id: &DeviceId) -> Option<&Device> {
+        self.0.iter().find(|device| &device.id == id)
+    }
+
+    /// Retrieves a mutable reference to the [`Device`] with the given
+    /// [`DeviceId`].
+    #[must_use]
+    #[inline]
+    pub fn get_mut_by_id(&mut self, id: &DeviceId) -> Option<&mut Device> {
+        self.0.iter_mut().find(|device| &device.id == id)
+    }
+
+    /// Returns an iterator over the [`Device`]s which support the given
+    /// [`Capability`], regardless of their kind.
+    #[inline]
+    pub fn with_capability(&self, capability: Capability) -> impl Iterator<Item = &Device> {
+        self.0
+            .iter()
+            .filter(move |device| device.has_capability(capability))
+    }
+
+    pub(crate) fn get_mut_by_name(&mut self, name: &str) -> Option<&mut Device> {
+        self.0
+            .iter_mut()
+            .find(|device| device.network_info.name == name)
+    }
+
+    pub(crate) fn remove_by_name(&mut self, name: &str) -> Option<Device> {
+        self.0
+            .iter()
+            .position(|device| device.network_info.name == name)
+            .map(|index| self.0.remove(index))
+    }
+
     /// Returns an iterator over [`Device`]s.
     #[inline]
     pub fn iter(&self) -> std::slice::Iter<'_, Device> {
@@ -393,12 +667,12 @@ impl Devices {
 pub(crate) mod tests {
     use std::collections::{HashMap, HashSet};
 
-    use tosca::device::{DeviceEnvironment, DeviceKindId};
+    use tosca::device::{Capabilities, Capability, DeviceEnvironment, DeviceKind, DeviceKindId};
     use tosca::hazards::{Hazard, Hazards};
     use tosca::parameters::Parameters;
-    use tosca::route::{Route, RouteConfigs};
+    use tosca::route::{LightOnRoute, LockRoute, Route, RouteConfigs, UnlockRoute};
 
-    use super::{Description, Device, Devices, NetworkInformation, build_device_address};
+    use super::{Description, Device, DeviceId, Devices, NetworkInformation, build_device_address};
 
     fn create_network_info(address: &str, port: u16) -> NetworkInformation {
         let ip_address = address.parse().unwrap();
@@ -514,4 +788,178 @@ pub(crate) mod tests {
         // Get a reference to a device. The order is important.
         assert_eq!(devices.get(1), Some(&create_unknown()));
     }
+
+    #[test]
+    fn check_candidate_addresses() {
+        let light = create_light();
+
+        // The last reachable address comes first.
+        assert_eq!(
+            light.candidate_addresses(),
+            vec![
+                "http://192.168.1.174:5000".to_owned(),
+                "http://172.0.0.1:5000".to_owned()
+            ]
+        );
+
+        // Fail over to another address.
+        light.set_reachable_address("http://172.0.0.1:5000".into());
+
+        assert_eq!(light.reachable_address(), "http://172.0.0.1:5000");
+        assert_eq!(
+            light.candidate_addresses(),
+            vec![
+                "http://172.0.0.1:5000".to_owned(),
+                "http://192.168.1.174:5000".to_owned()
+            ]
+        );
+
+        // The network information is not changed.
+        assert_eq!(
+            light.network_info().last_reachable_address,
+            "http://192.168.1.174:5000"
+        );
+    }
+
+    #[test]
+    fn check_standard_kind() {
+        let light = create_light();
+
+        assert_eq!(light.standard_kind(), Some(DeviceKind::Light));
+        assert!(light.mandatory_request(&LightOnRoute::MANDATORY).is_some());
+        assert!(light.mandatory_request(&LockRoute::MANDATORY).is_none());
+
+        // A device of unknown kind has no mandatory routes.
+        assert_eq!(create_unknown().standard_kind(), Some(DeviceKind::Unknown));
+
+        // A device which does not implement all the mandatory routes of
+        // its kind cannot be operated as a standard device.
+        let description = create_description(DeviceKindId::new("DoorLock"), "lock/");
+        let route_configs = RouteConfigs::init(Route::put("Lock", "/lock").serialize_data())
+            .insert(Route::get("Unlock", "/unlock").serialize_data());
+        let lock = Device::new(
+            create_network_info("192.168.1.178", 5000),
+            description,
+            route_configs,
+        );
+
+        assert_eq!(lock.standard_kind(), None);
+        assert!(lock.mandatory_request(&LockRoute::MANDATORY).is_some());
+        assert!(lock.mandatory_request(&UnlockRoute::MANDATORY).is_none());
+
+        // Custom device kinds are never standard.
+        let description = create_description(DeviceKindId::new("Relay"), "relay/");
+        let relay = Device::new(
+            create_network_info("192.168.1.180", 5000),
+            description,
+            RouteConfigs::new(),
+        );
+        assert_eq!(relay.standard_kind(), None);
+    }
+
+    #[test]
+    fn check_capabilities() {
+        let mut light = create_light();
+        light.description.capabilities = Capabilities::new()
+            .insert(Capability::OnOff)
+            .insert(Capability::Dimmable);
+
+        // The light does not implement the dimmable routes.
+        assert!(light.has_capability(Capability::OnOff));
+        assert!(!light.has_capability(Capability::Dimmable));
+
+        // The plug implements the on/off routes, but it does not declare
+        // the capability yet.
+        let description = create_description(DeviceKindId::new("Switch"), "plug/");
+        let route_configs = RouteConfigs::init(Route::put("On", "/on").serialize_data())
+            .insert(Route::put("Off", "/off").serialize_data());
+        let mut plug = Device::new(
+            create_network_info("192.168.1.182", 5000),
+            description,
+            route_configs,
+        );
+        assert!(!plug.has_capability(Capability::OnOff));
+        plug.description.capabilities = Capabilities::init(Capability::OnOff);
+
+        let devices = Devices::from_devices(vec![light, create_unknown(), plug]);
+
+        let on_off = devices
+            .with_capability(Capability::OnOff)
+            .map(|device| device.description().kind.name())
+            .collect::<Vec<_>>();
+        assert_eq!(on_off, ["Light", "Switch"]);
+        assert_eq!(devices.with_capability(Capability::Dimmable).count(), 0);
+    }
+
+    #[test]
+    fn check_device_id() {
+        let light = create_light();
+
+        // The Wi-Fi MAC address has the precedence.
+        assert_eq!(
+            light.id(),
+            &DeviceId::mac([0x02, 0x11, 0x22, 0x33, 0x44, 0x55], 5000)
+        );
+        assert_eq!(light.id().to_string(), "02:11:22:33:44:55@5000");
+
+        // Fall back to the Ethernet MAC address.
+        let mut network_info = light.network_info().clone();
+        network_info.wifi_mac = None;
+        assert_eq!(
+            DeviceId::from(&network_info),
+            DeviceId::mac([0x06, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE], 5000)
+        );
+
+        // Fall back to the full device name.
+        network_info.ethernet_mac = None;
+        assert_eq!(
+            DeviceId::from(&network_info),
+            DeviceId::name("device-name1._tosca._tcp.local.")
+        );
+
+        let devices = Devices::from_devices(vec![create_light()]);
+
+        // Retrieve a device through its identifier.
+        assert_eq!(devices.get_by_id(light.id()), Some(&light));
+        assert_eq!(devices.get_by_id(&DeviceId::name("wrong")), None);
+    }
+
+    #[test]
+    fn check_devices_sharing_mac() {
+        // Two devices running on the same machine, hence with the same
+        // MAC addresses, but listening on different ports.
+        let first = Device::new(
+            create_network_info("127.0.0.1", 3000),
+            create_description(DeviceKindId::new("Light"), "light/"),
+            RouteConfigs::new(),
+        );
+        let second = Device::new(
+            create_network_info("127.0.0.1", 3001),
+            create_description(DeviceKindId::new("Light"), "light/"),
+            RouteConfigs::new(),
+        );
+        assert_eq!(
+            first.network_info().wifi_mac,
+            second.network_info().wifi_mac
+        );
+        assert_ne!(first.id(), second.id());
+
+        let first_id = first.id().clone();
+        let second_id = second.id().clone();
+        let devices = Devices::from_devices(vec![first, second]);
+
+        // Each device is retrieved through its own identifier.
+        assert_eq!(
+            devices
+                .get_by_id(&first_id)
+                .map(|device| device.network_info().port),
+            Some(3000)
+        );
+        assert_eq!(
+            devices
+                .get_by_id(&second_id)
+                .map(|device| device.network_info().port),
+            Some(3001)
+        );
+    }
 }
@@ -1,21 +1,31 @@
 use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::net::IpAddr;
 use std::time::Duration;
 
-use tosca::device::DeviceData;
+use tosca::device::{DeviceData, PROTOCOL_VERSION, ProtocolVersion};
+use tosca::route::RestKind;
 
 use flume::RecvTimeoutError;
 
 use mdns_sd::{IfKind, Receiver, ResolvedService, ServiceDaemon, ServiceEvent};
 
+use tokio::net::lookup_host;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 
-use tracing::{info, warn};
+use tokio_util::sync::CancellationToken;
 
-use crate::device::{Description, Device, Devices, NetworkInformation, build_device_address};
-use crate::error::Error;
+use tracing::{error, info, warn};
+
+use crate::client::HttpClient;
+use crate::device::{
+    Description, Device, DeviceId, Devices, NetworkInformation, build_device_address,
+};
+use crate::error::{Error, ErrorKind};
 use crate::events::Events;
-use crate::request::create_requests;
+use crate::response::decode_response;
 
 // Service top-level domain.
 //
@@ -48,6 +58,36 @@ impl TransportProtocol {
     }
 }
 
+/// A notification emitted by the continuous discovery service.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiscoveryEvent {
+    /// A new device has been found on the network.
+    DeviceAdded(DeviceId),
+    /// A device has been removed from the network.
+    DeviceRemoved(DeviceId),
+    /// The data of an already known device has changed.
+    DeviceChanged(DeviceId),
+}
+
+impl std::fmt::Display for DiscoveryEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DeviceAdded(id) => write!(f, "Device `{id}` added"),
+            Self::DeviceRemoved(id) => write!(f, "Device `{id}` removed"),
+            Self::DeviceChanged(id) => write!(f, "Device `{id}` changed"),
+        }
+    }
+}
+
+// An update produced by the continuous discovery task.
+#[derive(Debug)]
+pub(crate) enum DiscoveryUpdate {
+    // A device has been resolved.
+    Resolved(Box<Device>),
+    // The device with the given full name has been removed.
+    Removed(String),
+}
+
 /// Device discovery service.
 ///
 /// A service for identifying and registering all `tosca` devices within
@@ -137,42 +177,36 @@ impl Discovery {
         self
     }
 
-    pub(crate) async fn discover(&self) -> Result<Devices, Error> {
+    pub(crate) async fn discover(&self, client: &HttpClient) -> Result<Devices, Error> {
         // Discover devices.
         let discovery_info = self.discover_devices().await?;
 
-        Self::obtain_devices_data(discovery_info).await
+        Self::obtain_devices_data(client, discovery_info).await
     }
 
-    async fn discover_devices(&self) -> Result<Vec<ResolvedService>, Error> {
-        // Create a mdns daemon
-        let mdns = ServiceDaemon::new()?;
-
-        // Disable IPv6 interface.
-        if self.disable_ipv6 {
-            mdns.disable_interface(IfKind::IPv6)?;
-        }
-
-        // Disable IP.
-        if let Some(ip) = self.disable_ip {
-            mdns.disable_interface(ip)?;
-        }
-
-        // Disable network interface.
-        if let Some(network_interface) = self.disable_network_interface {
-            mdns.disable_interface(network_interface)?;
-        }
-
-        // Service type.
-        let service_type = format!(
-            "_{}._{}.{}.",
-            self.domain,
-            self.transport_protocol.name(),
-            self.top_level_domain
-        );
+    // Browses the network in a background task until the cancellation token
+    // is triggered, sending an update each time a device is resolved
+    // or removed.
+    pub(crate) fn discover_continuously(
+        &self,
+        client: HttpClient,
+        sender: mpsc::Sender<DiscoveryUpdate>,
+        cancellation_token: CancellationToken,
+    ) -> Result<JoinHandle<()>, Error> {
+        let (mdns, service_type, receiver) = self.browse()?;
+
+        Ok(tokio::spawn(run_continuous_discovery(
+            mdns,
+            service_type,
+            receiver,
+            client,
+            sender,
+            cancellation_token,
+        )))
+    }
 
-        // Detects devices.
-        let receiver = mdns.browse(&service_type)?;
+    async fn discover_devices(&self) -> Result<Vec<ResolvedService>, Error> {
+        let (mdns, service_type, receiver) = self.browse()?;
 
         // Discovery service.
         let mut discovery_service = Vec::new();
@@ -205,6 +239,39 @@ impl Discovery {
         Ok(discovery_service)
     }
 
+    fn browse(&self) -> Result<(ServiceDaemon, String, Receiver<ServiceEvent>), Error> {
+        // Create a mdns daemon
+        let mdns = ServiceDaemon::new()?;
+
+        // Disable IPv6 interface.
+        if self.disable_ipv6 {
+            mdns.disable_interface(IfKind::IPv6)?;
+        }
+
+        // Disable IP.
+        if let Some(ip) = self.disable_ip {
+            mdns.disable_interface(ip)?;
+        }
+
+        // Disable network interface.
+        if let Some(network_interface) = self.disable_network_interface {
+            mdns.disable_interface(network_interface)?;
+        }
+
+        // Service type.
+        let service_type = format!(
+            "_{}._{}.{}.",
+            self.domain,
+            self.transport_protocol.name(),
+            self.top_level_domain
+        );
+
+        // Detects devices.
+        let receiver = mdns.browse(&service_type)?;
+
+        Ok((mdns, service_type, receiver))
+    }
+
     #[inline]
     async fn with_timeout<T>(&self, receiver: &Receiver<T>) -> Result<T, RecvTimeoutError> {
         let timeout_future = sleep(self.timeout);
@@ -222,6 +289,7 @@ impl Discovery {
     }
 
     async fn obtain_devices_data(
+        client: &HttpClient,
         discovery_service: Vec<ResolvedService>,
     ) -> Result<Devices, Error> {
         // Devices collection.
@@ -229,88 +297,62 @@ impl Discovery {
 
         // Iterate over discovered metadata
         for service in discovery_service {
-            // Try to contact each available address for a device
-            // to retrieve data.
-            for address in &service.addresses {
-                let complete_address = build_device_address(
-                    service
-                        .txt_properties
-                        .get_property_val_str("scheme")
-                        // If the scheme is not specified as a property,
-                        // fall back to `http` as default.
-                        .unwrap_or("http"),
-                    &address.to_ip_addr(),
-                    service.port,
-                );
-                info!("Complete address: {complete_address}");
-
-                // Contact devices to retrieve their data
-                match reqwest::Client::new()
-                    .get(&complete_address)
-                    .header("Connection", "close")
-                    .send()
-                    .await
-                {
-                    Ok(response) => {
-                        let device_data: DeviceData = response.json().await?;
-
-                        if device_data.wifi_mac.is_none() && device_data.ethernet_mac.is_none() {
-                            warn!(
-                                "Ignoring device {complete_address} because no valid MAC addresses have been found"
-                            );
-                            continue;
-                        }
-
-                        let requests = create_requests(
-                            device_data.route_configs,
-                            &complete_address,
-                            &device_data.main_route,
-                            device_data.environment,
-                        );
-
-                        let description = Description::new(
-                            device_data.kind,
-                            device_data.environment,
-                            device_data.main_route.into_owned(),
-                        );
-
-                        let mut network_info = NetworkInformation::new(
-                            service.fullname,
-                            service
-                                .addresses
-                                .into_iter()
-                                .map(|address| address.to_ip_addr())
-                                .collect(),
-                            service.port,
-                            service.txt_properties.into_property_map_str(),
-                            complete_address,
-                        );
-
-                        if let Some(mac) = device_data.wifi_mac {
-                            network_info = network_info.wifi_mac(mac);
-                        }
-
-                        if let Some(mac) = device_data.ethernet_mac {
-                            network_info = network_info.ethernet_mac(mac);
-                        }
-
-                        let events = device_data.events_description.map(Events::new);
-
-                        devices.add(Device::init(network_info, description, requests, events));
-
-                        // Only a single address is necessary.
-                        break;
-                    }
-                    Err(e) => {
-                        warn!("Impossible to contact address {complete_address}: {e}");
-                    }
+            match Self::obtain_device_data(client, service).await {
+                Ok(Some(device)) => devices.add(device),
+                Ok(None) => {}
+                // Devices implementing an incompatible protocol are skipped.
+                Err(e) if e.kind() == ErrorKind::IncompatibleDevice => {
+                    warn!("Skipping device: {e}");
                 }
+                Err(e) => return Err(e),
             }
         }
 
         Ok(devices)
     }
 
+    async fn obtain_device_data(
+        client: &HttpClient,
+        service: ResolvedService,
+    ) -> Result<Option<Device>, Error> {
+        // If the scheme is not specified as a property,
+        // fall back to `http` as default.
+        let scheme = service
+            .txt_properties
+            .get_property_val_str("scheme")
+            .unwrap_or("http");
+
+        let complete_addresses = service
+            .addresses
+            .iter()
+            .map(|address| build_device_address(scheme, &address.to_ip_addr(), service.port))
+            .collect::<Vec<String>>();
+
+        // Try to contact each available address for a device
+        // to retrieve data.
+        //
+        // Only a single address is necessary.
+        let Some((complete_address, device_data)) =
+            retrieve_device_data(client, complete_addresses).await?
+        else {
+            return Ok(None);
+        };
+
+        let network_info = NetworkInformation::new(
+            service.fullname,
+            service
+                .addresses
+                .into_iter()
+                .map(|address| address.to_ip_addr())
+                .collect(),
+            service.port,
+            service.txt_properties.into_property_map_str(),
+            complete_address,
+        );
+
+        Ok(create_device(device_data, network_info))
+    }
+
     // A discovered device is equal to another device when:
     //
     // - It has an address with IP and port identical to the ones of
@@ -348,19 +390,271 @@ impl Discovery {
     }
 }
 
+// Obtains a device from its address, without relying on mDNS.
+//
+// The address must be in the `scheme://host:port` form. When the host is
+// a domain name, it is resolved into its IP addresses.
+pub(crate) async fn obtain_device_from_address(
+    client: &HttpClient,
+    address: &str,
+) -> Result<Device, Error> {
+    let url = reqwest::Url::parse(address).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidParameter,
+            format!("Invalid device address `{address}`: {e}"),
+        )
+    })?;
+
+    let scheme = url.scheme();
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return Err(Error::new(
+            ErrorKind::InvalidParameter,
+            format!("The device address `{address}` lacks a host or a port"),
+        ));
+    };
+
+    // IPv6 hosts are enclosed in square brackets.
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let addresses = match host.parse::<IpAddr>() {
+        Ok(ip) => HashSet::from([ip]),
+        Err(_) => lookup_host((host, port))
+            .await
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::Request,
+                    format!("Impossible to resolve `{host}`: {e}"),
+                )
+            })?
+            .map(|socket_address| socket_address.ip())
+            .collect(),
+    };
+
+    let complete_addresses = addresses
+        .iter()
+        .map(|address| build_device_address(scheme, address, port))
+        .collect::<Vec<String>>();
+
+    let unreachable_error = || {
+        Error::new(
+            ErrorKind::Request,
+            format!("No valid device found at `{address}`"),
+        )
+    };
+
+    let (complete_address, device_data) = retrieve_device_data(client, complete_addresses)
+        .await?
+        .ok_or_else(unreachable_error)?;
+
+    // Store the scheme as a property, the same way a device
+    // announces it through mDNS.
+    let properties = HashMap::from([("scheme".into(), scheme.into())]);
+
+    let network_info = NetworkInformation::new(
+        address.into(),
+        addresses,
+        port,
+        properties,
+        complete_address,
+    );
+
+    create_device(device_data, network_info).ok_or_else(unreachable_error)
+}
+
+// Contacts the given addresses in order, returning the data of the first
+// device which responds, along with its address.
+pub(crate) async fn retrieve_device_data<I>(
+    client: &HttpClient,
+    complete_addresses: I,
+) -> Result<Option<(String, DeviceData)>, Error>
+where
+    I: IntoIterator<Item = String>,
+{
+    for complete_address in complete_addresses {
+        info!("Complete address: {complete_address}");
+
+        // Contact devices to retrieve their data
+        match client
+            .send(
+                || client.request(RestKind::Get, &complete_address, true),
+                RestKind::Get,
+            )
+            .await
+        {
+            Ok(response) => {
+                let device_data = parse_device_data(decode_response(response).await?)?;
+                return Ok(Some((complete_address, device_data)));
+            }
+            Err(e) => {
+                warn!("Impossible to contact address {complete_address}: {e}");
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+// Checks whether a device protocol version is compatible with
+// the controller one.
+//
+// A device implementing a newer minor version is accepted, but the
+// features introduced by that version are unknown to the controller.
+pub(crate) fn check_protocol_version(protocol_version: ProtocolVersion) -> Result<(), Error> {
+    if !PROTOCOL_VERSION.is_compatible(&protocol_version) {
+        return Err(Error::new(
+            ErrorKind::IncompatibleDevice,
+            format!(
+                "The device protocol version {protocol_version} is incompatible with the controller protocol version {PROTOCOL_VERSION}"
+            ),
+        ));
+    }
+
+    if protocol_version > PROTOCOL_VERSION {
+        warn!(
+            "The device protocol version {protocol_version} is newer than the controller protocol version {PROTOCOL_VERSION}"
+        );
+    }
+
+    Ok(())
+}
+
+// Parses the data of a device, checking its protocol version first,
+// since the data of an incompatible device cannot be interpreted.
+fn parse_device_data(value: serde_json::Value) -> Result<DeviceData, Error> {
+    let protocol_version = match value.get("protocol_version") {
+        Some(version) => {
+            serde_json::from_value::<ProtocolVersion>(version.clone()).map_err(|e| {
+                Error::new(
+                    ErrorKind::IncompatibleDevice,
+                    format!("Invalid device protocol version: {e}"),
+                )
+            })?
+        }
+        None => ProtocolVersion::default(),
+    };
+
+    check_protocol_version(protocol_version)?;
+
+    serde_json::from_value(value)
+        .map_err(|e| Error::new(ErrorKind::JsonResponse, format!("Invalid device data: {e}")))
+}
+
+// Creates a device from its data.
+//
+// Returns `None` when the device has no MAC addresses.
+pub(crate) fn create_device(
+    device_data: DeviceData,
+    mut network_info: NetworkInformation,
+) -> Option<Device> {
+    if device_data.wifi_mac.is_none() && device_data.ethernet_mac.is_none() {
+        warn!(
+            "Ignoring device {} because no valid MAC addresses have been found",
+            network_info.last_reachable_address
+        );
+        return None;
+    }
+
+    let mut description = Description::new(
+        device_data.kind,
+        device_data.environment,
+        device_data.main_route.into_owned(),
+    );
+    description.protocol_version = device_data.protocol_version;
+    description.identity = device_data.identity;
+    description.capabilities = device_data.capabilities;
+
+    network_info.wifi_mac = device_data.wifi_mac;
+    network_info.ethernet_mac = device_data.ethernet_mac;
+
+    let events = device_data.events_description.map(Events::new);
+
+    Some(Device::init(
+        network_info,
+        description,
+        device_data.route_configs,
+        events,
+    ))
+}
+
+async fn run_continuous_discovery(
+    mdns: ServiceDaemon,
+    service_type: String,
+    receiver: Receiver<ServiceEvent>,
+    client: HttpClient,
+    sender: mpsc::Sender<DiscoveryUpdate>,
+    cancellation_token: CancellationToken,
+) {
+    loop {
+        let event = tokio::select! {
+            // Use the cancellation token to stop the loop
+            () = cancellation_token.cancelled() => { break; }
+            event = receiver.recv_async() => event,
+        };
+
+        let Ok(event) = event else {
+            warn!("The discovery service has been disconnected");
+            break;
+        };
+
+        let update = match event {
+            ServiceEvent::ServiceResolved(info) => {
+                if info.get_addresses().is_empty() {
+                    warn!("No device address available for {:?}", info);
+                    continue;
+                }
+
+                match Discovery::obtain_device_data(&client, *info).await {
+                    Ok(Some(device)) => DiscoveryUpdate::Resolved(Box::new(device)),
+                    Ok(None) => continue,
+                    Err(e) => {
+                        error!("Impossible to retrieve the device data: {e}");
+                        continue;
+                    }
+                }
+            }
+            ServiceEvent::ServiceRemoved(_, fullname) => DiscoveryUpdate::Removed(fullname),
+            _ => continue,
+        };
+
+        if sender.send(update).await.is_err() {
+            warn!("The discovery receiver has been dropped, stop the discovery");
+            break;
+        }
+    }
+
+    // Stop detection.
+    if let Err(e) = mdns.stop_browse(&service_type) {
+        error!("Failed to stop the discovery service: {e}");
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
+    use std::collections::{HashMap, HashSet};
     use std::time::Duration;
 
+    use tosca::device::{
+        Capabilities, Capability, DeviceData, DeviceEnvironment, DeviceIdentity, DeviceKindId,
+        PROTOCOL_VERSION,
+    };
+    use tosca::encoding::Encoding;
+    use tosca::route::{RestKind, RouteConfigs};
+
+    use reqwest::header::CONTENT_TYPE;
+
     use tracing::warn;
 
+    use serde_json::json;
+
     use serial_test::serial;
 
+    use crate::client::{ClientConfig, HttpClient};
+    use crate::device::NetworkInformation;
+    use crate::error::ErrorKind;
     use crate::tests::{
         DOMAIN, check_function_with_device, check_function_with_two_devices, compare_device_data,
     };
 
-    use super::Discovery;
+    use super::{Discovery, create_device, parse_device_data, retrieve_device_data};
 
     pub(crate) fn configure_discovery() -> Discovery {
         Discovery::new(DOMAIN)
@@ -370,7 +664,10 @@ pub(crate) mod tests {
     }
 
     async fn discovery_comparison(devices_len: usize) {
-        let devices = configure_discovery().discover().await.unwrap();
+        let devices = configure_discovery()
+            .discover(&HttpClient::default())
+            .await
+            .unwrap();
 
         // Count devices.
         assert_eq!(devices.len(), devices_len);
@@ -420,4 +717,144 @@ pub(crate) mod tests {
         })
         .await;
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    #[serial]
+    async fn test_device_data_encodings() {
+        const ADDRESS: &str = "http://127.0.0.1:3000/";
+
+        check_function_with_device(|| async {
+            // The device data is encoded as preferred by the controller.
+            let content_type = async |client: HttpClient| {
+                let response = client
+                    .request(RestKind::Get, ADDRESS, true)
+                    .send()
+                    .await
+                    .unwrap();
+                response.headers()[CONTENT_TYPE].clone()
+            };
+            assert_eq!(
+                content_type(HttpClient::default()).await,
+                Encoding::Json.content_type()
+            );
+            assert_eq!(
+                content_type(HttpClient::new(
+                    ClientConfig::new().encoding(Encoding::Cbor)
+                ))
+                .await,
+                Encoding::Cbor.content_type()
+            );
+
+            let device_data = async |encoding| {
+                let client = HttpClient::new(ClientConfig::new().encoding(encoding));
+                retrieve_device_data(&client, [ADDRESS.to_owned()])
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .1
+            };
+
+            // The same data is retrieved with both encodings.
+            assert_eq!(
+                device_data(Encoding::Cbor).await,
+                device_data(Encoding::Json).await
+            );
+        })
+        .await;
+    }
+
+    #[test]
+    fn create_device_with_identity_and_capabilities() {
+        let identity = DeviceIdentity::new()
+            .manufacturer("Tosca Labs")
+            .model("L-100")
+            .location("Kitchen");
+
+        let device_data = DeviceData::new(
+            DeviceKindId::new("Light"),
+            DeviceEnvironment::Os,
+            Some([1, 2, 3, 4, 5, 6]),
+            None,
+            "/light",
+            RouteConfigs::new(),
+            0,
+        )
+        .identity(identity.clone())
+        .capabilities(Capabilities::init(Capability::OnOff));
+
+        let network_info = NetworkInformation::new(
+            "light._tosca._tcp.local.".into(),
+            HashSet::new(),
+            3000,
+            HashMap::new(),
+            "http://127.0.0.1:3000".into(),
+        );
+
+        let device = create_device(device_data, network_info).unwrap();
+        assert_eq!(device.description().identity, identity);
+        assert_eq!(
+            device.description().capabilities,
+            Capabilities::init(Capability::OnOff)
+        );
+    }
+
+    #[test]
+    fn check_device_protocol_version() {
+        let device_data = || {
+            DeviceData::new(
+                DeviceKindId::new("Light"),
+                DeviceEnvironment::Os,
+                Some([1, 2, 3, 4, 5, 6]),
+                None,
+                "/light",
+                RouteConfigs::new(),
+                0,
+            )
+        };
+        let with_version = |version| {
+            let mut value = serde_json::to_value(device_data()).unwrap();
+            value["protocol_version"] = version;
+            value
+        };
+
+        // Same protocol version.
+        assert_eq!(
+            parse_device_data(serde_json::to_value(device_data()).unwrap()),
+            Ok(device_data())
+        );
+
+        // A newer minor version is accepted.
+        let device = parse_device_data(with_version(json!({
+            "major": PROTOCOL_VERSION.major,
+            "minor": PROTOCOL_VERSION.minor + 1,
+        })))
+        .unwrap();
+        assert_eq!(device.protocol_version.minor, PROTOCOL_VERSION.minor + 1);
+
+        // A device without a protocol version implements the initial one,
+        // which is incompatible since events encode the types of their values.
+        let mut legacy = serde_json::to_value(device_data()).unwrap();
+        legacy.as_object_mut().unwrap().remove("protocol_version");
+        assert_eq!(
+            parse_device_data(legacy).unwrap_err().kind(),
+            ErrorKind::IncompatibleDevice
+        );
+
+        // A different major version is incompatible, even when the device
+        // data cannot be interpreted.
+        let incompatible = json!({
+            "protocol_version": { "major": PROTOCOL_VERSION.major + 1, "minor": 0 },
+            "routes": [],
+        });
+        assert_eq!(
+            parse_device_data(incompatible).unwrap_err().kind(),
+            ErrorKind::IncompatibleDevice
+        );
+        assert_eq!(
+            parse_device_data(with_version(json!("1.0")))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::IncompatibleDevice
+        );
+    }
 }
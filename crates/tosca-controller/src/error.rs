@@ -7,11 +7,21 @@ use tracing::error;
 pub enum ErrorKind {
     /// Errors encountered during the discovery service.
     Discovery,
+    /// Errors caused by a device implementing an incompatible
+    /// protocol version.
+    IncompatibleDevice,
     /// Errors encountered when sending requests to a device.
     Request,
+    /// Errors caused by a route not found on a device.
+    NotFound,
+    /// Errors caused by a `REST` method not allowed by a device route.
+    MethodNotAllowed,
+    /// Errors caused by an internal device failure which does not provide
+    /// any further details.
+    DeviceInternal,
     /// Errors caused by an invalid parameter.
     InvalidParameter,
-    /// Errors encountered while parsing a `json` response.
+    /// Errors encountered while parsing a `json` or `cbor` response.
     JsonResponse,
     /// Errors encountered while parsing a byte stream response.
     #[cfg(feature = "stream")]
@@ -20,19 +30,26 @@ pub enum ErrorKind {
     Sender,
     /// Errors related to event management.
     Events,
+    /// Errors encountered while saving or loading the device registry.
+    Registry,
 }
 
 impl ErrorKind {
     pub(crate) const fn description(self) -> &'static str {
         match self {
             Self::Discovery => "Discovery",
+            Self::IncompatibleDevice => "Incompatible Device",
             Self::Request => "Request",
+            Self::NotFound => "Not Found",
+            Self::MethodNotAllowed => "Method Not Allowed",
+            Self::DeviceInternal => "Device Internal",
             Self::InvalidParameter => "Invalid Parameter",
             Self::JsonResponse => "Json Response",
             #[cfg(feature = "stream")]
             Self::StreamResponse => "Stream Response",
             Self::Sender => "Response Sender",
             Self::Events => "Events",
+            Self::Registry => "Registry",
         }
     }
 }
@@ -71,6 +88,12 @@ impl Error {
         Self { kind, description }
     }
 
+    /// Returns the [`ErrorKind`].
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
     fn format(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}: {}", self.kind, self.description)
     }
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use tosca::encoding::Encoding;
 use tosca::events::{BrokerData, Events as ToscaEvents, EventsDescription};
 
 use rumqttc::v5::{
@@ -14,6 +15,7 @@ use tokio_util::sync::CancellationToken;
 
 use tracing::{error, warn};
 
+use crate::device::DeviceId;
 use crate::error::Result;
 
 // The capacity of the bounded asynchronous channel.
@@ -22,29 +24,91 @@ const ASYNC_CHANNEL_CAPACITY: usize = 10;
 // Keep alive time to send `pingreq` to broker when the connection is idle.
 const KEEP_ALIVE_TIME: Duration = Duration::from_secs(5);
 
-/// Event payload transmitted by the global asynchronous receiver task.
+/// Event payload transmitted by the asynchronous receiver tasks.
 ///
 /// The payload consists of a device identifier and its associated event data.
-#[derive(Debug)]
+/// Both the global receiver and the device receivers transmit it.
+#[derive(Debug, Clone)]
 pub struct EventPayload {
     /// Device identifier.
-    pub device_id: usize,
+    pub device_id: DeviceId,
     /// Device events.
     pub events: ToscaEvents,
+    /// Number of event frames published by the device and never received
+    /// since the previous payload of the same device.
+    pub missed_frames: u64,
 }
 
 impl std::fmt::Display for EventPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         writeln!(f)?;
         writeln!(f, "Events for `Device {}`", self.device_id)?;
+        if self.missed_frames > 0 {
+            writeln!(f, "Missed frames: {}", self.missed_frames)?;
+        }
         writeln!(f)?;
         write!(f, "{}", self.events)
     }
 }
 
 impl EventPayload {
-    pub(crate) const fn new(device_id: usize, events: ToscaEvents) -> Self {
-        Self { device_id, events }
+    pub(crate) const fn new(device_id: DeviceId, events: ToscaEvents, missed_frames: u64) -> Self {
+        Self {
+            device_id,
+            events,
+            missed_frames,
+        }
+    }
+}
+
+/// A tracker of the event frames received from a device.
+///
+/// Every time a device publishes its events, it numbers the published frame
+/// with a monotonic sequence number. The tracker compares the sequence
+/// numbers of consecutive frames to detect the frames which have been
+/// dropped along the way.
+///
+/// Frames with a zero sequence number come from devices which do not number
+/// their frames, so they are never considered. A sequence number lower than
+/// or equal to the last one means that the device has restarted,
+/// so the tracking starts again from it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameTracker {
+    // Sequence number of the last tracked frame.
+    last_sequence: Option<u64>,
+}
+
+impl FrameTracker {
+    /// Creates a [`FrameTracker`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            last_sequence: None,
+        }
+    }
+
+    /// Tracks a received frame.
+    ///
+    /// Returns the number of frames missed between the last tracked frame
+    /// and the given one.
+    pub const fn track(&mut self, events: &ToscaEvents) -> u64 {
+        let sequence = events.sequence();
+        if sequence == 0 {
+            return 0;
+        }
+
+        let missed_frames = match self.last_sequence {
+            Some(last_sequence) if sequence > last_sequence => sequence - last_sequence - 1,
+            _ => 0,
+        };
+        self.last_sequence = Some(sequence);
+        missed_frames
+    }
+
+    /// Returns the sequence number of the last tracked frame, if any.
+    #[must_use]
+    pub const fn last_sequence(&self) -> Option<u64> {
+        self.last_sequence
     }
 }
 
@@ -65,8 +129,26 @@ impl Events {
     }
 }
 
+// Builds the payload of a received frame, tracking the frames missed
+// since the previous one.
 #[inline]
-fn parse_event(event: &std::result::Result<Event, ConnectionError>) -> Option<ToscaEvents> {
+fn build_payload(
+    id: &DeviceId,
+    frame_tracker: &mut FrameTracker,
+    tosca_events: ToscaEvents,
+) -> EventPayload {
+    let missed_frames = frame_tracker.track(&tosca_events);
+    if missed_frames > 0 {
+        warn!("Missed {missed_frames} event frames of device with id `{id}`");
+    }
+    EventPayload::new(id.clone(), tosca_events, missed_frames)
+}
+
+#[inline]
+fn parse_event(
+    event: &std::result::Result<Event, ConnectionError>,
+    encoding: Encoding,
+) -> Option<ToscaEvents> {
     let event = match event {
         Ok(event) => event,
         Err(e) => {
@@ -88,8 +170,8 @@ fn parse_event(event: &std::result::Result<Event, ConnectionError>) -> Option<To
         return None;
     };
 
-    match serde_json::from_slice(&packet.payload) {
-        Ok(tosca_events) => tosca_events,
+    match encoding.decode(&packet.payload) {
+        Ok(tosca_events) => Some(tosca_events),
         Err(e) => {
             error!("Error converting packet bytes into events: {e}");
             None
@@ -100,21 +182,24 @@ fn parse_event(event: &std::result::Result<Event, ConnectionError>) -> Option<To
 async fn run_global_event_subscriber(
     client: AsyncClient,
     mut eventloop: EventLoop,
-    id: usize,
+    id: DeviceId,
+    encoding: Encoding,
     cancellation_token: CancellationToken,
     sender: mpsc::Sender<EventPayload>,
 ) {
+    let mut frame_tracker = FrameTracker::new();
     loop {
         tokio::select! {
             // Use the cancellation token to stop the loop
             () = cancellation_token.cancelled() => { break; }
             // Poll the `MQTT` event coming from the network
             event = eventloop.poll() => {
-                let Some(tosca_events) = parse_event(&event) else {
+                let Some(tosca_events) = parse_event(&event, encoding) else {
                     continue;
                 };
 
-                if let Err(e) = sender.send(EventPayload::new(id, tosca_events)).await {
+                let payload = build_payload(&id, &mut frame_tracker, tosca_events);
+                if let Err(e) = sender.send(payload).await {
                     error!(
                         "Stop sending events to the global receiver: {e}"
                     );
@@ -131,21 +216,24 @@ async fn run_global_event_subscriber(
 async fn run_event_subscriber(
     client: AsyncClient,
     mut eventloop: EventLoop,
-    id: usize,
+    id: DeviceId,
+    encoding: Encoding,
     cancellation_token: CancellationToken,
-    sender: broadcast::Sender<ToscaEvents>,
+    sender: broadcast::Sender<EventPayload>,
 ) {
+    let mut frame_tracker = FrameTracker::new();
     loop {
         tokio::select! {
             // Use the cancellation token to stop the loop
             () = cancellation_token.cancelled() => { break; }
             // Poll the `MQTT` event coming from the network
             event = eventloop.poll() => {
-                let Some(tosca_events) = parse_event(&event) else {
+                let Some(tosca_events) = parse_event(&event, encoding) else {
                     continue;
                 };
 
-                if let Err(e) = sender.send(tosca_events) {
+                let payload = build_payload(&id, &mut frame_tracker, tosca_events);
+                if let Err(e) = sender.send(payload) {
                     error!(
                         "Stop sending events to the device receiver with id `{id}`: {e}"
                     );
@@ -165,15 +253,16 @@ pub(crate) struct EventsRunner;
 impl EventsRunner {
     pub(crate) async fn run_global_subscriber(
         events: &Events,
-        id: usize,
+        id: DeviceId,
         sender: mpsc::Sender<EventPayload>,
     ) -> Result<JoinHandle<()>> {
-        let (client, eventloop) = Self::init(id, events).await?;
+        let (client, eventloop) = Self::init(&id, events).await?;
 
         Ok(tokio::spawn(run_global_event_subscriber(
             client,
             eventloop,
             id,
+            events.description.encoding,
             events.cancellation_token.clone(),
             sender,
         )))
@@ -181,22 +270,23 @@ impl EventsRunner {
 
     pub(crate) async fn run_device_subscriber(
         events: &Events,
-        id: usize,
-        sender: broadcast::Sender<ToscaEvents>,
+        id: DeviceId,
+        sender: broadcast::Sender<EventPayload>,
     ) -> Result<JoinHandle<()>> {
-        let (client, eventloop) = Self::init(id, events).await?;
+        let (client, eventloop) = Self::init(&id, events).await?;
 
         Ok(tokio::spawn(run_event_subscriber(
             client,
             eventloop,
             id,
+            events.description.encoding,
             events.cancellation_token.clone(),
             sender,
         )))
     }
 
     #[inline]
-    async fn init(id: usize, events: &Events) -> Result<(AsyncClient, EventLoop)> {
+    async fn init(id: &DeviceId, events: &Events) -> Result<(AsyncClient, EventLoop)> {
         let BrokerData { address, port } = events.description.broker_data;
         let topic = events.description.topic.as_str();
 
@@ -215,3 +305,67 @@ impl EventsRunner {
         Ok((client, eventloop))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::broadcast;
+
+    use tosca::events::Events as ToscaEvents;
+
+    use crate::device::DeviceId;
+
+    use super::{FrameTracker, build_payload};
+
+    fn frame(sequence: u64) -> ToscaEvents {
+        let mut events = ToscaEvents::empty();
+        for _ in 0..sequence {
+            events.next_frame(None);
+        }
+        events
+    }
+
+    #[test]
+    fn track_frames() {
+        let mut tracker = FrameTracker::new();
+        assert_eq!(tracker.last_sequence(), None);
+
+        // The first frame never misses anything.
+        assert_eq!(tracker.track(&frame(3)), 0);
+        assert_eq!(tracker.track(&frame(4)), 0);
+        assert_eq!(tracker.track(&frame(7)), 2);
+        assert_eq!(tracker.last_sequence(), Some(7));
+
+        // Frames which are not numbered are ignored.
+        assert_eq!(tracker.track(&frame(0)), 0);
+        assert_eq!(tracker.last_sequence(), Some(7));
+
+        // A device restart resets the tracking.
+        assert_eq!(tracker.track(&frame(1)), 0);
+        assert_eq!(tracker.track(&frame(3)), 1);
+    }
+
+    #[tokio::test]
+    async fn device_receiver_detects_gaps() {
+        let id = DeviceId::name("device");
+        let (sender, mut receiver) = broadcast::channel(4);
+
+        // Send the frames as the device subscriber does.
+        let mut tracker = FrameTracker::new();
+        for sequence in [1, 2, 5] {
+            sender
+                .send(build_payload(&id, &mut tracker, frame(sequence)))
+                .unwrap();
+        }
+
+        let missed_frames = [
+            receiver.recv().await.unwrap(),
+            receiver.recv().await.unwrap(),
+            receiver.recv().await.unwrap(),
+        ]
+        .map(|payload| {
+            assert_eq!(payload.device_id, id);
+            (payload.events.sequence(), payload.missed_frames)
+        });
+        assert_eq!(missed_frames, [(1, 0), (2, 0), (5, 2)]);
+    }
+}
@@ -15,6 +15,7 @@
 //! - Defining privacy policies to allow or block requests to a device
 //! - Intercepting device events by subscribing to the brokers where
 //!   they are published
+//! - Persisting devices to restore them without running a new discovery
 //!
 //! To optimize system resource usage, `tosca-controller` leverages `tokio` as
 //! an asynchronous executor, allowing concurrent execution of independent
@@ -25,6 +26,8 @@
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
 
+/// Configuration of the `HTTP` client shared by all requests.
+pub mod client;
 /// A controller for interacting with `tosca` devices.
 pub mod controller;
 /// Device data along with its associated methods.
@@ -38,6 +41,8 @@ pub mod events;
 /// A privacy policy manager that blocks or allows the requests to devices
 /// based on a set of privacy rules.
 pub mod policy;
+/// A registry for persisting devices across controller restarts.
+pub mod registry;
 /// Request data and the associated methods.
 pub mod request;
 /// All supported methods and data for handling `tosca` device responses.
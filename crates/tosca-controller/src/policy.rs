@@ -1,17 +1,24 @@
 use std::collections::HashMap;
 
-use tosca::hazards::Hazards;
+use tosca::hazards::{HazardRisks, Hazards, Severity};
 
-// TODO: Eventually rewrite policy IDs as &'static str.
+use crate::device::DeviceId;
 
 /// A privacy policy manager.
 ///
 /// It allows or blocks the requests to devices, or to a specific device,
 /// according to a set of privacy rules.
+///
+/// Besides specific hazards, requests can be blocked according to the
+/// [`Severity`] and the risk score of their hazards. Since route risks are
+/// advertised by devices, they can only raise the default risks of their
+/// hazards, as computed by [`HazardRisks::raised_risk`].
 #[derive(Debug, PartialEq)]
 pub struct Policy {
     block_on_hazards: Hazards,
-    block_device_on_hazards: HashMap<usize, Hazards>,
+    block_device_on_hazards: HashMap<DeviceId, Hazards>,
+    max_severity: Option<Severity>,
+    max_risk_score: Option<u8>,
 }
 
 impl Policy {
@@ -23,31 +30,59 @@ impl Policy {
         Self {
             block_on_hazards,
             block_device_on_hazards: HashMap::new(),
+            max_severity: None,
+            max_risk_score: None,
         }
     }
 
-    /// Creates a [`Policy`] to block **all** [`crate::device::Device`] id
-    /// requests that have the given [`Hazards`] in their routes.
+    /// Creates a [`Policy`] to block the requests of the
+    /// [`crate::device::Device`] with the given [`DeviceId`] that have the
+    /// given [`Hazards`] in their routes.
     #[must_use]
     #[inline]
-    pub fn only_local_policy(id: usize, hazards: Hazards) -> Self {
+    pub fn only_local_policy(id: DeviceId, hazards: Hazards) -> Self {
         let policy = Self::init();
         policy.block_device_on_hazards(id, hazards)
     }
 
-    /// Adds a new [`Policy`] to block **all** [`crate::device::Device`] id
-    /// requests that have the given [`Hazards`] in their routes.
+    /// Adds a new [`Policy`] to block the requests of the
+    /// [`crate::device::Device`] with the given [`DeviceId`] that have the
+    /// given [`Hazards`] in their routes.
     #[must_use]
     #[inline]
-    pub fn block_device_on_hazards(mut self, id: usize, hazards: Hazards) -> Self {
+    pub fn block_device_on_hazards(mut self, id: DeviceId, hazards: Hazards) -> Self {
         self.block_device_on_hazards.insert(id, hazards);
         self
     }
 
+    /// Blocks **all** requests whose hazards have a [`Severity`] above the
+    /// given one.
+    #[must_use]
+    #[inline]
+    pub fn block_above_severity(mut self, severity: Severity) -> Self {
+        self.max_severity = Some(severity);
+        self
+    }
+
+    /// Blocks **all** requests whose hazards have a risk score above the
+    /// given one.
+    ///
+    /// The risk score of a request is the highest
+    /// [`HazardRisk::score`](tosca::hazards::HazardRisk::score) among its
+    /// hazards, ranging from 1 up to 25.
+    #[must_use]
+    #[inline]
+    pub fn block_above_risk_score(mut self, score: u8) -> Self {
+        self.max_risk_score = Some(score);
+        self
+    }
+
     pub(crate) fn init() -> Self {
         Self {
             block_on_hazards: Hazards::new(),
             block_device_on_hazards: HashMap::new(),
+            max_severity: None,
+            max_risk_score: None,
         }
     }
 
@@ -61,8 +96,31 @@ impl Policy {
         blocked_hazards
     }
 
-    pub(crate) fn local_blocked_hazards(&self, id: usize, hazards: &Hazards) -> Hazards {
-        if let Some(local_hazards) = self.block_device_on_hazards.get(&id) {
+    pub(crate) fn exceeded_severity(
+        &self,
+        hazards: &Hazards,
+        risks: &HazardRisks,
+    ) -> Option<Severity> {
+        let max_severity = self.max_severity?;
+        hazards
+            .iter()
+            .map(|hazard| risks.raised_risk(*hazard).severity)
+            .max()
+            .filter(|severity| *severity > max_severity)
+    }
+
+    pub(crate) fn exceeded_risk_score(&self, hazards: &Hazards, risks: &HazardRisks) -> Option<u8> {
+        let max_risk_score = self.max_risk_score?;
+        let risk_score = hazards
+            .iter()
+            .map(|hazard| risks.raised_risk(*hazard).score())
+            .max()
+            .unwrap_or(0);
+        (risk_score > max_risk_score).then_some(risk_score)
+    }
+
+    pub(crate) fn local_blocked_hazards(&self, id: &DeviceId, hazards: &Hazards) -> Hazards {
+        if let Some(local_hazards) = self.block_device_on_hazards.get(id) {
             let mut blocked_hazards = Hazards::new();
             for hazard in hazards {
                 if local_hazards.contains(hazard) {
@@ -80,10 +138,16 @@ impl Policy {
 mod tests {
     use std::collections::HashMap;
 
-    use tosca::hazards::{Hazard, Hazards};
+    use tosca::hazards::{Hazard, HazardRisk, HazardRisks, Hazards, Likelihood, Severity};
+
+    use crate::device::DeviceId;
 
     use super::Policy;
 
+    const FIRST_MAC: [u8; 6] = [0x02, 0x11, 0x22, 0x33, 0x44, 0x55];
+    const SECOND_MAC: [u8; 6] = [0x06, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE];
+    const PORT: u16 = 3000;
+
     fn create_policy() -> (Hazards, Policy) {
         let hazards = Hazards::new().insert(Hazard::ElectricEnergyConsumption);
 
@@ -94,14 +158,16 @@ mod tests {
 
     fn check_device_policies(policy: &Policy, block_on_hazards: Hazards, local_hazards: &Hazards) {
         let mut devices_hazards = HashMap::new();
-        devices_hazards.insert(1, local_hazards.clone());
-        devices_hazards.insert(2, local_hazards.clone());
+        devices_hazards.insert(DeviceId::mac(FIRST_MAC, PORT), local_hazards.clone());
+        devices_hazards.insert(DeviceId::name("device-name"), local_hazards.clone());
 
         assert_eq!(
             policy,
             &Policy {
                 block_on_hazards,
                 block_device_on_hazards: devices_hazards,
+                max_severity: None,
+                max_risk_score: None,
             }
         );
     }
@@ -114,7 +180,9 @@ mod tests {
             policy,
             Policy {
                 block_on_hazards: hazards,
-                block_device_on_hazards: HashMap::new()
+                block_device_on_hazards: HashMap::new(),
+                max_severity: None,
+                max_risk_score: None,
             }
         );
     }
@@ -123,8 +191,9 @@ mod tests {
     fn only_local_policy() {
         let local_hazards = Hazards::new().insert(Hazard::Explosion);
 
-        let policy = Policy::only_local_policy(1, local_hazards.clone())
-            .block_device_on_hazards(2, local_hazards.clone());
+        let policy =
+            Policy::only_local_policy(DeviceId::mac(FIRST_MAC, PORT), local_hazards.clone())
+                .block_device_on_hazards(DeviceId::name("device-name"), local_hazards.clone());
 
         check_device_policies(&policy, Hazards::new(), &local_hazards);
     }
@@ -136,9 +205,87 @@ mod tests {
         let local_hazards = Hazards::new().insert(Hazard::Explosion);
 
         let policy = policy
-            .block_device_on_hazards(1, local_hazards.clone())
-            .block_device_on_hazards(2, local_hazards.clone());
+            .block_device_on_hazards(DeviceId::mac(FIRST_MAC, PORT), local_hazards.clone())
+            .block_device_on_hazards(DeviceId::name("device-name"), local_hazards.clone());
 
         check_device_policies(&policy, global_hazards, &local_hazards);
     }
+
+    #[test]
+    fn stable_local_policy() {
+        let local_hazards = Hazards::new().insert(Hazard::Explosion);
+        let route_hazards = Hazards::new()
+            .insert(Hazard::Explosion)
+            .insert(Hazard::FireHazard);
+
+        let policy =
+            Policy::only_local_policy(DeviceId::mac(FIRST_MAC, PORT), local_hazards.clone());
+
+        assert_eq!(
+            policy.local_blocked_hazards(&DeviceId::mac(FIRST_MAC, PORT), &route_hazards),
+            local_hazards
+        );
+        assert!(
+            policy
+                .local_blocked_hazards(&DeviceId::mac(SECOND_MAC, PORT), &route_hazards)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn severity_and_risk_policy() {
+        let route_hazards = Hazards::new()
+            .insert(Hazard::FireHazard)
+            .insert(Hazard::LogUsageTime);
+        let risks = HazardRisks::new();
+
+        // Without limits, nothing is blocked.
+        let policy = Policy::new(Hazards::new());
+        assert_eq!(policy.exceeded_severity(&route_hazards, &risks), None);
+        assert_eq!(policy.exceeded_risk_score(&route_hazards, &risks), None);
+
+        let policy = Policy::new(Hazards::new())
+            .block_above_severity(Severity::High)
+            .block_above_risk_score(9);
+        assert_eq!(
+            policy.exceeded_severity(&route_hazards, &risks),
+            Some(Severity::Critical)
+        );
+        assert_eq!(policy.exceeded_risk_score(&route_hazards, &risks), Some(10));
+
+        // A route can raise the default risk of its hazards.
+        let low_hazards = Hazards::init(Hazard::ElectricEnergyConsumption);
+        assert_eq!(policy.exceeded_severity(&low_hazards, &risks), None);
+        assert_eq!(policy.exceeded_risk_score(&low_hazards, &risks), None);
+
+        let risks = HazardRisks::init(
+            Hazard::ElectricEnergyConsumption,
+            HazardRisk::new(Severity::Critical, Likelihood::Likely),
+        );
+        assert_eq!(
+            policy.exceeded_severity(&low_hazards, &risks),
+            Some(Severity::Critical)
+        );
+        assert_eq!(policy.exceeded_risk_score(&low_hazards, &risks), Some(25));
+    }
+
+    #[test]
+    fn lowered_risks_do_not_bypass_policy() {
+        let policy = Policy::new(Hazards::new())
+            .block_above_severity(Severity::High)
+            .block_above_risk_score(9);
+
+        // A route lowers the risk of a critical hazard.
+        let route_hazards = Hazards::init(Hazard::FireHazard);
+        let risks = HazardRisks::init(
+            Hazard::FireHazard,
+            HazardRisk::new(Severity::Negligible, Likelihood::Rare),
+        );
+
+        assert_eq!(
+            policy.exceeded_severity(&route_hazards, &risks),
+            Some(Severity::Critical)
+        );
+        assert_eq!(policy.exceeded_risk_score(&route_hazards, &risks), Some(10));
+    }
 }
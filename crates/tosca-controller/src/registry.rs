@@ -0,0 +1,214 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use tosca::events::EventsDescription;
+use tosca::route::RouteConfigs;
+
+use tracing::warn;
+
+use crate::device::{Description, Device, Devices, NetworkInformation};
+use crate::discovery::check_protocol_version;
+use crate::error::{Error, ErrorKind, Result};
+use crate::events::Events;
+
+// A device entry to be saved into the registry.
+#[derive(Serialize)]
+struct SavedDevice<'a> {
+    network_info: NetworkInformation,
+    description: &'a Description,
+    route_configs: &'a RouteConfigs,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events_description: Option<&'a EventsDescription>,
+}
+
+// A device entry loaded from the registry.
+#[derive(Deserialize)]
+struct LoadedDevice {
+    network_info: NetworkInformation,
+    description: Description,
+    route_configs: RouteConfigs,
+    #[serde(default)]
+    events_description: Option<EventsDescription>,
+}
+
+impl LoadedDevice {
+    fn into_device(self) -> Device {
+        let mut device = Device::init(
+            self.network_info,
+            self.description,
+            self.route_configs,
+            self.events_description.map(Events::new),
+        );
+        // The reachability of a restored device is checked only
+        // when it is contacted for the first time.
+        device.verified = false;
+        device
+    }
+}
+
+#[inline]
+fn registry_error(description: impl std::fmt::Display) -> Error {
+    Error::new(ErrorKind::Registry, description.to_string())
+}
+
+/// A registry which persists [`Devices`] into a `JSON` file.
+///
+/// For each device, the registry stores its network information, its
+/// description, its route configurations, and its events description.
+///
+/// Restored devices are **not** contacted, so their reachability is
+/// revalidated lazily through
+/// [`crate::controller::Controller::verified_device`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Registry {
+    path: PathBuf,
+}
+
+impl Registry {
+    /// Creates a [`Registry`] backed by the file at the given path.
+    #[must_use]
+    #[inline]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the [`Registry`] file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Saves [`Devices`] into the [`Registry`] file, replacing
+    /// its previous content.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the devices cannot be serialized or
+    /// the file cannot be written.
+    pub fn save(&self, devices: &Devices) -> Result<()> {
+        let entries = devices
+            .iter()
+            .map(|device| SavedDevice {
+                // Store the address on which the device has been reached
+                // the last time.
+                network_info: NetworkInformation {
+                    last_reachable_address: device.reachable_address(),
+                    ..device.network_info().clone()
+                },
+                description: device.description(),
+                route_configs: &device.route_configs,
+                events_description: device.events_metadata(),
+            })
+            .collect::<Vec<SavedDevice>>();
+
+        let data = serde_json::to_vec_pretty(&entries).map_err(registry_error)?;
+
+        fs::write(&self.path, data).map_err(|e| {
+            registry_error(format!(
+                "Impossible to write `{}`: {e}",
+                self.path.display()
+            ))
+        })
+    }
+
+    /// Loads [`Devices`] from the [`Registry`] file.
+    ///
+    /// Loaded devices are marked as unverified. Devices whose protocol
+    /// version is incompatible with the controller one are discarded,
+    /// since the registry might have been saved by an older controller.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the file cannot be read or
+    /// its content is not valid.
+    pub fn load(&self) -> Result<Devices> {
+        let data = fs::read(&self.path).map_err(|e| {
+            registry_error(format!("Impossible to read `{}`: {e}", self.path.display()))
+        })?;
+
+        let entries: Vec<LoadedDevice> = serde_json::from_slice(&data).map_err(registry_error)?;
+
+        Ok(Devices::from_devices(
+            entries
+                .into_iter()
+                .filter_map(|entry| {
+                    if let Err(e) = check_protocol_version(entry.description.protocol_version) {
+                        warn!(
+                            "Ignoring device {} restored from the registry: {e}",
+                            entry.network_info.last_reachable_address
+                        );
+                        return None;
+                    }
+                    Some(entry.into_device())
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tosca::device::PROTOCOL_VERSION;
+
+    use crate::device::Devices;
+    use crate::device::tests::{create_light, create_unknown};
+    use crate::error::ErrorKind;
+
+    use super::Registry;
+
+    fn registry(name: &str) -> Registry {
+        Registry::new(
+            std::env::temp_dir().join(format!("tosca-registry-{}-{name}.json", std::process::id())),
+        )
+    }
+
+    #[test]
+    fn save_and_load() {
+        let registry = registry("roundtrip");
+
+        let devices = Devices::from_devices(vec![create_light(), create_unknown()]);
+        registry.save(&devices).unwrap();
+
+        let loaded = registry.load().unwrap();
+        std::fs::remove_file(registry.path()).unwrap();
+
+        assert_eq!(loaded, devices);
+
+        // Loaded devices maintain their identifiers and routes.
+        for (loaded_device, device) in loaded.iter().zip(&devices) {
+            assert_eq!(loaded_device.id(), device.id());
+            assert_eq!(loaded_device.requests_count(), device.requests_count());
+            assert!(!loaded_device.is_verified());
+        }
+    }
+
+    #[test]
+    fn load_incompatible_device() {
+        let registry = registry("incompatible");
+
+        let devices = Devices::from_devices(vec![create_light(), create_unknown()]);
+        registry.save(&devices).unwrap();
+
+        // A device saved with an incompatible protocol version.
+        let mut entries: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(registry.path()).unwrap()).unwrap();
+        entries[0]["description"]["protocol_version"] =
+            serde_json::json!({ "major": PROTOCOL_VERSION.major + 1, "minor": 0 });
+        std::fs::write(registry.path(), entries.to_string()).unwrap();
+
+        let loaded = registry.load().unwrap();
+        std::fs::remove_file(registry.path()).unwrap();
+
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded.iter().next().unwrap().id(), create_unknown().id());
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let registry = registry("missing");
+
+        assert_eq!(registry.load().unwrap_err().kind(), ErrorKind::Registry);
+    }
+}
@@ -4,16 +4,20 @@ use std::future::Future;
 
 use serde::Serialize;
 
-use tracing::error;
+use tracing::{error, info, warn};
 
 use tosca::device::DeviceEnvironment;
-use tosca::hazards::Hazards;
-use tosca::parameters::{ParameterValue, ParametersData, ParametersValues};
+use tosca::hazards::{HazardRisks, Hazards};
+use tosca::parameters::{ParameterValue, ParameterViolation, ParametersData, ParametersValues};
 use tosca::response::{ResponseKind, SERIALIZATION_ERROR};
 use tosca::route::{RestKind, RouteConfig, RouteConfigs};
 
+use crate::client::HttpClient;
+use crate::device::Device;
 use crate::error::{Error, ErrorKind};
-use crate::response::{InfoResponseParser, OkResponseParser, Response, SerialResponseParser};
+use crate::response::{
+    InfoResponseParser, OkResponseParser, Response, SerialResponseParser, error_response,
+};
 
 fn slash_end(s: &str) -> &str {
     if s.len() > 1 && s.ends_with('/') {
@@ -44,12 +48,17 @@ fn compare_values_with_params_data(
             return Err(parameter_error(format!("`{name}` does not exist")));
         };
 
-        if !parameter_value.match_kind(parameter_kind) {
-            return Err(parameter_error(format!(
-                "Found type `{}` for `{name}`, expected type `{}`",
-                parameter_value.as_type(),
-                parameter_kind.as_type(),
-            )));
+        // Reject values with a wrong type, out of range, or off step.
+        match parameter_kind.validate(parameter_value) {
+            Ok(()) => {}
+            Err(ParameterViolation::Type { expected, found }) => {
+                return Err(parameter_error(format!(
+                    "Found type `{found}` for `{name}`, expected type `{expected}`"
+                )));
+            }
+            Err(violation) => {
+                return Err(parameter_error(format!("Invalid `{name}`: {violation}")));
+            }
         }
     }
     Ok(())
@@ -63,11 +72,11 @@ fn parameter_error(message: String) -> Error {
 #[derive(Debug, PartialEq)]
 struct RequestData {
     request: String,
-    parameters: HashMap<String, String>,
+    parameters: HashMap<String, ParameterValue>,
 }
 
 impl RequestData {
-    const fn new(request: String, parameters: HashMap<String, String>) -> Self {
+    const fn new(request: String, parameters: HashMap<String, ParameterValue>) -> Self {
         Self {
             request,
             parameters,
@@ -105,12 +114,18 @@ pub struct RequestInfo<'device> {
     pub rest_kind: RestKind,
     /// Route hazards.
     pub hazards: &'device Hazards,
+    /// Route risks overriding the default ones of its hazards.
+    pub risks: &'device HazardRisks,
     /// Parameters data.
     ///
     /// If the request has no parameters, the reference will be empty.
     pub parameters_data: &'device ParametersData,
     /// Response kind.
     pub response_kind: ResponseKind,
+    /// Fields of the serial response payload.
+    ///
+    /// If the route does not declare them, the reference will be empty.
+    pub serial_payload: &'device ParametersData,
 }
 
 impl<'device> RequestInfo<'device> {
@@ -120,8 +135,10 @@ impl<'device> RequestInfo<'device> {
             description: request.description.as_deref(),
             rest_kind: request.kind,
             hazards: &request.hazards,
+            risks: &request.risks,
             parameters_data: &request.parameters_data,
             response_kind: request.response_kind,
+            serial_payload: &request.serial_payload,
         }
     }
 }
@@ -134,10 +151,13 @@ impl<'device> RequestInfo<'device> {
 pub struct Request {
     pub(crate) kind: RestKind,
     pub(crate) hazards: Hazards,
+    pub(crate) risks: HazardRisks,
     pub(crate) route: String,
+    pub(crate) path: String,
     pub(crate) description: Option<String>,
     pub(crate) parameters_data: ParametersData,
     pub(crate) response_kind: ResponseKind,
+    pub(crate) serial_payload: ParametersData,
     pub(crate) device_environment: DeviceEnvironment,
 }
 
@@ -148,6 +168,13 @@ impl Request {
         &self.hazards
     }
 
+    /// Returns an immutable reference to the request [`HazardRisks`], which
+    /// override the default risks of its [`Hazards`].
+    #[must_use]
+    pub fn risks(&self) -> &HazardRisks {
+        &self.risks
+    }
+
     /// Returns a request [`RestKind`].
     #[must_use]
     pub fn kind(&self) -> RestKind {
@@ -172,23 +199,28 @@ impl Request {
         route_config: RouteConfig,
     ) -> Self {
         let kind = route_config.rest_kind;
-        let route = format!(
-            "{}/{}/{}",
-            slash_end(address),
+        let path = format!(
+            "/{}/{}",
             slash_start_end(main_route),
             slash_start_end(&route_config.data.path)
         );
+        let route = format!("{}{path}", slash_end(address));
         let hazards = route_config.data.hazards;
+        let risks = route_config.data.risks;
         let parameters_data = route_config.data.parameters;
+        let serial_payload = route_config.data.serial_payload;
         let response_kind = route_config.response_kind;
 
         Self {
             kind,
             hazards,
+            risks,
             route,
+            path,
             description: route_config.data.description.map(|s| s.to_string()),
             parameters_data,
             response_kind,
+            serial_payload,
             device_environment,
         }
     }
@@ -208,9 +240,16 @@ impl Request {
 
         let response = retrieve_response().await?;
 
+        if !response.status().is_success() {
+            return error_response(response).await.map(Response::Error);
+        }
+
         Ok(match self.response_kind {
             ResponseKind::Ok => Response::OkBody(OkResponseParser::new(response)),
-            ResponseKind::Serial => Response::SerialBody(SerialResponseParser::new(response)),
+            ResponseKind::Serial => Response::SerialBody(SerialResponseParser::new(
+                response,
+                self.serial_payload.clone(),
+            )),
             ResponseKind::Info => Response::InfoBody(InfoResponseParser::new(response)),
             #[cfg(feature = "stream")]
             ResponseKind::Stream => {
@@ -219,57 +258,88 @@ impl Request {
         })
     }
 
-    pub(crate) async fn plain_send(&self) -> Result<reqwest::Response, Error> {
+    pub(crate) async fn plain_send(
+        &self,
+        client: &HttpClient,
+        device: &Device,
+    ) -> Result<reqwest::Response, Error> {
         let request_data =
             self.request_data(|| self.axum_get_plain(), || self.create_params_plain());
 
-        self.parameters_send(request_data).await
+        self.parameters_send(client, device, request_data).await
     }
 
     pub(crate) async fn create_response(
         &self,
+        client: &HttpClient,
+        device: &Device,
         parameters: &ParametersValues<'_>,
     ) -> Result<reqwest::Response, Error> {
         let request_data = self.create_request(parameters)?;
-        self.parameters_send(request_data).await
+        self.parameters_send(client, device, request_data).await
     }
 
-    async fn parameters_send(&self, request_data: RequestData) -> Result<reqwest::Response, Error> {
+    async fn parameters_send(
+        &self,
+        client: &HttpClient,
+        device: &Device,
+        request_data: RequestData,
+    ) -> Result<reqwest::Response, Error> {
         let RequestData {
             request,
             parameters,
         } = request_data;
 
-        let client = reqwest::Client::new();
-
-        let request_builder = match self.kind {
-            RestKind::Get => client.get(request),
-            RestKind::Post => client.post(request),
-            RestKind::Put => client.put(request),
-            RestKind::Delete => client.delete(request),
-        };
-
-        let request_builder = if self.kind != RestKind::Get && !parameters.is_empty() {
-            request_builder.json(&parameters)
-        } else {
-            request_builder
-        };
+        // Byte streams last indefinitely, so no timeout is applied to them.
+        #[cfg(feature = "stream")]
+        let with_timeout = self.response_kind != ResponseKind::Stream;
+        #[cfg(not(feature = "stream"))]
+        let with_timeout = true;
+
+        let mut last_error = None;
+        for (index, address) in device.candidate_addresses().into_iter().enumerate() {
+            let url = format!("{}{request}", slash_end(&address));
+
+            let result = client
+                .send(
+                    || {
+                        let request_builder = client.request(self.kind, &url, with_timeout);
+                        if self.kind != RestKind::Get && !parameters.is_empty() {
+                            request_builder.json(&parameters)
+                        } else {
+                            request_builder
+                        }
+                    },
+                    self.kind,
+                )
+                .await;
+
+            match result {
+                Ok(response) => {
+                    // The first candidate is the current reachable address.
+                    if index > 0 {
+                        info!("Device {} is now reachable at {address}", device.id());
+                        device.set_reachable_address(address);
+                    }
+                    return Self::check_serialization(response).await;
+                }
+                // A `POST` request which timed out might have been executed,
+                // so it is not sent again to another address.
+                Err(e) if e.is_connect() || (e.is_timeout() && self.kind != RestKind::Post) => {
+                    warn!("Impossible to contact address {address}: {e}");
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
 
-        // Close the connection after issuing a request.
-        let response = request_builder.header("Connection", "close").send().await?;
-
-        // TODO: Analyze the response status.
-        // A 404 status (route not found) might be returned when a
-        // device is down or in case of a malformed route.
-        // A 405 status (method not allowed) in case of a wrong REST method.
-        // If the status is 200, the device response is valid. If the
-        // response is 500, an error occurred during a device operation.
-        //
-        //  Add a logger to record the response. In this way, we do not block
-        //  the process returning an error. An app using the controller as
-        //  dependency can then consult the logger to obtain the internal
-        //  problem.
+        Err(last_error.map_or_else(
+            || Error::new(ErrorKind::Request, "No device address available"),
+            Error::from,
+        ))
+    }
 
+    async fn check_serialization(response: reqwest::Response) -> Result<reqwest::Response, Error> {
         // Checks whether serialization errors have occurred on the device.
         // If the serialization error header is present, the response
         // is considered invalid.
@@ -294,13 +364,13 @@ impl Request {
     fn request_data<A, F>(&self, axum_get: A, params: F) -> RequestData
     where
         A: FnOnce() -> String,
-        F: FnOnce() -> HashMap<String, String>,
+        F: FnOnce() -> HashMap<String, ParameterValue>,
     {
         let request =
             if self.kind == RestKind::Get && self.device_environment == DeviceEnvironment::Os {
                 axum_get()
             } else {
-                self.route.clone()
+                self.path.clone()
             };
 
         let parameters = params();
@@ -319,7 +389,7 @@ impl Request {
     }
 
     fn axum_get_plain(&self) -> String {
-        let mut route = self.route.clone();
+        let mut route = self.path.clone();
         for (_, parameter_kind) in &self.parameters_data {
             // TODO: Consider returning `Option<String>`
             if let Err(e) = write!(
@@ -334,12 +404,12 @@ impl Request {
         route
     }
 
-    fn create_params_plain(&self) -> HashMap<String, String> {
+    fn create_params_plain(&self) -> HashMap<String, ParameterValue> {
         let mut params = HashMap::new();
         for (name, parameter_kind) in &self.parameters_data {
             params.insert(
                 name.clone(),
-                format!("{}", ParameterValue::from_parameter_kind(parameter_kind)),
+                ParameterValue::from_parameter_kind(parameter_kind),
             );
         }
         params
@@ -348,7 +418,7 @@ impl Request {
     // Axum parameters: hello/{{1}}/{{2}}
     //                  hello/0.5/1
     fn axum_get(&self, parameters: &ParametersValues) -> String {
-        let mut route = String::from(&self.route);
+        let mut route = String::from(&self.path);
         for (name, parameter_kind) in &self.parameters_data {
             let value = if let Some(value) = parameters.get(name) {
                 format!("{value}")
@@ -365,17 +435,15 @@ impl Request {
         route
     }
 
-    fn create_params(&self, parameters: &ParametersValues<'_>) -> HashMap<String, String> {
+    // Parameters are sent as typed `JSON` values, so lists and objects
+    // maintain their structure.
+    fn create_params(&self, parameters: &ParametersValues<'_>) -> HashMap<String, ParameterValue> {
         let mut params = HashMap::new();
         for (name, parameter_kind) in &self.parameters_data {
-            let (name, value) = if let Some(value) = parameters.get(name) {
-                (name, format!("{value}"))
-            } else {
-                (
-                    name,
-                    format!("{}", ParameterValue::from_parameter_kind(parameter_kind)),
-                )
-            };
+            let value = parameters.get(name).map_or_else(
+                || ParameterValue::from_parameter_kind(parameter_kind),
+                Clone::clone,
+            );
             params.insert(name.clone(), value);
         }
         params
@@ -387,8 +455,10 @@ mod tests {
     use std::collections::HashMap;
 
     use tosca::device::DeviceEnvironment;
-    use tosca::hazards::{Hazard, Hazards};
-    use tosca::parameters::{ParameterKind, Parameters, ParametersData, ParametersValues};
+    use tosca::hazards::{Hazard, HazardRisk, HazardRisks, Hazards, Likelihood, Severity};
+    use tosca::parameters::{
+        ParameterKind, ParameterValue, Parameters, ParametersData, ParametersValues,
+    };
     use tosca::route::{RestKind, Route, RouteConfig};
 
     use super::{Request, RequestData, ResponseKind, parameter_error};
@@ -396,6 +466,7 @@ mod tests {
     const ADDRESS_ROUTE: &str = "http://tosca.local/";
     const ADDRESS_ROUTE_WITHOUT_SLASH: &str = "http://tosca.local/";
     const COMPLETE_ROUTE: &str = "http://tosca.local/light/route";
+    const ROUTE_PATH: &str = "/light/route";
 
     fn plain_request(route: Route, kind: RestKind, hazards: Hazards) {
         let route = route.serialize_data();
@@ -412,10 +483,13 @@ mod tests {
             Request {
                 kind,
                 hazards,
+                risks: HazardRisks::new(),
                 route: COMPLETE_ROUTE.into(),
+                path: ROUTE_PATH.into(),
                 description,
                 parameters_data: ParametersData::new(),
                 response_kind: ResponseKind::Ok,
+                serial_payload: ParametersData::new(),
                 device_environment: DeviceEnvironment::Os,
             }
         );
@@ -426,14 +500,11 @@ mod tests {
             .with_parameters(
                 Parameters::new()
                     .rangeu64_with_default("rangeu64", (0, 20, 1), 5)
-                    .rangef64("rangef64", (0., 20., 0.1)),
+                    .rangef64("rangef64", (0., 20., 0.1))
+                    .rangei32("rangei32", (-10, 10, 2)),
             )
             .serialize_data();
-        let description = route
-            .data
-            .description
-            .as_ref()
-            .map(std::string::ToString::to_string);
+        let description = route.data.description.as_ref().map(ToString::to_string);
 
         let parameters_data = ParametersData::new()
             .insert(
@@ -443,6 +514,7 @@ mod tests {
                     max: 20,
                     step: 1,
                     default: 5,
+                    unit: None,
                 },
             )
             .insert(
@@ -452,6 +524,17 @@ mod tests {
                     max: 20.,
                     step: 0.1,
                     default: 0.,
+                    unit: None,
+                },
+            )
+            .insert(
+                "rangei32".into(),
+                ParameterKind::RangeI32 {
+                    min: -10,
+                    max: 10,
+                    step: 2,
+                    default: 0,
+                    unit: None,
                 },
             );
 
@@ -462,10 +545,13 @@ mod tests {
             Request {
                 kind,
                 hazards: hazards.clone(),
+                risks: HazardRisks::new(),
                 route: COMPLETE_ROUTE.into(),
+                path: ROUTE_PATH.into(),
                 description,
                 parameters_data,
                 response_kind: ResponseKind::Ok,
+                serial_payload: ParametersData::new(),
                 device_environment: DeviceEnvironment::Os,
             }
         );
@@ -484,17 +570,38 @@ mod tests {
             ))
         );
 
-        let mut parameters = HashMap::with_capacity(2);
-        parameters.insert("rangeu64".into(), "3".into());
-        parameters.insert("rangef64".into(), "0".into());
+        // Out of range parameter.
+        assert_eq!(
+            request.create_request(ParametersValues::new().u64("rangeu64", 21)),
+            Err(parameter_error(
+                "Invalid `rangeu64`: value greater than the maximum `20`".into()
+            ))
+        );
 
+        // Off step parameter.
         assert_eq!(
-            request.create_request(ParametersValues::new().u64("rangeu64", 3)),
+            request.create_request(ParametersValues::new().f64("rangef64", 0.15)),
+            Err(parameter_error(
+                "Invalid `rangef64`: value not a multiple of the step `0.1`".into()
+            ))
+        );
+
+        let mut parameters = HashMap::with_capacity(3);
+        parameters.insert("rangeu64".into(), ParameterValue::U64(3));
+        parameters.insert("rangef64".into(), ParameterValue::F64(0.));
+        parameters.insert("rangei32".into(), ParameterValue::I32(-4));
+
+        assert_eq!(
+            request.create_request(
+                ParametersValues::new()
+                    .u64("rangeu64", 3)
+                    .i32("rangei32", -4)
+            ),
             Ok(RequestData {
                 request: if kind == RestKind::Get {
-                    format!("{COMPLETE_ROUTE}/3/0")
+                    format!("{ROUTE_PATH}/3/0/-4")
                 } else {
-                    COMPLETE_ROUTE.into()
+                    ROUTE_PATH.into()
                 },
                 parameters,
             })
@@ -512,10 +619,13 @@ mod tests {
             Request {
                 kind: RestKind::Put,
                 hazards: Hazards::new(),
+                risks: HazardRisks::new(),
                 route: COMPLETE_ROUTE.into(),
+                path: ROUTE_PATH.into(),
                 description: None,
                 parameters_data: ParametersData::new(),
                 response_kind: ResponseKind::Ok,
+                serial_payload: ParametersData::new(),
                 device_environment: DeviceEnvironment::Os,
             }
         );
@@ -568,6 +678,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_request_with_hazard_risks() {
+        let risk = HazardRisk::new(Severity::Low, Likelihood::Rare);
+        let route = Route::get("Route", "/route")
+            .with_hazard(Hazard::FireHazard)
+            .with_hazard_risk(Hazard::FireHazard, risk)
+            .serialize_data();
+
+        let request = Request::new(ADDRESS_ROUTE, "light/", DeviceEnvironment::Os, route);
+
+        assert_eq!(
+            request.risks(),
+            &HazardRisks::init(Hazard::FireHazard, risk)
+        );
+        assert_eq!(
+            request.hazards().max_severity(request.risks()),
+            Some(Severity::Low)
+        );
+    }
+
     #[test]
     fn create_get_request_with_parameters() {
         request_with_parameters(
@@ -609,4 +739,79 @@ mod tests {
             &hazards,
         );
     }
+
+    #[test]
+    fn create_request_with_choice() {
+        let route = Route::put("Route", "/route")
+            .with_parameters(Parameters::new().choice("mode", ["auto", "eco"]))
+            .serialize_data();
+        let request = Request::new(ADDRESS_ROUTE, "light/", DeviceEnvironment::Os, route);
+
+        assert_eq!(
+            request.create_request(
+                ParametersValues::new().characters_sequence("mode", "turbo".into())
+            ),
+            Err(parameter_error(
+                "Invalid `mode`: value not among the allowed options: `auto`, `eco`".into()
+            ))
+        );
+
+        let mut parameters = HashMap::with_capacity(1);
+        parameters.insert("mode".into(), ParameterValue::CharsSequence("eco".into()));
+
+        assert_eq!(
+            request
+                .create_request(ParametersValues::new().characters_sequence("mode", "eco".into())),
+            Ok(RequestData {
+                request: ROUTE_PATH.into(),
+                parameters,
+            })
+        );
+    }
+
+    #[test]
+    fn create_request_with_list_and_object() {
+        let route = Route::put("Route", "/route")
+            .with_parameters(
+                Parameters::new()
+                    .list(
+                        "zones",
+                        ParameterKind::U8 {
+                            default: 0,
+                            min: u8::MAX,
+                            max: u8::MIN,
+                            unit: None,
+                        },
+                    )
+                    .object("time", Parameters::new().u8("hour", 0).u8("minute", 0)),
+            )
+            .serialize_data();
+        let request = Request::new(ADDRESS_ROUTE, "light/", DeviceEnvironment::Os, route);
+
+        let mut time = ParametersValues::new();
+        time.u8("hour", 7).u8("minute", 30);
+        let request_data = request
+            .create_request(
+                ParametersValues::new()
+                    .list("zones", vec![ParameterValue::U8(1), ParameterValue::U8(3)])
+                    .object("time", time),
+            )
+            .unwrap();
+
+        // Lists and objects are sent as structured values.
+        assert_eq!(
+            serde_json::to_value(&request_data.parameters).unwrap(),
+            serde_json::json!({"zones": [1, 3], "time": {"hour": 7, "minute": 30}})
+        );
+
+        // Missing fields are reported.
+        let mut time = ParametersValues::new();
+        time.u8("hour", 7);
+        assert_eq!(
+            request.create_request(ParametersValues::new().object("time", time)),
+            Err(parameter_error(
+                "Invalid `time`: missing field `minute`".into()
+            ))
+        );
+    }
 }
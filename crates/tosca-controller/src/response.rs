@@ -1,6 +1,8 @@
-use tosca::response::{InfoResponse, OkResponse, SerialResponse};
+use tosca::encoding::Encoding;
+use tosca::parameters::{ParametersData, ParametersValues};
+use tosca::response::{ErrorResponse, InfoResponse, OkResponse, SerialResponse};
 
-use reqwest::Response as ReqwestResponse;
+use reqwest::{Response as ReqwestResponse, StatusCode, header::CONTENT_TYPE};
 
 use serde::{Serialize, de::DeserializeOwned};
 
@@ -12,14 +14,67 @@ use crate::error::{Error, ErrorKind, Result};
 // InfoCollector --> Save Info responses in order to maintain a history.
 // StreamCollector --> Save information about a Stream Response before and after
 
-async fn json_response<T>(response: ReqwestResponse) -> Result<T>
-where
-    T: Serialize + DeserializeOwned,
-{
+// Retrieves the encoding of a response body from its `Content-Type` header.
+//
+// `JSON` is assumed when the header is missing or unknown.
+fn response_encoding(response: &ReqwestResponse) -> Encoding {
     response
-        .json::<T>()
-        .await
-        .map_err(|e| Error::new(ErrorKind::JsonResponse, format!("Json error caused by {e}")))
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(Encoding::from_content_type)
+        .unwrap_or_default()
+}
+
+// Decodes a response body according to its encoding.
+pub(crate) async fn decode_response<T: DeserializeOwned>(response: ReqwestResponse) -> Result<T> {
+    let encoding = response_encoding(&response);
+    let body = response.bytes().await?;
+    encoding.decode(&body).map_err(|e| {
+        Error::new(
+            ErrorKind::JsonResponse,
+            format!("Response error caused by {e}"),
+        )
+    })
+}
+
+// Analyzes an unsuccessful device response.
+//
+// A 404 status means the route does not exist on the device, or the device
+// is down, while a 405 status means a wrong `REST` method has been used.
+// For any other status, the body is parsed as a device `ErrorResponse`.
+pub(crate) async fn error_response(response: ReqwestResponse) -> Result<ErrorResponse<'static>> {
+    let status = response.status();
+    let url = response.url().to_string();
+
+    match status {
+        StatusCode::NOT_FOUND => Err(Error::new(
+            ErrorKind::NotFound,
+            format!("Route `{url}` not found"),
+        )),
+        StatusCode::METHOD_NOT_ALLOWED => Err(Error::new(
+            ErrorKind::MethodNotAllowed,
+            format!("Method not allowed for route `{url}`"),
+        )),
+        _ => {
+            let encoding = response_encoding(&response);
+            let body = response.bytes().await?;
+            encoding.decode::<ErrorResponse>(&body).map_err(|_| {
+                let kind = if status.is_server_error() {
+                    ErrorKind::DeviceInternal
+                } else {
+                    ErrorKind::Request
+                };
+                Error::new(
+                    kind,
+                    format!(
+                        "Route `{url}` responded with status {status}: {}",
+                        String::from_utf8_lossy(&body)
+                    ),
+                )
+            })
+        }
+    }
 }
 
 /// An [`OkResponse`] body parser.
@@ -34,7 +89,7 @@ impl OkResponseParser {
     /// parsing error will be raised. This may occur due to an incorrect format
     /// or because the binary data contains syntactic or semantic errors.
     pub async fn parse_body(self) -> Result<OkResponse> {
-        json_response::<OkResponse>(self.0).await
+        decode_response::<OkResponse>(self.0).await
     }
 
     pub(crate) const fn new(response: ReqwestResponse) -> Self {
@@ -43,7 +98,7 @@ impl OkResponseParser {
 }
 
 /// A [`SerialResponse`] body parser.
-pub struct SerialResponseParser(ReqwestResponse);
+pub struct SerialResponseParser(ReqwestResponse, ParametersData);
 
 impl SerialResponseParser {
     /// Parses the internal response body to retrieve a [`SerialResponse`].
@@ -54,11 +109,47 @@ impl SerialResponseParser {
     /// parsing error will be raised. This may occur due to an incorrect format
     /// or because the binary data contains syntactic or semantic errors.
     pub async fn parse_body<T: Serialize + DeserializeOwned>(self) -> Result<SerialResponse<T>> {
-        json_response::<SerialResponse<T>>(self.0).await
+        decode_response::<SerialResponse<T>>(self.0).await
     }
 
-    pub(crate) const fn new(response: ReqwestResponse) -> Self {
-        Self(response)
+    /// Parses the internal response body into [`ParametersValues`],
+    /// interpreting and validating each field according to the payload
+    /// fields declared by the route.
+    ///
+    /// # Errors
+    ///
+    /// A parsing error is raised when the route does not declare any payload
+    /// field, when the response body is not an object, or when its
+    /// fields do not comply with the declared ones.
+    pub async fn parse_values(self) -> Result<ParametersValues<'static>> {
+        let serial_payload = self.1;
+        if serial_payload.is_empty() {
+            return Err(Error::new(
+                ErrorKind::JsonResponse,
+                "The route does not declare the fields of its serial payload",
+            ));
+        }
+
+        let body = decode_response::<serde_json::Value>(self.0).await?;
+        let values = serial_payload.values_from_json(&body).ok_or_else(|| {
+            Error::new(
+                ErrorKind::JsonResponse,
+                format!("Serial payload `{body}` does not match the declared fields"),
+            )
+        })?;
+
+        serial_payload.validate(&values).map_err(|violation| {
+            Error::new(
+                ErrorKind::JsonResponse,
+                format!("Invalid serial payload: {violation}"),
+            )
+        })?;
+
+        Ok(values)
+    }
+
+    pub(crate) const fn new(response: ReqwestResponse, serial_payload: ParametersData) -> Self {
+        Self(response, serial_payload)
     }
 }
 
@@ -74,7 +165,7 @@ impl InfoResponseParser {
     /// parsing error will be raised. This may occur due to an incorrect format
     /// or because the binary data contains syntactic or semantic errors.
     pub async fn parse_body(self) -> Result<InfoResponse> {
-        json_response::<InfoResponse>(self.0).await
+        decode_response::<InfoResponse>(self.0).await
     }
 
     pub(crate) const fn new(response: ReqwestResponse) -> Self {
@@ -124,4 +215,126 @@ pub enum Response {
     /// A byte stream response body.
     #[cfg(feature = "stream")]
     StreamBody(StreamResponse),
+    /// An [`ErrorResponse`] returned by a device when an operation fails.
+    Error(ErrorResponse<'static>),
+}
+
+#[cfg(test)]
+mod tests {
+    use tosca::parameters::{Parameters, ParametersData, ParametersValues};
+    use tosca::response::{ErrorKind as DeviceErrorKind, ErrorResponse};
+
+    use reqwest::{Response as ReqwestResponse, StatusCode};
+
+    use crate::error::{Error, ErrorKind};
+
+    use super::{SerialResponseParser, error_response};
+
+    fn response(status: StatusCode, body: &str) -> ReqwestResponse {
+        http::Response::builder()
+            .status(status)
+            .body(body.to_owned())
+            .unwrap()
+            .into()
+    }
+
+    fn error_kind<T: std::fmt::Debug>(result: Result<T, Error>) -> ErrorKind {
+        result.unwrap_err().kind()
+    }
+
+    #[tokio::test]
+    async fn analyze_error_status() {
+        assert_eq!(
+            error_kind(error_response(response(StatusCode::NOT_FOUND, "")).await),
+            ErrorKind::NotFound
+        );
+
+        assert_eq!(
+            error_kind(error_response(response(StatusCode::METHOD_NOT_ALLOWED, "")).await),
+            ErrorKind::MethodNotAllowed
+        );
+
+        // A device error response is parsed.
+        let body = serde_json::to_string(&ErrorResponse::with_description_error(
+            DeviceErrorKind::InvalidData,
+            "Invalid brightness",
+            "Value out of range",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            error_response(response(StatusCode::INTERNAL_SERVER_ERROR, &body))
+                .await
+                .unwrap(),
+            ErrorResponse::with_description_error(
+                DeviceErrorKind::InvalidData,
+                "Invalid brightness",
+                "Value out of range",
+            )
+        );
+
+        // Internal failures without an error response.
+        assert_eq!(
+            error_kind(error_response(response(StatusCode::INTERNAL_SERVER_ERROR, "panic")).await),
+            ErrorKind::DeviceInternal
+        );
+
+        assert_eq!(
+            error_kind(error_response(response(StatusCode::BAD_REQUEST, "")).await),
+            ErrorKind::Request
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_serial_values() {
+        let serial_payload = Parameters::new()
+            .f64("temperature", 0.)
+            .u8_with_limits("humidity", 0, 0, 100)
+            .serialize_data();
+
+        let parse = |body: &str| {
+            SerialResponseParser::new(response(StatusCode::OK, body), serial_payload.clone())
+                .parse_values()
+        };
+
+        let mut values = ParametersValues::new();
+        values.f64("temperature", 21.5).u8("humidity", 40);
+        assert_eq!(
+            parse(r#"{"temperature": 21.5, "humidity": 40}"#)
+                .await
+                .unwrap(),
+            values
+        );
+
+        // A field with a wrong type.
+        assert_eq!(
+            error_kind(parse(r#"{"temperature": "warm", "humidity": 40}"#).await),
+            ErrorKind::JsonResponse
+        );
+
+        // A field out of its limits.
+        assert_eq!(
+            parse(r#"{"temperature": 21.5, "humidity": 140}"#).await,
+            Err(Error::new(
+                ErrorKind::JsonResponse,
+                "Invalid serial payload: field `humidity`: value greater than the maximum `100`"
+            ))
+        );
+
+        // A missing field.
+        assert_eq!(
+            error_kind(parse(r#"{"temperature": 21.5}"#).await),
+            ErrorKind::JsonResponse
+        );
+
+        // A route without a declared payload.
+        assert_eq!(
+            error_kind(
+                SerialResponseParser::new(response(StatusCode::OK, "{}"), ParametersData::new())
+                    .parse_values()
+                    .await
+            ),
+            ErrorKind::JsonResponse
+        );
+    }
 }
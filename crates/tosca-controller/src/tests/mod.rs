@@ -1,8 +1,11 @@
+// Test route handlers return the `ErrorResponse` type of `tosca-os`.
+#![allow(clippy::result_large_err)]
+
 use std::net::Ipv4Addr;
 use std::time::Duration;
 
 use tosca::device::{DeviceEnvironment, DeviceKindId};
-use tosca::hazards::{Hazard, Hazards};
+use tosca::hazards::{Hazard, HazardRisks, Hazards};
 use tosca::parameters::{ParameterKind, Parameters, ParametersData};
 use tosca::response::ResponseKind;
 use tosca::route::{LightOffRoute, LightOnRoute, RestKind, Route};
@@ -128,6 +131,10 @@ fn build_route(device: &Device, route: &str) -> String {
     )
 }
 
+fn build_path(device: &Device, route: &str) -> String {
+    format!("{}{}", device.description().main_route, route)
+}
+
 fn check_request(
     device: &Device,
     route: &str,
@@ -144,10 +151,13 @@ fn check_request(
         Some(&Request {
             kind,
             hazards,
+            risks: HazardRisks::new(),
             route: build_route(device, route),
+            path: build_path(device, route),
             description: Some(description.to_string()),
             parameters_data,
             response_kind,
+            serial_payload: ParametersData::new(),
             device_environment: DeviceEnvironment::Os,
         })
     );
@@ -195,6 +205,7 @@ pub(crate) fn compare_device_data(device: &Device) {
                 max: 20,
                 step: 1,
                 default: 0,
+                unit: None,
             },
         );
         // Check "/toggle" request
@@ -2,10 +2,11 @@ use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 
-use tosca::device::{DeviceData, DeviceEnvironment, DeviceKind, DeviceKindId};
-use tosca::hazards::Hazard;
+use tosca::device::{
+    Capability, DeviceData, DeviceEnvironment, DeviceIdentity, DeviceKind, DeviceKindId,
+};
 use tosca::response::ResponseKind;
-use tosca::route::{Route, RouteConfigs};
+use tosca::route::{LIGHT_ROUTES, Route, RouteConfigs};
 
 use esp_radio::wifi::WifiDevice;
 
@@ -22,9 +23,6 @@ use crate::state::{State, ValueFromRef};
 // Default main route.
 const MAIN_ROUTE: &str = "/light";
 
-// Allowed hazards.
-const ALLOWED_HAZARDS: &[Hazard] = &[Hazard::FireHazard, Hazard::ElectricEnergyConsumption];
-
 /// A `light` device.
 ///
 /// Its methods guide in the definition of a correct light.
@@ -220,6 +218,27 @@ where
         self
     }
 
+    /// Sets the [`DeviceIdentity`].
+    ///
+    /// The identity is advertised to controllers together with the
+    /// device description.
+    #[must_use]
+    #[inline]
+    pub fn identity(mut self, identity: DeviceIdentity) -> Self {
+        self.device_data = self.device_data.identity(identity);
+        self
+    }
+
+    /// Declares a [`Capability`] of the light.
+    ///
+    /// The routes required by the capability must be added to the light.
+    #[must_use]
+    #[inline]
+    pub fn capability(mut self, capability: Capability) -> Self {
+        self.device_data.capabilities.add(capability);
+        self
+    }
+
     /// Adds a [`Route`] with a stateless handler that returns an [`OkResponse`]
     /// on success and an [`ErrorResponse`] on failure.
     #[must_use]
@@ -344,6 +363,12 @@ where
     #[must_use]
     #[inline]
     pub fn build(self) -> Device<S> {
+        for capability in &self.device_data.capabilities {
+            if !capability.is_implemented_by(&self.device_data.route_configs) {
+                error!("The routes of the `{capability}` capability are missing!");
+            }
+        }
+
         Device::new(
             self.wifi_mac,
             self.state,
@@ -364,7 +389,7 @@ where
         F: FnOnce(Self) -> Self,
     {
         let route_config = route
-            .remove_prohibited_hazards(ALLOWED_HAZARDS)
+            .remove_prohibited_hazards(LIGHT_ROUTES.allowed_hazards)
             .serialize_data()
             .change_response_kind(response_kind);
 
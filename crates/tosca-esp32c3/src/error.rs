@@ -5,6 +5,8 @@ pub enum ErrorKind {
     EmptyEventsManager,
     /// `DNS` error.
     Dns,
+    /// Event registration error.
+    EventRegistration,
     /// `mDNS` error.
     MDns,
     /// `MQTT` error.
@@ -28,6 +30,7 @@ impl ErrorKind {
         match self {
             Self::EmptyEventsManager => "Empty events manager",
             Self::Dns => "DNS",
+            Self::EventRegistration => "Event registration",
             Self::MDns => "mDNS",
             Self::Mqtt => "MQTT",
             Self::Server => "Server",
@@ -1,14 +1,10 @@
-pub(crate) mod bool;
-pub(crate) mod f32;
-pub(crate) mod f64;
-pub(crate) mod i32;
-pub(crate) mod u8;
-
 use core::marker::PhantomData;
 
 use embassy_time::Timer;
 
-use crate::events::{WAIT_FOR_MILLISECONDS, WRITE_ON_NETWORK};
+use tosca::events::EventType;
+
+use crate::events::{EVENTS, LastEmission, WAIT_FOR_MILLISECONDS, WRITE_ON_NETWORK};
 
 #[inline]
 async fn notify_network_task() {
@@ -21,7 +17,40 @@ async fn notify_network_task() {
 }
 
 /// A notifier for signaling an [`tosca::events::Event`].
-pub struct Notifier<T: Clone + Copy> {
+///
+/// The event is updated only when its values satisfy the event
+/// emission rules.
+pub struct Notifier<T: EventType> {
     index: usize,
+    last_emission: LastEmission,
     phantom: PhantomData<T>,
 }
+
+impl<T: EventType> Notifier<T> {
+    /// Updates the [`tosca::events::Event`].
+    #[inline]
+    pub async fn update_event(&self, value: T) {
+        // Update the event value.
+        {
+            let value = value.into_value();
+            let mut events = EVENTS.lock().await;
+            let emit = events
+                .events_as_slice()
+                .get(self.index)
+                .is_some_and(|event| event.should_emit(&value, self.last_emission.elapsed()));
+            if !emit || !events.update_value(self.index, value) {
+                return;
+            }
+            self.last_emission.record();
+        }
+        notify_network_task().await;
+    }
+
+    pub(crate) const fn new(index: usize) -> Self {
+        Self {
+            index,
+            last_emission: LastEmission::new(),
+            phantom: PhantomData,
+        }
+    }
+}
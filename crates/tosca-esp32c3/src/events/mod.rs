@@ -8,25 +8,31 @@ pub mod interrupt;
 /// A set of notifiers designed to manage periodic events.
 pub mod periodic;
 
+use core::cell::Cell;
 use core::net::IpAddr;
+use core::pin::Pin;
 use core::time::Duration;
 
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
-use embassy_executor::{SpawnToken, Spawner};
+use embassy_executor::Spawner;
 use embassy_net::{IpAddress, Stack, dns::DnsQueryType};
-use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::blocking_mutex::{Mutex as BlockingMutex, raw::CriticalSectionRawMutex};
 use embassy_sync::mutex::Mutex;
 use embassy_sync::signal::Signal;
-use embassy_time::Timer;
+use embassy_time::{Instant, Timer};
 
 use esp_hal::gpio::AnyPin;
 
 use log::{debug, error, info};
 
+use tosca::encoding::Encoding;
 use tosca::events::{
-    BrokerData as ToscaBrokerData, Event, Events, EventsDescription, PeriodicEvent, Topic,
+    BrokerData as ToscaBrokerData, EmissionRules, Event, EventType, Events, EventsDescription,
+    PeriodicEvent, Topic,
 };
+use tosca::values::Timestamp;
 
 use crate::device::Device;
 use crate::error::{Error, ErrorKind};
@@ -37,40 +43,11 @@ use broker::BrokerData;
 use mqtt::Mqtt;
 use topic::TopicBuilder;
 
-use super::events::interrupt::{
-    Notifier,
-    bool::{BoolFn, BoolFnPinless, monitor_bool_event, monitor_bool_event_pinless},
-    f32::{F32Fn, F32FnPinless, monitor_f32_event, monitor_f32_event_pinless},
-    f64::{F64Fn, F64FnPinless, monitor_f64_event, monitor_f64_event_pinless},
-    i32::{I32Fn, I32FnPinless, monitor_i32_event, monitor_i32_event_pinless},
-    u8::{U8Fn, U8FnPinless, monitor_u8_event, monitor_u8_event_pinless},
-};
-use super::events::periodic::{
-    PeriodicNotifier,
-    bool::{
-        PeriodicBoolFn, PeriodicBoolFnPinless, monitor_periodic_bool_event,
-        monitor_periodic_bool_event_pinless,
-    },
-    f32::{
-        PeriodicF32Fn, PeriodicF32FnPinless, monitor_periodic_f32_event,
-        monitor_periodic_f32_event_pinless,
-    },
-    f64::{
-        PeriodicF64Fn, PeriodicF64FnPinless, monitor_periodic_f64_event,
-        monitor_periodic_f64_event_pinless,
-    },
-    i32::{
-        PeriodicI32Fn, PeriodicI32FnPinless, monitor_periodic_i32_event,
-        monitor_periodic_i32_event_pinless,
-    },
-    u8::{
-        PeriodicU8Fn, PeriodicU8FnPinless, monitor_periodic_u8_event,
-        monitor_periodic_u8_event_pinless,
-    },
-};
+use super::events::interrupt::Notifier;
+use super::events::periodic::PeriodicNotifier;
 
-// Internal array capacity
-const CAPACITY: usize = 4;
+// Maximum number of event tasks
+const MAX_EVENT_TASKS: usize = 16;
 
 // Time to wait, in milliseconds, after completing a task operation
 const WAIT_FOR_MILLISECONDS: u64 = 200;
@@ -93,6 +70,38 @@ static EVENTS: Mutex<CriticalSectionRawMutex, Events> = Mutex::new(Events::empty
 // Signal that enables network transmission
 static WRITE_ON_NETWORK: Signal<CriticalSectionRawMutex, u8> = Signal::new();
 
+// Time of the last emission of an event.
+pub(crate) struct LastEmission(BlockingMutex<CriticalSectionRawMutex, Cell<Option<Instant>>>);
+
+impl LastEmission {
+    pub(crate) const fn new() -> Self {
+        Self(BlockingMutex::new(Cell::new(None)))
+    }
+
+    // Returns the time elapsed since the last emission, if any.
+    pub(crate) fn elapsed(&self) -> Option<Duration> {
+        self.0
+            .lock(Cell::get)
+            .map(|instant| Duration::from_micros(instant.elapsed().as_micros()))
+    }
+
+    // Records an emission at the current time.
+    pub(crate) fn record(&self) {
+        self.0.lock(|last| last.set(Some(Instant::now())));
+    }
+}
+
+// A future monitoring an event through its notifier.
+//
+// Embassy tasks do not support generics, so the future is boxed to erase
+// the type of its notifier.
+type EventFuture = Pin<Box<dyn Future<Output = ()> + Send + Sync + 'static>>;
+
+#[embassy_executor::task(pool_size = MAX_EVENT_TASKS)]
+async fn monitor_event(future: EventFuture) {
+    future.await;
+}
+
 /// Events configuration.
 ///
 /// Defines all the data required to execute event tasks.
@@ -104,6 +113,8 @@ where
     stack: Stack<'static>,
     broker: BrokerData,
     topic: Topic,
+    encoding: Encoding,
+    clock: fn() -> Option<Timestamp>,
     device: Device<S>,
 }
 
@@ -130,13 +141,43 @@ where
                 .suffix("events")
                 .mac(device.wifi_mac)
                 .build(),
+            encoding: Encoding::Json,
+            clock: || None,
             device,
         }
     }
+
+    /// Sets the clock which timestamps the published events.
+    ///
+    /// The clock returns [`None`] when the current time is not known yet,
+    /// for example before its synchronization with a time server.
+    /// Without a clock, events are published without timestamp.
+    #[inline]
+    #[must_use]
+    pub fn clock(mut self, clock: fn() -> Option<Timestamp>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Publishes events in the compact `CBOR` binary encoding instead
+    /// of `JSON`.
+    #[cfg(feature = "cbor")]
+    #[inline]
+    #[must_use]
+    pub fn cbor(mut self) -> Self {
+        self.encoding = Encoding::Cbor;
+        self
+    }
 }
 
 #[embassy_executor::task]
-async fn write_on_network(stack: Stack<'static>, remote_endpoint: (IpAddress, u16), topic: Topic) {
+async fn write_on_network(
+    stack: Stack<'static>,
+    remote_endpoint: (IpAddress, u16),
+    topic: Topic,
+    encoding: Encoding,
+    clock: fn() -> Option<Timestamp>,
+) {
     // This task is scheduled to run last, so it is assigned a lower priority.
     Timer::after_secs(LOWER_PRIORITY).await;
 
@@ -219,11 +260,15 @@ async fn write_on_network(stack: Stack<'static>, remote_endpoint: (IpAddress, u1
             let _ = WRITE_ON_NETWORK.wait().await;
         }
         // The lock will be released at the end of this scope,
-        // once the JSON data has been retrieved.
-        let json_data = { serde_json::to_vec(&*EVENTS.lock().await) };
+        // once the data has been encoded as a new frame.
+        let encoded_data = {
+            let mut events = EVENTS.lock().await;
+            events.next_frame(clock());
+            encoding.encode(&*events)
+        };
 
         // Serialize data
-        let data = match json_data {
+        let data = match encoded_data {
             Ok(data) => data,
             Err(e) => {
                 error!("Error retrieving data: {e}");
@@ -249,12 +294,16 @@ async fn write_on_network(stack: Stack<'static>, remote_endpoint: (IpAddress, u1
 /// An event manager.
 ///
 /// Validates the events data and executes the corresponding tasks.
+///
+/// Events are registered into the events shared with the notifiers as soon
+/// as they are added, so each notifier finds its event when its task runs.
 pub struct EventsManager<S>
 where
     S: ValueFromRef + Send + Sync + 'static,
 {
     config: EventsConfig<S>,
-    events: Events,
+    rules: Vec<(&'static str, EmissionRules)>,
+    error: Option<Error>,
 }
 
 impl<S> EventsManager<S>
@@ -262,778 +311,135 @@ where
     S: ValueFromRef + Send + Sync + 'static,
 {
     /// Configures the [`EventsManager`].
-    ///
-    /// Allocates internal structures with a fixed amount of memory.
     #[inline]
     #[must_use]
     pub fn config(config: EventsConfig<S>) -> Self {
         Self {
             config,
-            events: Events::with_capacity(CAPACITY),
-        }
-    }
-
-    /// Monitors a pin with an [`Event<bool>`] notifier.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn bool_event<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-        pin: AnyPin<'static>,
-    ) -> Self
-    where
-        F: Fn(AnyPin<'static>, Notifier<bool>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.bool_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::bool(name).description(description);
-        let bool_notifier = Notifier::bool(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: BoolFn = Box::new(move |pin, bool_notifier| Box::pin(func(pin, bool_notifier)));
-        let task = monitor_bool_event(event, pin, bool_notifier, func);
-
-        self.spawn(name, task, |events| events.add_bool_event(event))
-    }
-
-    /// Monitors an [`Event<bool>`] notifier not tied to a pin.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn bool_event_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-    ) -> Self
-    where
-        F: Fn(Notifier<bool>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.bool_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::bool(name).description(description);
-        let bool_notifier = Notifier::bool(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: BoolFnPinless = Box::new(move |bool_notifier| Box::pin(func(bool_notifier)));
-        let task = monitor_bool_event_pinless(event, bool_notifier, func);
-
-        self.spawn(name, task, |events| events.add_bool_event(event))
-    }
-
-    /// Monitors a pin with a [`PeriodicEvent<bool>`] notifier.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn periodic_bool<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-        pin: AnyPin<'static>,
-    ) -> Self
-    where
-        F: Fn(AnyPin<'static>, PeriodicNotifier<bool>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.periodic_bool_events_as_slice();
-        let len = events_ref.len();
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = PeriodicEvent::bool(Event::bool(name).description(description), interval);
-        let periodic_bool_notifier = PeriodicNotifier::bool(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicBoolFn =
-            Box::new(move |pin, bool_notifier| Box::pin(func(pin, bool_notifier)));
-        let task = monitor_periodic_bool_event(event, pin, periodic_bool_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_bool_event(event))
-    }
-
-    /// Monitors a [`PeriodicEvent<bool>`] notifier not tied to a pin.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn periodic_bool_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-    ) -> Self
-    where
-        F: Fn(PeriodicNotifier<bool>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.periodic_bool_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = PeriodicEvent::bool(Event::bool(name).description(description), interval);
-        let periodic_bool_notifier = PeriodicNotifier::bool(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicBoolFnPinless = Box::new(move |notifier| Box::pin(func(notifier)));
-        let task = monitor_periodic_bool_event_pinless(event, periodic_bool_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_bool_event(event))
-    }
-
-    /// Monitors a pin with an [`Event<u8>`] notifier.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn u8_event<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-        pin: AnyPin<'static>,
-    ) -> Self
-    where
-        F: Fn(AnyPin<'static>, Notifier<u8>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.u8_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::u8(name).description(description);
-        let u8_notifier = Notifier::u8(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: U8Fn = Box::new(move |pin, u8_notifier| Box::pin(func(pin, u8_notifier)));
-        let task = monitor_u8_event(event, pin, u8_notifier, func);
-
-        self.spawn(name, task, |events| events.add_u8_event(event))
-    }
-
-    /// Monitors an [`Event<u8>`] notifier not tied to a pin.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn u8_event_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-    ) -> Self
-    where
-        F: Fn(Notifier<u8>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.u8_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::u8(name).description(description);
-        let u8_notifier = Notifier::u8(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: U8FnPinless = Box::new(move |u8_notifier| Box::pin(func(u8_notifier)));
-        let task = monitor_u8_event_pinless(event, u8_notifier, func);
-
-        self.spawn(name, task, |events| events.add_u8_event(event))
-    }
-
-    /// Monitors a pin with a [`PeriodicEvent<u8>`] notifier.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn periodic_u8<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-        pin: AnyPin<'static>,
-    ) -> Self
-    where
-        F: Fn(AnyPin<'static>, PeriodicNotifier<u8>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.periodic_u8_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = PeriodicEvent::u8(Event::u8(name).description(description), interval);
-        let periodic_u8_notifier = PeriodicNotifier::u8(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicU8Fn = Box::new(move |pin, u8_notifier| Box::pin(func(pin, u8_notifier)));
-        let task = monitor_periodic_u8_event(event, pin, periodic_u8_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_u8_event(event))
-    }
-
-    /// Monitors a [`PeriodicEvent<u8>`] notifier not tied to a pin.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn periodic_u8_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-    ) -> Self
-    where
-        F: Fn(PeriodicNotifier<u8>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.periodic_u8_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = PeriodicEvent::u8(Event::u8(name).description(description), interval);
-        let periodic_u8_notifier = PeriodicNotifier::u8(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicU8FnPinless = Box::new(move |notifier| Box::pin(func(notifier)));
-        let task = monitor_periodic_u8_event_pinless(event, periodic_u8_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_u8_event(event))
-    }
-
-    /// Monitors a pin with an [`Event<i32>`] notifier.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn i32_event<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-        pin: AnyPin<'static>,
-    ) -> Self
-    where
-        F: Fn(AnyPin<'static>, Notifier<i32>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.i32_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
+            rules: Vec::new(),
+            error: None,
         }
-
-        let event = Event::i32(name).description(description);
-        let i32_notifier = Notifier::i32(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: I32Fn = Box::new(move |pin, i32_notifier| Box::pin(func(pin, i32_notifier)));
-        let task = monitor_i32_event(event, pin, i32_notifier, func);
-
-        self.spawn(name, task, |events| events.add_i32_event(event))
     }
 
-    /// Monitors an [`Event<i32>`] notifier not tied to a pin.
+    /// Sets the [`EmissionRules`] of the events with the given name.
     ///
-    /// Discards the event if it matches an existing one.
+    /// Rules must be set before adding the events they refer to, since
+    /// events are monitored as soon as they are added.
+    /// Notifiers update the events and notify the network task only when
+    /// their values satisfy the rules.
     #[inline]
     #[must_use]
-    pub fn i32_event_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-    ) -> Self
-    where
-        F: Fn(Notifier<i32>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.i32_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::i32(name).description(description);
-        let i32_notifier = Notifier::i32(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: I32FnPinless = Box::new(move |i32_notifier| Box::pin(func(i32_notifier)));
-        let task = monitor_i32_event_pinless(event, i32_notifier, func);
-
-        self.spawn(name, task, |events| events.add_i32_event(event))
+    pub fn rules(mut self, name: &'static str, rules: EmissionRules) -> Self {
+        self.rules.retain(|(rule_name, _)| *rule_name != name);
+        self.rules.push((name, rules));
+        self
     }
 
-    /// Monitors a pin with a [`PeriodicEvent<i32>`] notifier.
+    /// Monitors a pin with an [`Event`] notifier.
     ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn periodic_i32<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-        pin: AnyPin<'static>,
-    ) -> Self
-    where
-        F: Fn(AnyPin<'static>, PeriodicNotifier<i32>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.periodic_i32_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = PeriodicEvent::i32(Event::i32(name).description(description), interval);
-        let periodic_i32_notifier = PeriodicNotifier::i32(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicI32Fn =
-            Box::new(move |pin, i32_notifier| Box::pin(func(pin, i32_notifier)));
-        let task = monitor_periodic_i32_event(event, pin, periodic_i32_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_i32_event(event))
-    }
-
-    /// Monitors a [`PeriodicEvent<i32>`] notifier not tied to a pin.
+    /// The event value can be of any [`EventType`], such as an integer of
+    /// any width, a text or an object made of multiple fields, and it must
+    /// be of the same type of the notifier values.
     ///
-    /// Discards the event if it matches an existing one.
+    /// Discards the event if its name matches an existing one, or if its
+    /// value is not of the notifier type. A failure in monitoring the event
+    /// is returned by [`Self::run_network_task`].
     #[inline]
     #[must_use]
-    pub fn periodic_i32_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-    ) -> Self
+    pub fn event<T, F, Fut>(self, event: Event, func: F, pin: AnyPin<'static>) -> Self
     where
-        F: Fn(PeriodicNotifier<i32>) -> Fut + Send + Sync + 'static,
+        T: EventType,
+        F: FnOnce(AnyPin<'static>, Notifier<T>) -> Fut,
         Fut: Future<Output = ()> + Send + Sync + 'static,
     {
-        let events_ref = self.events.periodic_i32_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = PeriodicEvent::i32(Event::i32(name).description(description), interval);
-        let periodic_i32_notifier = PeriodicNotifier::i32(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicI32FnPinless = Box::new(move |notifier| Box::pin(func(notifier)));
-        let task = monitor_periodic_i32_event_pinless(event, periodic_i32_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_i32_event(event))
+        self.event_pinless(event, move |notifier| func(pin, notifier))
     }
 
-    /// Monitors a pin with an [`Event<f32>`] notifier.
+    /// Monitors an [`Event`] notifier not tied to a pin.
     ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn f32_event<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-        pin: AnyPin<'static>,
-    ) -> Self
-    where
-        F: Fn(AnyPin<'static>, Notifier<f32>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.f32_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::f32(name).description(description);
-        let f32_notifier = Notifier::f32(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: F32Fn = Box::new(move |pin, f32_notifier| Box::pin(func(pin, f32_notifier)));
-        let task = monitor_f32_event(event, pin, f32_notifier, func);
-
-        self.spawn(name, task, |events| events.add_f32_event(event))
-    }
-
-    /// Monitors an [`Event<f32>`] notifier not tied to a pin.
+    /// The event value can be of any [`EventType`], and it must be of the
+    /// same type of the notifier values.
     ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
+    /// Discards the event if its name matches an existing one, or if its
+    /// value is not of the notifier type. A failure in monitoring the event
+    /// is returned by [`Self::run_network_task`].
     #[must_use]
-    pub fn f32_event_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-    ) -> Self
+    pub fn event_pinless<T, F, Fut>(self, event: Event, func: F) -> Self
     where
-        F: Fn(Notifier<f32>) -> Fut + Send + Sync + 'static,
+        T: EventType,
+        F: FnOnce(Notifier<T>) -> Fut,
         Fut: Future<Output = ()> + Send + Sync + 'static,
     {
-        let events_ref = self.events.f32_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::f32(name).description(description);
-        let f32_notifier = Notifier::f32(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: F32FnPinless = Box::new(move |f32_notifier| Box::pin(func(f32_notifier)));
-        let task = monitor_f32_event_pinless(event, f32_notifier, func);
-
-        self.spawn(name, task, |events| events.add_f32_event(event))
-    }
-
-    /// Monitors a pin with a [`PeriodicEvent<f32>`] notifier.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn periodic_f32<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-        pin: AnyPin<'static>,
-    ) -> Self
-    where
-        F: Fn(AnyPin<'static>, PeriodicNotifier<f32>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.periodic_f32_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
+        let event = self.apply_rules(event);
+        if !Self::is_notifier_type::<T>(&event) {
+            return self;
         }
-
-        let event = PeriodicEvent::f32(Event::f32(name).description(description), interval);
-        let periodic_f32_notifier = PeriodicNotifier::f32(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicF32Fn =
-            Box::new(move |pin, f32_notifier| Box::pin(func(pin, f32_notifier)));
-        let task = monitor_periodic_f32_event(event, pin, periodic_f32_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_f32_event(event))
+        let spawner = self.config.spawner;
+
+        self.register(event.name, move |events| {
+            let index = events.events_as_slice().len();
+            spawner.spawn(monitor_event(Box::pin(func(Notifier::new(index)))))?;
+            events.add_event(event);
+            Ok(())
+        })
     }
 
-    /// Monitors a [`PeriodicEvent<f32>`] notifier not tied to a pin.
+    /// Monitors a pin with a [`PeriodicEvent`] notifier.
     ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn periodic_f32_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-    ) -> Self
-    where
-        F: Fn(PeriodicNotifier<f32>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.periodic_f32_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = PeriodicEvent::f32(Event::f32(name).description(description), interval);
-        let periodic_f32_notifier = PeriodicNotifier::f32(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicF32FnPinless = Box::new(move |notifier| Box::pin(func(notifier)));
-        let task = monitor_periodic_f32_event_pinless(event, periodic_f32_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_f32_event(event))
-    }
-
-    /// Monitors a pin with an [`Event<f64>`] notifier.
+    /// The event value can be of any [`EventType`], and it must be of the
+    /// same type of the notifier values.
     ///
-    /// Discards the event if it matches an existing one.
+    /// Discards the event if its name matches an existing one, or if its
+    /// value is not of the notifier type. A failure in monitoring the event
+    /// is returned by [`Self::run_network_task`].
     #[inline]
     #[must_use]
-    pub fn f64_event<F, Fut>(
+    pub fn periodic_event<T, F, Fut>(
         self,
-        name: &'static str,
-        description: &'static str,
+        periodic_event: PeriodicEvent,
         func: F,
         pin: AnyPin<'static>,
     ) -> Self
     where
-        F: Fn(AnyPin<'static>, Notifier<f64>) -> Fut + Send + Sync + 'static,
+        T: EventType,
+        F: FnOnce(AnyPin<'static>, PeriodicNotifier<T>) -> Fut,
         Fut: Future<Output = ()> + Send + Sync + 'static,
     {
-        let events_ref = self.events.f64_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::f64(name).description(description);
-        let f64_notifier = Notifier::f64(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: F64Fn = Box::new(move |pin, f64_notifier| Box::pin(func(pin, f64_notifier)));
-        let task = monitor_f64_event(event, pin, f64_notifier, func);
-
-        self.spawn(name, task, |events| events.add_f64_event(event))
+        self.periodic_event_pinless(periodic_event, move |notifier| func(pin, notifier))
     }
 
-    /// Monitors an [`Event<f64>`] notifier not tied to a pin.
+    /// Monitors a [`PeriodicEvent`] notifier not tied to a pin.
     ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn f64_event_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        func: F,
-    ) -> Self
-    where
-        F: Fn(Notifier<f64>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.f64_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = Event::f64(name).description(description);
-        let f64_notifier = Notifier::f64(len);
-        // We need to do this because embassy tasks do not support generics.
-        let func: F64FnPinless = Box::new(move |f64_notifier| Box::pin(func(f64_notifier)));
-        let task = monitor_f64_event_pinless(event, f64_notifier, func);
-
-        self.spawn(name, task, |events| events.add_f64_event(event))
-    }
-
-    /// Monitors a pin with a [`PeriodicEvent<f64>`] notifier.
+    /// The event value can be of any [`EventType`], and it must be of the
+    /// same type of the notifier values.
     ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
+    /// Discards the event if its name matches an existing one, or if its
+    /// value is not of the notifier type. A failure in monitoring the event
+    /// is returned by [`Self::run_network_task`].
     #[must_use]
-    pub fn periodic_f64<F, Fut>(
+    pub fn periodic_event_pinless<T, F, Fut>(
         self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
+        mut periodic_event: PeriodicEvent,
         func: F,
-        pin: AnyPin<'static>,
     ) -> Self
     where
-        F: Fn(AnyPin<'static>, PeriodicNotifier<f64>) -> Fut + Send + Sync + 'static,
+        T: EventType,
+        F: FnOnce(PeriodicNotifier<T>) -> Fut,
         Fut: Future<Output = ()> + Send + Sync + 'static,
     {
-        let events_ref = self.events.periodic_f64_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
-        }
-
-        let event = PeriodicEvent::f64(Event::f64(name).description(description), interval);
-        let periodic_f64_notifier = PeriodicNotifier::f64(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicF64Fn =
-            Box::new(move |pin, f64_notifier| Box::pin(func(pin, f64_notifier)));
-        let task = monitor_periodic_f64_event(event, pin, periodic_f64_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_f64_event(event))
-    }
-
-    /// Monitors a [`PeriodicEvent<f64>`] notifier not tied to a pin.
-    ///
-    /// Discards the event if it matches an existing one.
-    #[inline]
-    #[must_use]
-    pub fn periodic_f64_pinless<F, Fut>(
-        self,
-        name: &'static str,
-        description: &'static str,
-        interval: Duration,
-        func: F,
-    ) -> Self
-    where
-        F: Fn(PeriodicNotifier<f64>) -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = ()> + Send + Sync + 'static,
-    {
-        let events_ref = self.events.periodic_f64_events_as_slice();
-        let len = events_ref.len();
-
-        for value in events_ref {
-            if value.event.name == name {
-                info!(
-                    "The event `{}` is equal to `{}`, discard it.",
-                    value.event.name, name
-                );
-                return self;
-            }
+        periodic_event.event = self.apply_rules(periodic_event.event);
+        if !Self::is_notifier_type::<T>(&periodic_event.event) {
+            return self;
         }
-
-        let event = PeriodicEvent::f64(Event::f64(name).description(description), interval);
-        let periodic_f64_notifier = PeriodicNotifier::f64(len, interval);
-        // We need to do this because embassy tasks do not support generics.
-        let func: PeriodicF64FnPinless = Box::new(move |notifier| Box::pin(func(notifier)));
-        let task = monitor_periodic_f64_event_pinless(event, periodic_f64_notifier, func);
-
-        self.spawn(name, task, |events| events.add_periodic_f64_event(event))
+        let spawner = self.config.spawner;
+
+        self.register(periodic_event.event.name, move |events| {
+            let index = events.periodic_events_as_slice().len();
+            let notifier = PeriodicNotifier::new(index, periodic_event.interval);
+            spawner.spawn(monitor_event(Box::pin(func(notifier))))?;
+            events.add_periodic_event(periodic_event);
+            Ok(())
+        })
     }
 
     /// Runs the task that transmits events over the network.
@@ -1043,12 +449,19 @@ where
     /// # Errors
     ///
     /// Fails when:
+    /// - An event could not be monitored.
     /// - The events manager is empty (no events have been inserted).
     /// - The broker domain cannot be resolved via a `DNS` query.
     /// - The task responsible for network transmission cannot interact with
     ///   the scheduler or the network.
     pub async fn run_network_task(self) -> Result<Device<S>, Error> {
-        if self.events.is_empty() {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        // The description carries the events registered so far.
+        let events = EVENTS.lock().await.clone();
+        if events.is_empty() {
             return Err(Error::new(
                 ErrorKind::EmptyEventsManager,
                 "No events in the event manager",
@@ -1073,28 +486,202 @@ where
             self.config.stack,
             remote_endpoint,
             self.config.topic.clone(),
+            self.config.encoding,
+            self.config.clock,
         ))?;
 
-        Ok(self
-            .config
-            .device
-            .events_description(EventsDescription::new(
+        Ok(self.config.device.events_description(
+            EventsDescription::new(
                 ToscaBrokerData::new(IpAddr::from(remote_endpoint.0), remote_endpoint.1),
                 self.config.topic,
-                self.events,
-            )))
+                events,
+            )
+            .encoding(self.config.encoding),
+        ))
     }
 
-    fn spawn<F, T>(mut self, name: &'static str, task: SpawnToken<T>, add_event: F) -> Self
+    // Applies the emission rules set for an event, if any.
+    fn apply_rules(&self, event: Event) -> Event {
+        match self
+            .rules
+            .iter()
+            .find(|(rule_name, _)| *rule_name == event.name)
+        {
+            Some((_, rules)) => event.rules(*rules),
+            None => event,
+        }
+    }
+
+    fn is_notifier_type<T: EventType>(event: &Event) -> bool {
+        if T::from_value(&event.value).is_none() {
+            error!(
+                "The event `{}` is not of the notifier type, discard it.",
+                event.name
+            );
+            return false;
+        }
+        true
+    }
+
+    // Registers an event into the events shared with the notifiers.
+    //
+    // The registration spawns the task monitoring the event, which reaches
+    // its event through the index it has in the shared events. The task
+    // cannot run before the registration ends, since the manager is
+    // configured without ever yielding to the executor.
+    //
+    // The first failed registration is kept and returned when running the
+    // network task.
+    fn register<F>(mut self, name: &'static str, register: F) -> Self
     where
-        F: FnOnce(&mut Events),
+        F: FnOnce(&mut Events) -> Result<(), Error>,
     {
-        if let Err(e) = self.config.spawner.spawn(task) {
-            error!("Impossible to spawn the event `{name}`: {e}");
+        if self.error.is_some() {
+            error!("Skip the event `{name}` after a failed registration");
             return self;
         }
-        add_event(&mut self.events);
-        info!("Spawned the task for event `{name}`");
+
+        // Notifiers and the network task never hold the lock across an
+        // await point, so it is free while the manager is configured.
+        let Ok(mut events) = EVENTS.try_lock() else {
+            error!("Impossible to register the event `{name}`: events are in use");
+            self.error = Some(Error::new(
+                ErrorKind::EventRegistration,
+                "Shared events in use",
+            ));
+            return self;
+        };
+
+        if events.event(name).is_some() {
+            info!("The event `{name}` is equal to an existing one, discard it.");
+            return self;
+        }
+
+        match register(&mut events) {
+            Ok(()) => info!("Spawned the task for event `{name}`"),
+            Err(e) => {
+                error!("Impossible to spawn the event `{name}`: {e}");
+                self.error = Some(e);
+            }
+        }
         self
     }
 }
+
+// Implements the methods monitoring the events of a primitive type,
+// which delegate to the generic ones.
+macro_rules! primitive_events {
+    ($(
+        $ty:ident ($article:literal) =>
+            $event:ident,
+            $event_pinless:ident,
+            $periodic:ident,
+            $periodic_pinless:ident;
+    )+) => {
+        impl<S> EventsManager<S>
+        where
+            S: ValueFromRef + Send + Sync + 'static,
+        {
+            $(
+                #[doc = concat!(
+                    "Monitors a pin with ", $article, " `", stringify!($ty), "` [`Event`] notifier."
+                )]
+                ///
+                /// Discards the event if it matches an existing one.
+                #[inline]
+                #[must_use]
+                pub fn $event<F, Fut>(
+                    self,
+                    name: &'static str,
+                    description: &'static str,
+                    func: F,
+                    pin: AnyPin<'static>,
+                ) -> Self
+                where
+                    F: Fn(AnyPin<'static>, Notifier<$ty>) -> Fut + Send + Sync + 'static,
+                    Fut: Future<Output = ()> + Send + Sync + 'static,
+                {
+                    self.event(Event::$ty(name).description(description), func, pin)
+                }
+
+                #[doc = concat!(
+                    "Monitors ", $article, " `", stringify!($ty), "` [`Event`] notifier not tied to a pin."
+                )]
+                ///
+                /// Discards the event if it matches an existing one.
+                #[inline]
+                #[must_use]
+                pub fn $event_pinless<F, Fut>(
+                    self,
+                    name: &'static str,
+                    description: &'static str,
+                    func: F,
+                ) -> Self
+                where
+                    F: Fn(Notifier<$ty>) -> Fut + Send + Sync + 'static,
+                    Fut: Future<Output = ()> + Send + Sync + 'static,
+                {
+                    self.event_pinless(Event::$ty(name).description(description), func)
+                }
+
+                #[doc = concat!(
+                    "Monitors a pin with ", $article, " `", stringify!($ty), "` [`PeriodicEvent`] notifier."
+                )]
+                ///
+                /// Discards the event if it matches an existing one.
+                #[inline]
+                #[must_use]
+                pub fn $periodic<F, Fut>(
+                    self,
+                    name: &'static str,
+                    description: &'static str,
+                    interval: Duration,
+                    func: F,
+                    pin: AnyPin<'static>,
+                ) -> Self
+                where
+                    F: Fn(AnyPin<'static>, PeriodicNotifier<$ty>) -> Fut + Send + Sync + 'static,
+                    Fut: Future<Output = ()> + Send + Sync + 'static,
+                {
+                    self.periodic_event(
+                        PeriodicEvent::new(Event::$ty(name).description(description), interval),
+                        func,
+                        pin,
+                    )
+                }
+
+                #[doc = concat!(
+                    "Monitors ", $article, " `", stringify!($ty), "` [`PeriodicEvent`] notifier not tied to a pin."
+                )]
+                ///
+                /// Discards the event if it matches an existing one.
+                #[inline]
+                #[must_use]
+                pub fn $periodic_pinless<F, Fut>(
+                    self,
+                    name: &'static str,
+                    description: &'static str,
+                    interval: Duration,
+                    func: F,
+                ) -> Self
+                where
+                    F: Fn(PeriodicNotifier<$ty>) -> Fut + Send + Sync + 'static,
+                    Fut: Future<Output = ()> + Send + Sync + 'static,
+                {
+                    self.periodic_event_pinless(
+                        PeriodicEvent::new(Event::$ty(name).description(description), interval),
+                        func,
+                    )
+                }
+            )+
+        }
+    };
+}
+
+primitive_events! {
+    bool ("a") => bool_event, bool_event_pinless, periodic_bool, periodic_bool_pinless;
+    u8 ("a") => u8_event, u8_event_pinless, periodic_u8, periodic_u8_pinless;
+    i32 ("an") => i32_event, i32_event_pinless, periodic_i32, periodic_i32_pinless;
+    f32 ("an") => f32_event, f32_event_pinless, periodic_f32, periodic_f32_pinless;
+    f64 ("an") => f64_event, f64_event_pinless, periodic_f64, periodic_f64_pinless;
+}
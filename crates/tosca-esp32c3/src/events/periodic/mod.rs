@@ -1,23 +1,64 @@
-pub(crate) mod bool;
-pub(crate) mod f32;
-pub(crate) mod f64;
-pub(crate) mod i32;
-pub(crate) mod u8;
-
 use core::marker::PhantomData;
 use core::time::Duration;
 
 use embassy_time::Timer;
 
-use crate::events::{WAIT_FOR_MILLISECONDS, WRITE_ON_NETWORK};
+use tosca::events::EventType;
+
+use crate::events::{EVENTS, LastEmission, WAIT_FOR_MILLISECONDS, WRITE_ON_NETWORK};
 
 /// A notifier for signaling a [`tosca::events::PeriodicEvent`].
-pub struct PeriodicNotifier<T: Clone + Copy> {
+///
+/// The event is updated only when its values satisfy the event
+/// emission rules.
+pub struct PeriodicNotifier<T: EventType> {
     index: usize,
     time_interval: Duration,
+    last_emission: LastEmission,
     phantom: PhantomData<T>,
 }
 
+impl<T: EventType> PeriodicNotifier<T> {
+    /// Updates the [`tosca::events::PeriodicEvent`] and then waits for
+    /// a determined time interval before checking again the event.
+    #[inline]
+    pub async fn update_event(&self, value: T) {
+        // Update the event value in the shared structure.
+        let emit = {
+            let value = value.into_value();
+            let mut events = EVENTS.lock().await;
+            let emit = events
+                .periodic_events_as_slice()
+                .get(self.index)
+                .is_some_and(|periodic_event| {
+                    periodic_event
+                        .event
+                        .should_emit(&value, self.last_emission.elapsed())
+                })
+                && events.update_periodic_value(self.index, value);
+            if emit {
+                self.last_emission.record();
+            }
+            emit
+        };
+        if emit {
+            // Notify the network task and wait for the chosen amount of seconds.
+            notify_network_task(self.time_interval.as_secs()).await;
+        } else {
+            wait_interval(self.time_interval.as_secs()).await;
+        }
+    }
+
+    pub(crate) const fn new(index: usize, time_interval: Duration) -> Self {
+        Self {
+            index,
+            time_interval,
+            last_emission: LastEmission::new(),
+            phantom: PhantomData,
+        }
+    }
+}
+
 #[inline]
 async fn notify_network_task(secs: u64) {
     // Wait for a bit after the writing operation.
@@ -27,3 +68,9 @@ async fn notify_network_task(secs: u64) {
     // Wait for a bit after sending the signal.
     Timer::after_secs(secs).await;
 }
+
+#[inline]
+async fn wait_interval(secs: u64) {
+    // Wait without writing over the network.
+    Timer::after_secs(secs).await;
+}
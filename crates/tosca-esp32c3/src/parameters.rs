@@ -1,9 +1,14 @@
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::vec::Vec;
+
+use core::time::Duration;
 
 use tosca::parameters::{
-    ParameterKind, ParameterPayload, ParameterValue, ParametersPayloads as ToscaParametersPayloads,
+    ParameterKind, ParameterPayload, ParameterValue, ParametersData,
+    ParametersPayloads as ToscaParametersPayloads, ParametersValues,
 };
+use tosca::values::{Color, TimeOfDay, Timestamp};
 
 use crate::response::ErrorResponse;
 use crate::server::invalid_data;
@@ -114,6 +119,98 @@ impl U64Payload {
     }
 }
 
+/// An [`i8`] payload.
+pub struct I8Payload {
+    /// Value.
+    pub value: i8,
+    /// Default value.
+    pub default: i8,
+    /// Minimum value.
+    pub min: i8,
+    /// Maximum value.
+    pub max: i8,
+}
+
+impl I8Payload {
+    const fn new(value: i8, default: i8, min: i8, max: i8) -> Self {
+        Self {
+            value,
+            default,
+            min,
+            max,
+        }
+    }
+}
+
+/// An [`i16`] payload.
+pub struct I16Payload {
+    /// Value.
+    pub value: i16,
+    /// Default value.
+    pub default: i16,
+    /// Minimum value.
+    pub min: i16,
+    /// Maximum value.
+    pub max: i16,
+}
+
+impl I16Payload {
+    const fn new(value: i16, default: i16, min: i16, max: i16) -> Self {
+        Self {
+            value,
+            default,
+            min,
+            max,
+        }
+    }
+}
+
+/// An [`i32`] payload.
+pub struct I32Payload {
+    /// Value.
+    pub value: i32,
+    /// Default value.
+    pub default: i32,
+    /// Minimum value.
+    pub min: i32,
+    /// Maximum value.
+    pub max: i32,
+}
+
+impl I32Payload {
+    const fn new(value: i32, default: i32, min: i32, max: i32) -> Self {
+        Self {
+            value,
+            default,
+            min,
+            max,
+        }
+    }
+}
+
+/// An [`i64`] payload.
+pub struct I64Payload {
+    /// Value.
+    pub value: i64,
+    /// Default value.
+    pub default: i64,
+    /// Minimum value.
+    pub min: i64,
+    /// Maximum value.
+    pub max: i64,
+}
+
+impl I64Payload {
+    const fn new(value: i64, default: i64, min: i64, max: i64) -> Self {
+        Self {
+            value,
+            default,
+            min,
+            max,
+        }
+    }
+}
+
 /// A [`f32`] payload.
 pub struct F32Payload {
     /// Value.
@@ -180,6 +277,82 @@ impl<'a> CharsSequencePayload<'a> {
     }
 }
 
+/// A payload consisting of an option chosen among a fixed set.
+pub struct ChoicePayload<'a> {
+    /// Value.
+    pub value: Cow<'a, str>,
+    /// Default value.
+    pub default: Cow<'a, str>,
+    /// Allowed options.
+    pub options: Vec<Cow<'a, str>>,
+}
+
+impl<'a> ChoicePayload<'a> {
+    const fn new(value: Cow<'a, str>, default: Cow<'a, str>, options: Vec<Cow<'a, str>>) -> Self {
+        Self {
+            value,
+            default,
+            options,
+        }
+    }
+}
+
+/// A [`Color`] payload.
+pub struct ColorPayload {
+    /// Value.
+    pub value: Color,
+    /// Default value.
+    pub default: Color,
+}
+
+/// A [`Duration`] payload.
+pub struct DurationPayload {
+    /// Value.
+    pub value: Duration,
+    /// Default value.
+    pub default: Duration,
+    /// Minimum value.
+    pub min: Duration,
+    /// Maximum value.
+    pub max: Duration,
+}
+
+/// A [`TimeOfDay`] payload.
+pub struct TimeOfDayPayload {
+    /// Value.
+    pub value: TimeOfDay,
+    /// Default value.
+    pub default: TimeOfDay,
+}
+
+/// A [`Timestamp`] payload.
+pub struct TimestampPayload {
+    /// Value.
+    pub value: Timestamp,
+    /// Default value.
+    pub default: Timestamp,
+}
+
+/// A payload consisting of a list of values.
+pub struct ListPayload {
+    /// Values.
+    pub value: Vec<ParameterValue>,
+    /// Kind of each element.
+    pub kind: ParameterKind,
+    /// Minimum number of elements.
+    pub min_length: u32,
+    /// Maximum number of elements.
+    pub max_length: u32,
+}
+
+/// A payload consisting of an object made of named values.
+pub struct ObjectPayload {
+    /// Values.
+    pub value: ParametersValues<'static>,
+    /// Parameters describing the object fields.
+    pub parameters: ParametersData,
+}
+
 /// A container for storing route parameter payloads.
 pub struct ParametersPayloads(pub(crate) ToscaParametersPayloads<'static>);
 
@@ -217,9 +390,12 @@ impl ParametersPayloads {
     #[inline]
     pub fn u8(&mut self, name: &'static str) -> Result<U8Payload, ErrorResponse> {
         self.insert(name, |payload| match (payload.value, payload.kind) {
-            (ParameterValue::U8(v), ParameterKind::U8 { default, min, max }) => {
-                Ok(U8Payload::new(v, default, min, max))
-            }
+            (
+                ParameterValue::U8(v),
+                ParameterKind::U8 {
+                    default, min, max, ..
+                },
+            ) => Ok(U8Payload::new(v, default, min, max)),
             _ => Err(invalid_data(&format!("`{name}` is not a `u8` kind"))),
         })
     }
@@ -237,9 +413,12 @@ impl ParametersPayloads {
     #[inline]
     pub fn u16(&mut self, name: &'static str) -> Result<U16Payload, ErrorResponse> {
         self.insert(name, |payload| match (payload.value, payload.kind) {
-            (ParameterValue::U16(v), ParameterKind::U16 { default, min, max }) => {
-                Ok(U16Payload::new(v, default, min, max))
-            }
+            (
+                ParameterValue::U16(v),
+                ParameterKind::U16 {
+                    default, min, max, ..
+                },
+            ) => Ok(U16Payload::new(v, default, min, max)),
             _ => Err(invalid_data(&format!("`{name}` is not a `u16` kind"))),
         })
     }
@@ -259,7 +438,9 @@ impl ParametersPayloads {
         self.insert(name, |payload| match (payload.value, payload.kind) {
             (
                 ParameterValue::U32(v),
-                ParameterKind::U32 { default, min, max }
+                ParameterKind::U32 {
+                    default, min, max, ..
+                }
                 | ParameterKind::RangeU32 {
                     default, min, max, ..
                 },
@@ -283,7 +464,9 @@ impl ParametersPayloads {
         self.insert(name, |payload| match (payload.value, payload.kind) {
             (
                 ParameterValue::U64(v),
-                ParameterKind::U64 { default, min, max }
+                ParameterKind::U64 {
+                    default, min, max, ..
+                }
                 | ParameterKind::RangeU64 {
                     default, min, max, ..
                 },
@@ -292,6 +475,104 @@ impl ParametersPayloads {
         })
     }
 
+    /// Retrieves the [`I8Payload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn i8(&mut self, name: &'static str) -> Result<I8Payload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (
+                ParameterValue::I8(v),
+                ParameterKind::I8 {
+                    default, min, max, ..
+                },
+            ) => Ok(I8Payload::new(v, default, min, max)),
+            _ => Err(invalid_data(&format!("`{name}` is not an `i8` kind"))),
+        })
+    }
+
+    /// Retrieves the [`I16Payload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn i16(&mut self, name: &'static str) -> Result<I16Payload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (
+                ParameterValue::I16(v),
+                ParameterKind::I16 {
+                    default, min, max, ..
+                },
+            ) => Ok(I16Payload::new(v, default, min, max)),
+            _ => Err(invalid_data(&format!("`{name}` is not an `i16` kind"))),
+        })
+    }
+
+    /// Retrieves the [`I32Payload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn i32(&mut self, name: &'static str) -> Result<I32Payload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (
+                ParameterValue::I32(v),
+                ParameterKind::I32 {
+                    default, min, max, ..
+                }
+                | ParameterKind::RangeI32 {
+                    default, min, max, ..
+                },
+            ) => Ok(I32Payload::new(v, default, min, max)),
+            _ => Err(invalid_data(&format!("`{name}` is not an `i32` kind"))),
+        })
+    }
+
+    /// Retrieves the [`I64Payload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn i64(&mut self, name: &'static str) -> Result<I64Payload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (
+                ParameterValue::I64(v),
+                ParameterKind::I64 {
+                    default, min, max, ..
+                }
+                | ParameterKind::RangeI64 {
+                    default, min, max, ..
+                },
+            ) => Ok(I64Payload::new(v, default, min, max)),
+            _ => Err(invalid_data(&format!("`{name}` is not an `i64` kind"))),
+        })
+    }
+
     /// Retrieves the [`F32Payload`] associated with the given parameter name.
     ///
     /// **It consumes the parameter.**
@@ -312,6 +593,7 @@ impl ParametersPayloads {
                     min,
                     max,
                     step,
+                    ..
                 },
             ) => Ok(F32Payload::new(v, default, min, max, step)),
             _ => Err(invalid_data(&format!("`{name}` is not a `f32` kind"))),
@@ -338,12 +620,14 @@ impl ParametersPayloads {
                     min,
                     max,
                     step,
+                    ..
                 }
                 | ParameterKind::RangeF64 {
                     default,
                     min,
                     max,
                     step,
+                    ..
                 },
             ) => Ok(F64Payload::new(v, default, min, max, step)),
             _ => Err(invalid_data(&format!("`{name}` is not a `f64` kind"))),
@@ -374,6 +658,192 @@ impl ParametersPayloads {
         })
     }
 
+    /// Retrieves the [`ChoicePayload`] associated with
+    /// the given parameter name.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    /// - When the given value is not among the allowed options
+    #[inline]
+    pub fn choice(&mut self, name: &'static str) -> Result<ChoicePayload<'_>, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (ParameterValue::CharsSequence(s), ParameterKind::Choice { default, options }) => {
+                if options.contains(&s) {
+                    Ok(ChoicePayload::new(s, default, options))
+                } else {
+                    Err(invalid_data(&format!(
+                        "`{s}` is not an allowed option for `{name}`"
+                    )))
+                }
+            }
+            _ => Err(invalid_data(&format!("`{name}` is not a `choice` kind"))),
+        })
+    }
+
+    /// Retrieves the [`ColorPayload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn color(&mut self, name: &'static str) -> Result<ColorPayload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (ParameterValue::Color(value), ParameterKind::Color { default }) => {
+                Ok(ColorPayload { value, default })
+            }
+            _ => Err(invalid_data(&format!("`{name}` is not a `color` kind"))),
+        })
+    }
+
+    /// Retrieves the [`U16Payload`] associated with the given color
+    /// temperature parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn color_temperature(&mut self, name: &'static str) -> Result<U16Payload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (ParameterValue::U16(v), ParameterKind::ColorTemperature { default, min, max }) => {
+                Ok(U16Payload::new(v, default, min, max))
+            }
+            _ => Err(invalid_data(&format!(
+                "`{name}` is not a `color temperature` kind"
+            ))),
+        })
+    }
+
+    /// Retrieves the [`DurationPayload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn duration(&mut self, name: &'static str) -> Result<DurationPayload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (ParameterValue::Duration(value), ParameterKind::Duration { default, min, max }) => {
+                Ok(DurationPayload {
+                    value,
+                    default,
+                    min,
+                    max,
+                })
+            }
+            _ => Err(invalid_data(&format!("`{name}` is not a `duration` kind"))),
+        })
+    }
+
+    /// Retrieves the [`TimeOfDayPayload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn time_of_day(&mut self, name: &'static str) -> Result<TimeOfDayPayload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (ParameterValue::TimeOfDay(value), ParameterKind::TimeOfDay { default }) => {
+                Ok(TimeOfDayPayload { value, default })
+            }
+            _ => Err(invalid_data(&format!(
+                "`{name}` is not a `time of day` kind"
+            ))),
+        })
+    }
+
+    /// Retrieves the [`TimestampPayload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn timestamp(&mut self, name: &'static str) -> Result<TimestampPayload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (ParameterValue::Timestamp(value), ParameterKind::Timestamp { default }) => {
+                Ok(TimestampPayload { value, default })
+            }
+            _ => Err(invalid_data(&format!("`{name}` is not a `timestamp` kind"))),
+        })
+    }
+
+    /// Retrieves the [`ListPayload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn list(&mut self, name: &'static str) -> Result<ListPayload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (
+                ParameterValue::List(value),
+                ParameterKind::List {
+                    kind,
+                    min_length,
+                    max_length,
+                },
+            ) => Ok(ListPayload {
+                value,
+                kind: *kind,
+                min_length,
+                max_length,
+            }),
+            _ => Err(invalid_data(&format!("`{name}` is not a `list` kind"))),
+        })
+    }
+
+    /// Retrieves the [`ObjectPayload`] associated with the given parameter name.
+    ///
+    /// **It consumes the parameter.**
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorResponse`] is returned in the following cases:
+    ///
+    /// - When the given parameter is not found
+    /// - When the given parameter has an incorrect type
+    #[inline]
+    pub fn object(&mut self, name: &'static str) -> Result<ObjectPayload, ErrorResponse> {
+        self.insert(name, |payload| match (payload.value, payload.kind) {
+            (ParameterValue::Object(value), ParameterKind::Object { parameters }) => {
+                Ok(ObjectPayload { value, parameters })
+            }
+            _ => Err(invalid_data(&format!("`{name}` is not an `object` kind"))),
+        })
+    }
+
     #[inline]
     fn insert<T, F>(&mut self, name: &'static str, func: F) -> Result<T, ErrorResponse>
     where
@@ -3,6 +3,7 @@ use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use tosca::device::DeviceInfo;
+use tosca::encoding::{CBOR_CONTENT_TYPE, Encoding};
 use tosca::response::{
     ErrorKind, ErrorResponse as ToscaErrorResponse, InfoResponse as ToscaInfoResponse,
     OkResponse as ToscaOkResponse, SERIALIZATION_ERROR, SerialResponse as ToscaSerialResponse,
@@ -13,6 +14,8 @@ use edge_http::io::server::Connection;
 
 use embedded_io_async::{Read, Write};
 
+use log::error;
+
 use serde::Serialize;
 
 /// A response which transmits a concise JSON message over the network to notify
@@ -161,6 +164,7 @@ struct Headers {
     status: u16,
     message: &'static str,
     content_type: &'static [(&'static str, &'static str)],
+    is_json: bool,
 }
 
 impl Headers {
@@ -169,6 +173,7 @@ impl Headers {
             status: 404,
             message: "Not Found",
             content_type: &[],
+            is_json: false,
         }
     }
 
@@ -177,6 +182,7 @@ impl Headers {
             status: 405,
             message: "Method Not Allowed",
             content_type: &[],
+            is_json: false,
         }
     }
 
@@ -185,6 +191,7 @@ impl Headers {
             status: 200,
             message: "Ok",
             content_type: &[("Content-Type", "application/json")],
+            is_json: true,
         }
     }
 
@@ -193,6 +200,7 @@ impl Headers {
             status: 500,
             message: "Error",
             content_type: &[("Content-Type", "application/json")],
+            is_json: true,
         }
     }
 
@@ -201,6 +209,7 @@ impl Headers {
             status: 500,
             message: "Error",
             content_type: &[("Content-Type", "text/plain"), (SERIALIZATION_ERROR, "")],
+            is_json: false,
         }
     }
 }
@@ -277,17 +286,63 @@ impl Response {
     pub(crate) async fn write<T, const N: usize>(
         self,
         conn: &mut Connection<'_, T, N>,
+        encoding: Encoding,
     ) -> Result<(), Error<T::Error>>
     where
         T: Read + Write,
     {
-        self.write_from_ref(conn).await
+        self.write_from_ref(conn, encoding).await
     }
 
     #[inline]
     pub(crate) async fn write_from_ref<T, const N: usize>(
         &self,
         conn: &mut Connection<'_, T, N>,
+        encoding: Encoding,
+    ) -> Result<(), Error<T::Error>>
+    where
+        T: Read + Write,
+    {
+        match self.encode(encoding) {
+            Some(response) => response.send(conn).await,
+            None => self.send(conn).await,
+        }
+    }
+
+    // Converts a `JSON` response into the given encoding.
+    //
+    // Since all encodings share the `JSON` data model, the body is parsed
+    // back and encoded again. Returns `None` when the response must be sent
+    // unchanged.
+    fn encode(&self, encoding: Encoding) -> Option<Self> {
+        if encoding.is_json() || !self.headers.is_json {
+            return None;
+        }
+
+        let encoded = serde_json::from_slice::<serde_json::Value>(&self.body.0)
+            .map_err(|e| e.to_string())
+            .and_then(|value| encoding.encode(&value).map_err(|e| e.to_string()));
+
+        match encoded {
+            Ok(encoded) => Some(Response::new(
+                Headers {
+                    status: self.headers.status,
+                    message: self.headers.message,
+                    content_type: &[("Content-Type", CBOR_CONTENT_TYPE)],
+                    is_json: false,
+                },
+                Body::owned(encoded),
+            )),
+            Err(e) => {
+                error!("Unable to encode the response as {encoding}: {e}");
+                None
+            }
+        }
+    }
+
+    async fn send<T, const N: usize>(
+        &self,
+        conn: &mut Connection<'_, T, N>,
     ) -> Result<(), Error<T::Error>>
     where
         T: Read + Write,
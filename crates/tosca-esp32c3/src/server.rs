@@ -6,14 +6,15 @@ use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::str::SplitTerminator;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
+use tosca::encoding::Encoding;
 use tosca::parameters::{
     ParameterKind, ParameterPayload, ParameterValue, ParametersPayloads as ToscaParametersPayloads,
-    ParametersValues,
 };
 use tosca::route::{RestKind, RouteConfig};
+use tosca::values::{Color, TimeOfDay, Timestamp, parse_duration};
 
 use edge_http::io::Body;
 use edge_http::io::server::{Connection, Handler, Server as EdgeServer};
@@ -26,6 +27,8 @@ use embassy_net::Stack;
 
 use embedded_io_async::{Read, Write};
 
+use serde_json::{Map, Value};
+
 use log::{error, info};
 
 use crate::device::{Device, InternalDevice};
@@ -592,18 +595,20 @@ where
             error_response_with_error("Error reading the request bytes", &format!("{e:?}"))
         })?;
 
-        let route_parameters =
-            serde_json::from_slice::<ParametersValues>(&bytes[0..content_length]).map_err(|e| {
-                error_response_with_error(
-                    "Failed to convert bytes into a sequence of parameters",
-                    &format!("{e}"),
-                )
-            })?;
+        let route_parameters = serde_json::from_slice::<Map<String, Value>>(
+            &bytes[0..content_length],
+        )
+        .map_err(|e| {
+            error_response_with_error(
+                "Failed to convert bytes into a sequence of parameters",
+                &format!("{e}"),
+            )
+        })?;
 
         info!("Route parameters: {route_parameters:?}");
 
         let mut parameters_payloads = ToscaParametersPayloads::new();
-        for (parameter_name, parameter_value) in route_parameters {
+        for (parameter_name, value) in route_parameters {
             let parameter_kind = route_config
                 .data
                 .parameters
@@ -612,16 +617,24 @@ where
                     invalid_data_response(&format!("Parameter `{parameter_name}` not found"))
                 })?;
 
-            if !parameter_value.match_kind(parameter_kind) {
-                return Err(invalid_data_response(&format!(
-                    "Found type `{}` for `{parameter_name}`, expected type `{}`",
-                    parameter_value.as_type(),
-                    parameter_kind.as_type(),
-                )));
-            }
+            // Parameters are typed `JSON` values, decoded according to
+            // the kind of the route parameter.
+            let parameter_value =
+                ParameterValue::from_json(parameter_kind, &value).ok_or_else(|| {
+                    invalid_data_response(&format!(
+                        "Invalid `{parameter_name}`: expected a value of type `{}`",
+                        parameter_kind.as_type(),
+                    ))
+                })?;
+
+            parameter_kind
+                .validate(&parameter_value)
+                .map_err(|violation| {
+                    invalid_data_response(&format!("Invalid `{parameter_name}`: {violation}"))
+                })?;
 
             parameters_payloads.add(
-                parameter_name,
+                parameter_name.into(),
                 ParameterPayload::new(parameter_kind.clone(), parameter_value),
             );
         }
@@ -640,7 +653,7 @@ where
             ParameterKind::U8 { .. } => {
                 Self::into_value::<u8, _>(parameter_value, "u8", ParameterValue::U8)
             }
-            ParameterKind::U16 { .. } => {
+            ParameterKind::U16 { .. } | ParameterKind::ColorTemperature { .. } => {
                 Self::into_value::<u16, _>(parameter_value, "u16", ParameterValue::U16)
             }
             ParameterKind::U32 { .. } | ParameterKind::RangeU32 { .. } => {
@@ -649,15 +662,78 @@ where
             ParameterKind::U64 { .. } | ParameterKind::RangeU64 { .. } => {
                 Self::into_value::<u64, _>(parameter_value, "u64", ParameterValue::U64)
             }
+            ParameterKind::I8 { .. } => {
+                Self::into_value::<i8, _>(parameter_value, "i8", ParameterValue::I8)
+            }
+            ParameterKind::I16 { .. } => {
+                Self::into_value::<i16, _>(parameter_value, "i16", ParameterValue::I16)
+            }
+            ParameterKind::I32 { .. } | ParameterKind::RangeI32 { .. } => {
+                Self::into_value::<i32, _>(parameter_value, "i32", ParameterValue::I32)
+            }
+            ParameterKind::I64 { .. } | ParameterKind::RangeI64 { .. } => {
+                Self::into_value::<i64, _>(parameter_value, "i64", ParameterValue::I64)
+            }
             ParameterKind::F32 { .. } => {
                 Self::into_value::<f32, _>(parameter_value, "f32", ParameterValue::F32)
             }
             ParameterKind::F64 { .. } | ParameterKind::RangeF64 { .. } => {
                 Self::into_value::<f64, _>(parameter_value, "f64", ParameterValue::F64)
             }
-            ParameterKind::CharsSequence { .. } => Ok(ParameterValue::CharsSequence(Cow::Owned(
-                parameter_value.to_string(),
-            ))),
+            ParameterKind::CharsSequence { .. } | ParameterKind::Choice { .. } => Ok(
+                ParameterValue::CharsSequence(Cow::Owned(parameter_value.to_string())),
+            ),
+            ParameterKind::Color { .. } => {
+                Self::into_value::<Color, _>(parameter_value, "Color", ParameterValue::Color)
+            }
+            ParameterKind::Duration { .. } => parse_duration(parameter_value)
+                .map(ParameterValue::Duration)
+                .map_err(|e| {
+                    error_response_with_error(
+                        &format!("Failed to parse `{parameter_value}` into `Duration` type"),
+                        &format!("{e}"),
+                    )
+                }),
+            ParameterKind::TimeOfDay { .. } => Self::into_value::<TimeOfDay, _>(
+                parameter_value,
+                "TimeOfDay",
+                ParameterValue::TimeOfDay,
+            ),
+            ParameterKind::Timestamp { .. } => Self::into_value::<Timestamp, _>(
+                parameter_value,
+                "Timestamp",
+                ParameterValue::Timestamp,
+            ),
+            ParameterKind::List { .. } | ParameterKind::Object { .. } => {
+                Self::into_structured_value(parameter_value, parameter_kind)
+            }
+        }
+    }
+
+    // Lists and objects contained in a route path are compact JSON texts.
+    #[inline]
+    fn into_structured_value(
+        parameter_value: &str,
+        parameter_kind: &ParameterKind,
+    ) -> Result<ParameterValue, Response> {
+        let value = serde_json::from_str::<ParameterValue>(parameter_value).map_err(|e| {
+            error_response_with_error(
+                &format!(
+                    "Failed to parse `{parameter_value}` into `{}` type",
+                    parameter_kind.as_type()
+                ),
+                &format!("{e}"),
+            )
+        })?;
+
+        if value.match_kind(parameter_kind) {
+            Ok(value)
+        } else {
+            Err(invalid_data_response(&format!(
+                "Found type `{}`, expected type `{}`",
+                value.as_type(),
+                parameter_kind.as_type(),
+            )))
         }
     }
 
@@ -758,12 +834,22 @@ impl<S: ValueFromRef + Send + Sync + 'static> Handler for ServerHandler<S> {
     {
         let (headers, body) = conn.split();
 
+        // Encoding of the response, as negotiated with the controller.
+        let encoding = headers
+            .headers
+            .get("Accept")
+            .map_or(Encoding::Json, Encoding::negotiate);
+
         if headers.path == "/" {
-            return self.device.main_route_response.write_from_ref(conn).await;
+            return self
+                .device
+                .main_route_response
+                .write_from_ref(conn, encoding)
+                .await;
         }
 
         if Self::is_method_allowed(headers.method) {
-            return Response::not_allowed().write(conn).await;
+            return Response::not_allowed().write(conn, encoding).await;
         }
 
         let route_info = match self
@@ -771,7 +857,7 @@ impl<S: ValueFromRef + Send + Sync + 'static> Handler for ServerHandler<S> {
             .await
         {
             Ok(index) => index,
-            Err(response) => return response.write(conn).await,
+            Err(response) => return response.write(conn, encoding).await,
         };
 
         let RouteInfo {
@@ -780,6 +866,6 @@ impl<S: ValueFromRef + Send + Sync + 'static> Handler for ServerHandler<S> {
         } = route_info;
 
         let response = self.run_function(index, parameters_payloads).await;
-        response.write(conn).await
+        response.write(conn, encoding).await
     }
 }
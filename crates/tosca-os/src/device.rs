@@ -1,10 +1,14 @@
-use tosca::device::{DeviceData, DeviceEnvironment, DeviceKind, DeviceKindId, DeviceKindTrait};
+use tosca::device::{
+    Capabilities, Capability, DeviceData, DeviceEnvironment, DeviceIdentity, DeviceKind,
+    DeviceKindId, DeviceKindTrait,
+};
 use tosca::route::{RouteConfig, RouteConfigs};
 
 use axum::Router;
 
 use tracing::{info, warn};
 
+use crate::events::EventsManager;
 use crate::mac::get_mac_addresses;
 use crate::responses::BaseResponse;
 
@@ -27,10 +31,16 @@ where
     pub(crate) state: S,
     // Device kind.
     kind: DeviceKindId,
+    // Device identity.
+    identity: DeviceIdentity,
+    // Device capabilities.
+    capabilities: Capabilities,
     // All device routes along with their associated hazards.
     route_configs: RouteConfigs,
     // Number of mandatory routes.
     num_mandatory_routes: u8,
+    // Events manager.
+    events: Option<EventsManager>,
 }
 
 impl Default for Device<()> {
@@ -66,6 +76,27 @@ where
         self
     }
 
+    /// Sets the [`DeviceIdentity`].
+    ///
+    /// The identity is advertised to controllers together with the
+    /// device description.
+    #[must_use]
+    #[inline]
+    pub fn identity(mut self, identity: DeviceIdentity) -> Self {
+        self.identity = identity;
+        self
+    }
+
+    /// Declares a [`Capability`] of [`Device`].
+    ///
+    /// The routes required by the capability must be added to the device.
+    #[must_use]
+    #[inline]
+    pub fn capability(mut self, capability: Capability) -> Self {
+        self.capabilities.add(capability);
+        self
+    }
+
     /// Adds a route to [`Device`].
     #[must_use]
     #[inline]
@@ -74,6 +105,16 @@ where
         self.response_data(base_response.finalize())
     }
 
+    /// Adds an [`EventsManager`] to [`Device`].
+    ///
+    /// Events are published over the network once the server starts.
+    #[must_use]
+    #[inline]
+    pub fn events(mut self, events: EventsManager) -> Self {
+        self.events = Some(events);
+        self
+    }
+
     /// Adds an informative route to [`Device`].
     #[must_use]
     pub fn info_route(self, device_info_route: impl FnOnce(S, ()) -> BaseResponse) -> Self {
@@ -86,9 +127,12 @@ where
             main_route: MAIN_ROUTE,
             router: Router::new(),
             kind: DeviceKindId::from(kind),
+            identity: DeviceIdentity::new(),
+            capabilities: Capabilities::new(),
             route_configs: RouteConfigs::new(),
             state,
             num_mandatory_routes: 0,
+            events: None,
         }
     }
 
@@ -113,12 +157,18 @@ where
         self
     }
 
-    pub(crate) fn finalize(self) -> (&'static str, DeviceData, Router) {
+    pub(crate) fn finalize(self) -> (&'static str, DeviceData, Router, Option<EventsManager>) {
         let (wifi_mac, ethernet_mac) = get_mac_addresses();
         if wifi_mac.is_none() && ethernet_mac.is_none() {
             warn!("Unable to retrieve any Wi-Fi or Ethernet MAC address.");
         }
 
+        for capability in &self.capabilities {
+            if !capability.is_implemented_by(&self.route_configs) {
+                warn!("The routes of the `{capability}` capability are missing.");
+            }
+        }
+
         for route in &self.route_configs {
             info!(
                 "Device route: [{}, \"{}{}\"]",
@@ -136,19 +186,24 @@ where
                 self.main_route,
                 self.route_configs,
                 self.num_mandatory_routes,
-            ),
+            )
+            .identity(self.identity)
+            .capabilities(self.capabilities),
             self.router,
+            self.events,
         )
     }
 }
 
 #[cfg(test)]
+// Test route handlers return the `ErrorResponse` type of the library.
+#[allow(clippy::result_large_err)]
 mod tests {
     use std::sync::Arc;
 
     use core::ops::{Deref, DerefMut};
 
-    use tosca::device::DeviceInfo;
+    use tosca::device::{Capabilities, Capability, DeviceIdentity, DeviceInfo};
     use tosca::energy::Energy;
     use tosca::route::Route;
 
@@ -160,6 +215,7 @@ mod tests {
 
     use crate::responses::error::ErrorResponse;
     use crate::responses::info::{InfoResponse, info_stateful};
+    use crate::responses::ok::{OkResponse, ok_stateless};
     use crate::responses::serial::{SerialResponse, serial_stateful, serial_stateless};
 
     use super::Device;
@@ -375,6 +431,42 @@ mod tests {
             ));
     }
 
+    #[test]
+    fn with_identity() {
+        let identity = DeviceIdentity::new()
+            .manufacturer("Tosca Labs")
+            .serial_number("SN0042");
+
+        let (_, device_data, _, _) = Device::new().identity(identity.clone()).finalize();
+        assert_eq!(device_data.identity, identity);
+    }
+
+    async fn ok_response() -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    #[test]
+    fn with_capabilities() {
+        let (_, device_data, _, _) = Device::new()
+            .route(ok_stateless(
+                Route::put("On", "/on").description("Turn on."),
+                ok_response,
+            ))
+            .route(ok_stateless(
+                Route::put("Off", "/off").description("Turn off."),
+                ok_response,
+            ))
+            .capability(Capability::OnOff)
+            .capability(Capability::OnOff)
+            .finalize();
+
+        assert_eq!(
+            device_data.capabilities,
+            Capabilities::init(Capability::OnOff)
+        );
+        assert!(Capability::OnOff.is_implemented_by(&device_data.route_configs));
+    }
+
     #[test]
     fn without_state() {
         let routes = create_routes();
@@ -1,8 +1,7 @@
 use axum::Router;
 
-use tosca::device::DeviceKind;
-use tosca::hazards::Hazard;
-use tosca::route::{LightOffRoute, LightOnRoute, Route, RouteConfig};
+use tosca::device::{Capability, DeviceKind};
+use tosca::route::{LIGHT_ROUTES, LightOffRoute, LightOnRoute, Route, RouteConfig};
 
 use crate::device::Device;
 use crate::error::Result;
@@ -11,13 +10,6 @@ use crate::responses::{BaseResponse, MandatoryResponse};
 // Default main route.
 const MAIN_ROUTE: &str = "/light";
 
-// Allowed hazards.
-const ALLOWED_HAZARDS: &[Hazard] = &[
-    Hazard::FireHazard,
-    Hazard::ElectricEnergyConsumption,
-    Hazard::LogEnergyConsumption,
-];
-
 /// A `light` device.
 ///
 /// Its methods guide in the definition of a correct light.
@@ -118,6 +110,16 @@ where
         self
     }
 
+    /// Declares a [`Capability`] of [`Light`].
+    ///
+    /// The routes required by the capability must be added to the light.
+    #[must_use]
+    #[inline]
+    pub fn capability(mut self, capability: Capability) -> Self {
+        self.device = self.device.capability(capability);
+        self
+    }
+
     /// Adds a route to [`Light`].
     ///
     /// # Errors
@@ -157,12 +159,15 @@ where
     }
 
     fn check_allowed_hazards(base_response: BaseResponse) -> (RouteConfig, Router) {
-        base_response.finalize_with_hazards(ALLOWED_HAZARDS)
+        base_response.finalize_with_hazards(LIGHT_ROUTES.allowed_hazards)
     }
 }
 
 #[cfg(test)]
+// Test route handlers return the `ErrorResponse` type of the library.
+#[allow(clippy::result_large_err)]
 mod tests {
+    use tosca::device::Capability;
     use tosca::hazards::Hazard;
     use tosca::parameters::Parameters;
     use tosca::route::Route;
@@ -282,6 +287,7 @@ mod tests {
             .unwrap()
             .route(ok_stateful(routes.toggle, toggle))
             .unwrap()
+            .capability(Capability::OnOff)
             .build();
     }
 
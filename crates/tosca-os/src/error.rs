@@ -9,6 +9,8 @@ pub enum ErrorKind {
     NotFoundAddress,
     /// Errors encountered while serializing or deserializing a file.
     Serialization,
+    /// Errors encountered while configuring events.
+    Events,
 }
 
 impl ErrorKind {
@@ -17,6 +19,7 @@ impl ErrorKind {
             Self::Service => "Service",
             Self::NotFoundAddress => "Not Found Address",
             Self::Serialization => "Serialization",
+            Self::Events => "Events",
         }
     }
 }
@@ -0,0 +1,46 @@
+use std::net::IpAddr;
+
+use tokio::net::lookup_host;
+
+use crate::error::{Error, ErrorKind, Result};
+
+/// Broker data.
+#[derive(Debug, Clone, Copy)]
+pub enum BrokerData {
+    /// Broker `URL` and `port`.
+    Url(&'static str, u16),
+
+    /// Broker [`IpAddr`] and `port`.
+    Ip(IpAddr, u16),
+}
+
+impl BrokerData {
+    /// Creates a [`BrokerData`] from the `URL` and `port`.
+    #[must_use]
+    pub const fn url(url: &'static str, port: u16) -> Self {
+        Self::Url(url, port)
+    }
+
+    /// Creates a [`BrokerData`] from the [`IpAddr`] and `port`.
+    #[must_use]
+    pub const fn ip(ip: IpAddr, port: u16) -> Self {
+        Self::Ip(ip, port)
+    }
+
+    // Resolves the broker address through a DNS query when necessary.
+    pub(crate) async fn resolve(self) -> Result<(IpAddr, u16)> {
+        match self {
+            Self::Url(url, port) => lookup_host((url, port))
+                .await?
+                .next()
+                .map(|address| (address.ip(), port))
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::NotFoundAddress,
+                        format!("Unable to resolve the broker address `{url}`"),
+                    )
+                }),
+            Self::Ip(ip, port) => Ok((ip, port)),
+        }
+    }
+}
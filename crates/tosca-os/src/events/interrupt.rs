@@ -0,0 +1,47 @@
+use std::marker::PhantomData;
+
+use tosca::events::EventType;
+
+use super::{LastEmission, SharedEvents};
+
+/// A notifier for signaling an [`tosca::events::Event`].
+#[derive(Debug)]
+pub struct Notifier<T> {
+    index: usize,
+    shared: SharedEvents,
+    last_emission: LastEmission,
+    phantom: PhantomData<T>,
+}
+
+impl<T: EventType> Notifier<T> {
+    /// Updates the [`tosca::events::Event`] and notifies the network task.
+    ///
+    /// Nothing happens when the value does not satisfy the event
+    /// emission rules.
+    #[inline]
+    pub async fn update_event(&self, value: T) {
+        // The lock will be released at the end of this scope.
+        {
+            let mut events = self.shared.events.lock().await;
+            let value = value.into_value();
+            if !events.events_as_slice()[self.index]
+                .should_emit(&value, self.last_emission.elapsed())
+            {
+                return;
+            }
+            events.update_value(self.index, value);
+            self.last_emission.record();
+        }
+        // Notify the network task.
+        self.shared.notify.notify_one();
+    }
+
+    pub(crate) const fn new(index: usize, shared: SharedEvents) -> Self {
+        Self {
+            index,
+            shared,
+            last_emission: LastEmission::new(),
+            phantom: PhantomData,
+        }
+    }
+}
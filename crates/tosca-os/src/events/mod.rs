@@ -0,0 +1,756 @@
+mod topic;
+
+/// All essential data needed to configure an event broker.
+pub mod broker;
+/// A notifier designed to manage interrupt events.
+pub mod interrupt;
+/// A notifier designed to manage periodic events.
+pub mod periodic;
+
+use std::pin::Pin;
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tosca::encoding::Encoding;
+use tosca::events::{
+    BrokerData as ToscaBrokerData, EmissionRules, Event, EventType, Events, EventsDescription,
+    PeriodicEvent, Topic,
+};
+use tosca::values::Timestamp;
+
+use rumqttc::v5::{AsyncClient, EventLoop, MqttOptions, mqttbytes::QoS};
+
+use tokio::sync::{Mutex, Notify};
+use tokio::task::JoinSet;
+use tokio::time::{Instant, sleep};
+
+use tracing::{debug, error, info, warn};
+
+use crate::error::{Error, ErrorKind, Result};
+
+use broker::BrokerData;
+use interrupt::Notifier;
+use periodic::PeriodicNotifier;
+use topic::TopicBuilder;
+
+// Topic suffix.
+const TOPIC_SUFFIX: &str = "events";
+
+// Capacity of the bounded channel between the `MQTT` client and its
+// event loop.
+const ASYNC_CHANNEL_CAPACITY: usize = 10;
+
+// Keep alive time to send `pingreq` to the broker when the connection is idle.
+const KEEP_ALIVE_TIME: Duration = Duration::from_secs(5);
+
+// Time to wait, in seconds, before reconnecting to the broker.
+const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+// A task monitoring an event.
+type EventTask = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+// Events shared among notifiers and the network task.
+#[derive(Debug, Clone)]
+pub(crate) struct SharedEvents {
+    events: Arc<Mutex<Events>>,
+    notify: Arc<Notify>,
+}
+
+impl SharedEvents {
+    fn new() -> Self {
+        Self {
+            events: Arc::new(Mutex::new(Events::empty())),
+            notify: Arc::new(Notify::new()),
+        }
+    }
+}
+
+// Time of the last emission of an event.
+#[derive(Debug)]
+struct LastEmission(std::sync::Mutex<Option<Instant>>);
+
+impl LastEmission {
+    const fn new() -> Self {
+        Self(std::sync::Mutex::new(None))
+    }
+
+    // Returns the time elapsed since the last emission, if any.
+    fn elapsed(&self) -> Option<Duration> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .map(|instant| instant.elapsed())
+    }
+
+    // Records an emission at the current time.
+    fn record(&self) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Some(Instant::now());
+    }
+}
+
+/// Events configuration.
+///
+/// Defines all the data required to publish events over the network.
+#[derive(Debug)]
+pub struct EventsConfig {
+    broker: BrokerData,
+    topic_prefix: &'static str,
+    encoding: Encoding,
+}
+
+impl EventsConfig {
+    /// Creates an [`EventsConfig`].
+    ///
+    /// Events are published on the `{topic_prefix}/{MAC}/events` topic,
+    /// where `MAC` is the device MAC address.
+    #[must_use]
+    pub const fn new(broker: BrokerData, topic_prefix: &'static str) -> Self {
+        Self {
+            broker,
+            topic_prefix,
+            encoding: Encoding::Json,
+        }
+    }
+
+    /// Publishes events in the compact `CBOR` binary encoding instead
+    /// of `JSON`.
+    #[cfg(feature = "cbor")]
+    #[must_use]
+    pub const fn cbor(mut self) -> Self {
+        self.encoding = Encoding::Cbor;
+        self
+    }
+}
+
+/// An event manager.
+///
+/// Validates the events data and runs the corresponding tasks once the
+/// server starts.
+pub struct EventsManager {
+    config: EventsConfig,
+    events: Events,
+    shared: SharedEvents,
+    tasks: Vec<EventTask>,
+}
+
+impl std::fmt::Debug for EventsManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventsManager")
+            .field("config", &self.config)
+            .field("events", &self.events)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventsManager {
+    /// Configures the [`EventsManager`].
+    #[must_use]
+    #[inline]
+    pub fn config(config: EventsConfig) -> Self {
+        Self {
+            config,
+            events: Events::empty(),
+            shared: SharedEvents::new(),
+            tasks: Vec::new(),
+        }
+    }
+
+    /// Monitors an [`Event`] notifier.
+    ///
+    /// The event value can be of any [`EventType`], such as an integer of
+    /// any width, a text or an object made of multiple fields, and it must
+    /// be of the same type of the notifier values.
+    ///
+    /// Discards the event if its name matches an existing one, or if its
+    /// value is not of the notifier type.
+    #[must_use]
+    pub fn event<T, F, Fut>(mut self, event: Event, func: F) -> Self
+    where
+        T: EventType,
+        F: FnOnce(Notifier<T>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        if !self.check_event(&event, T::from_value(&event.value).is_some()) {
+            return self;
+        }
+
+        let index = self.events.add_event(event);
+        self.tasks
+            .push(Box::pin(func(Notifier::new(index, self.shared.clone()))));
+
+        info!(
+            "Added the task for event `{}`",
+            self.events.events_as_slice()[index].name
+        );
+        self
+    }
+
+    /// Monitors a [`PeriodicEvent`] notifier.
+    ///
+    /// The event value can be of any [`EventType`], and it must be of the
+    /// same type of the notifier values.
+    ///
+    /// Discards the event if its name matches an existing one, or if its
+    /// value is not of the notifier type.
+    #[must_use]
+    pub fn periodic_event<T, F, Fut>(mut self, periodic_event: PeriodicEvent, func: F) -> Self
+    where
+        T: EventType,
+        F: FnOnce(PeriodicNotifier<T>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let event = &periodic_event.event;
+        if !self.check_event(event, T::from_value(&event.value).is_some()) {
+            return self;
+        }
+
+        let interval = periodic_event.interval;
+        let index = self.events.add_periodic_event(periodic_event);
+        self.tasks.push(Box::pin(func(PeriodicNotifier::new(
+            index,
+            interval,
+            self.shared.clone(),
+        ))));
+
+        info!(
+            "Added the task for periodic event `{}`",
+            self.events.periodic_events_as_slice()[index].event.name
+        );
+        self
+    }
+
+    /// Monitors an [`Event`] notifier with `bool` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn bool_event<F, Fut>(self, name: &'static str, description: &'static str, func: F) -> Self
+    where
+        F: FnOnce(Notifier<bool>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.event(Event::bool(name).description(description), func)
+    }
+
+    /// Monitors a [`PeriodicEvent`] notifier with `bool` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn periodic_bool<F, Fut>(
+        self,
+        name: &'static str,
+        description: &'static str,
+        interval: Duration,
+        func: F,
+    ) -> Self
+    where
+        F: FnOnce(PeriodicNotifier<bool>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.periodic_event(
+            PeriodicEvent::new(Event::bool(name).description(description), interval),
+            func,
+        )
+    }
+
+    /// Monitors an [`Event`] notifier with `u8` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn u8_event<F, Fut>(self, name: &'static str, description: &'static str, func: F) -> Self
+    where
+        F: FnOnce(Notifier<u8>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.event(Event::u8(name).description(description), func)
+    }
+
+    /// Monitors a [`PeriodicEvent`] notifier with `u8` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn periodic_u8<F, Fut>(
+        self,
+        name: &'static str,
+        description: &'static str,
+        interval: Duration,
+        func: F,
+    ) -> Self
+    where
+        F: FnOnce(PeriodicNotifier<u8>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.periodic_event(
+            PeriodicEvent::new(Event::u8(name).description(description), interval),
+            func,
+        )
+    }
+
+    /// Monitors an [`Event`] notifier with `i32` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn i32_event<F, Fut>(self, name: &'static str, description: &'static str, func: F) -> Self
+    where
+        F: FnOnce(Notifier<i32>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.event(Event::i32(name).description(description), func)
+    }
+
+    /// Monitors a [`PeriodicEvent`] notifier with `i32` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn periodic_i32<F, Fut>(
+        self,
+        name: &'static str,
+        description: &'static str,
+        interval: Duration,
+        func: F,
+    ) -> Self
+    where
+        F: FnOnce(PeriodicNotifier<i32>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.periodic_event(
+            PeriodicEvent::new(Event::i32(name).description(description), interval),
+            func,
+        )
+    }
+
+    /// Monitors an [`Event`] notifier with `f32` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn f32_event<F, Fut>(self, name: &'static str, description: &'static str, func: F) -> Self
+    where
+        F: FnOnce(Notifier<f32>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.event(Event::f32(name).description(description), func)
+    }
+
+    /// Monitors a [`PeriodicEvent`] notifier with `f32` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn periodic_f32<F, Fut>(
+        self,
+        name: &'static str,
+        description: &'static str,
+        interval: Duration,
+        func: F,
+    ) -> Self
+    where
+        F: FnOnce(PeriodicNotifier<f32>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.periodic_event(
+            PeriodicEvent::new(Event::f32(name).description(description), interval),
+            func,
+        )
+    }
+
+    /// Monitors an [`Event`] notifier with `f64` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn f64_event<F, Fut>(self, name: &'static str, description: &'static str, func: F) -> Self
+    where
+        F: FnOnce(Notifier<f64>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.event(Event::f64(name).description(description), func)
+    }
+
+    /// Monitors a [`PeriodicEvent`] notifier with `f64` values.
+    ///
+    /// Discards the event if it matches an existing one.
+    #[must_use]
+    #[inline]
+    pub fn periodic_f64<F, Fut>(
+        self,
+        name: &'static str,
+        description: &'static str,
+        interval: Duration,
+        func: F,
+    ) -> Self
+    where
+        F: FnOnce(PeriodicNotifier<f64>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.periodic_event(
+            PeriodicEvent::new(Event::f64(name).description(description), interval),
+            func,
+        )
+    }
+
+    /// Sets the [`EmissionRules`] of the events with the given name.
+    ///
+    /// Notifiers update the events and notify the network task only when
+    /// their values satisfy the rules. Rules are discarded when no
+    /// event has the given name.
+    #[must_use]
+    #[inline]
+    pub fn rules(mut self, name: &'static str, rules: EmissionRules) -> Self {
+        if !self.events.set_rules(name, rules) {
+            warn!("No event named `{name}`, discard its emission rules.");
+        }
+        self
+    }
+
+    // Runs all event tasks along with the network task.
+    //
+    // Returns the events description, together with the set of running tasks.
+    // All tasks are aborted when the set is dropped.
+    pub(crate) async fn run(
+        self,
+        mac: Option<[u8; 6]>,
+    ) -> Result<(EventsDescription, JoinSet<()>)> {
+        if self.events.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Events,
+                "No events in the events manager",
+            ));
+        }
+
+        let mac = mac.unwrap_or_else(|| {
+            warn!("No MAC address available, the events topic uses a zeroed one");
+            [0; 6]
+        });
+
+        let topic = TopicBuilder::new()
+            .prefix(self.config.topic_prefix)
+            .mac(mac)
+            .suffix(TOPIC_SUFFIX)
+            .build();
+
+        let (address, port) = self.config.broker.resolve().await?;
+
+        let mut mqttoptions =
+            MqttOptions::new(topic.as_str().replace('/', "-"), address.to_string(), port);
+        mqttoptions.set_keep_alive(KEEP_ALIVE_TIME);
+
+        let (client, eventloop) = AsyncClient::new(mqttoptions, ASYNC_CHANNEL_CAPACITY);
+
+        // Initialize the shared events before running any task.
+        {
+            *self.shared.events.lock().await = self.events.clone();
+        }
+
+        let mut tasks = JoinSet::new();
+        tasks.spawn(poll_broker(eventloop));
+        tasks.spawn(write_on_network(
+            client,
+            topic.clone(),
+            self.config.encoding,
+            self.shared,
+        ));
+        for task in self.tasks {
+            tasks.spawn(task);
+        }
+
+        info!("Publishing events on topic `{}`", topic.as_str());
+
+        Ok((
+            EventsDescription::new(ToscaBrokerData::new(address, port), topic, self.events)
+                .encoding(self.config.encoding),
+            tasks,
+        ))
+    }
+
+    // Checks whether an event can be added, given whether its value is of
+    // the notifier type.
+    fn check_event(&self, event: &Event, is_notifier_type: bool) -> bool {
+        if self.events.event(&event.name).is_some() {
+            info!(
+                "The event `{}` is equal to an existing one, discard it.",
+                event.name
+            );
+            return false;
+        }
+
+        if !is_notifier_type {
+            warn!(
+                "The event `{}` is not of the notifier type, discard it.",
+                event.name
+            );
+            return false;
+        }
+
+        true
+    }
+}
+
+async fn poll_broker(mut eventloop: EventLoop) {
+    loop {
+        // Polling drives the connection to the broker, reconnecting
+        // automatically after a failure.
+        if let Err(e) = eventloop.poll().await {
+            error!("Error in the connection with the `MQTT` broker: {e}");
+            sleep(RETRY_INTERVAL).await;
+        }
+    }
+}
+
+// Returns the current system time, used as timestamp of the published frames.
+fn now() -> Option<Timestamp> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|elapsed| i64::try_from(elapsed.as_secs()).ok())
+        .map(Timestamp::from_unix)
+}
+
+async fn write_on_network(
+    client: AsyncClient,
+    topic: Topic,
+    encoding: Encoding,
+    shared: SharedEvents,
+) {
+    loop {
+        // Wait until a notifier updates an event.
+        shared.notify.notified().await;
+
+        // The lock will be released at the end of this scope,
+        // once the data has been encoded as a new frame.
+        let encoded_data = {
+            let mut events = shared.events.lock().await;
+            events.next_frame(now());
+            encoding.encode(&*events)
+        };
+
+        let data = match encoded_data {
+            Ok(data) => data,
+            Err(e) => {
+                error!("Error retrieving data: {e}");
+                continue;
+            }
+        };
+
+        debug!("Data size: {} bytes", data.len());
+
+        // Transmit the data over the network.
+        if let Err(e) = client
+            .publish(topic.as_str(), QoS::AtMostOnce, false, data)
+            .await
+        {
+            error!("Error while publishing data over the network: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::time::Duration;
+
+    use tosca::events::{EmissionRules, Event, EventFields, PeriodicEvent};
+
+    use super::broker::BrokerData;
+    use super::interrupt::Notifier;
+    use super::{EventsConfig, EventsManager, now};
+
+    const MAC: [u8; 6] = [0x0a, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f];
+
+    fn events_manager() -> EventsManager {
+        EventsManager::config(EventsConfig::new(
+            BrokerData::ip(IpAddr::V4(Ipv4Addr::LOCALHOST), 1883),
+            "tosca",
+        ))
+    }
+
+    #[test]
+    fn discard_duplicated_events() {
+        let manager = events_manager()
+            .bool_event("button", "A button.", |_| async {})
+            .bool_event("button", "The same button.", |_| async {})
+            .periodic_f32(
+                "temperature",
+                "A temperature.",
+                Duration::from_secs(1),
+                |_| async {},
+            )
+            .periodic_f32(
+                "temperature",
+                "The same temperature.",
+                Duration::from_secs(1),
+                |_| async {},
+            );
+
+        assert_eq!(manager.events.events_as_slice().len(), 1);
+        assert_eq!(manager.events.periodic_events_as_slice().len(), 1);
+        assert_eq!(manager.tasks.len(), 2);
+
+        // Events with the same name are discarded, whatever their type.
+        let manager = manager
+            .u8_event("temperature", "A level.", |_| async {})
+            .event(Event::u16("counter"), |_: Notifier<u32>| async {});
+        assert_eq!(manager.events.events_as_slice().len(), 1);
+        assert_eq!(manager.tasks.len(), 2);
+    }
+
+    #[test]
+    fn frame_timestamp() {
+        assert!(now().is_some_and(|timestamp| timestamp.unix() > 0));
+    }
+
+    #[tokio::test]
+    async fn empty_events_manager() {
+        assert!(events_manager().run(Some(MAC)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn update_events() {
+        let manager = events_manager()
+            .bool_event("button", "A button.", |notifier| async move {
+                notifier.update_event(true).await;
+            })
+            .u8_event("level", "A level.", |notifier| async move {
+                notifier.update_event(3).await;
+            });
+
+        let shared = manager.shared.clone();
+        let (description, mut tasks) = manager.run(Some(MAC)).await.unwrap();
+
+        assert_eq!(description.topic.as_str(), "tosca/0A1B2C3D4E5F/events");
+        assert_eq!(
+            description.broker_data.address,
+            IpAddr::V4(Ipv4Addr::LOCALHOST)
+        );
+        assert_eq!(description.broker_data.port, 1883);
+        assert_eq!(description.events.value::<bool>("button"), Some(false));
+
+        // Wait for the two event tasks to complete.
+        for _ in 0..2 {
+            tasks.join_next().await;
+        }
+
+        let events = shared.events.lock().await;
+        assert_eq!(events.value::<bool>("button"), Some(true));
+        assert_eq!(events.value::<u8>("level"), Some(3));
+    }
+
+    #[tokio::test]
+    async fn update_events_of_any_type() {
+        let reading = |temperature: f32, humidity: u8| {
+            EventFields::init("temperature".into(), temperature.into())
+                .insert("humidity".into(), humidity.into())
+        };
+
+        let manager = events_manager()
+            .event(Event::text("status"), |notifier| async move {
+                notifier.update_event(String::from("running")).await;
+            })
+            .event(Event::i64("energy"), |notifier| async move {
+                notifier.update_event(-1_500_000_000_000_i64).await;
+            })
+            .periodic_event(
+                PeriodicEvent::new(
+                    Event::object("reading", reading(0., 0)),
+                    Duration::from_millis(1),
+                ),
+                move |notifier| async move {
+                    notifier.update_event(reading(21.5, 40)).await;
+                },
+            );
+
+        let shared = manager.shared.clone();
+        let (_, mut tasks) = manager.run(Some(MAC)).await.unwrap();
+
+        for _ in 0..3 {
+            tasks.join_next().await;
+        }
+
+        let events = shared.events.lock().await;
+        assert_eq!(events.value::<String>("status").as_deref(), Some("running"));
+        assert_eq!(events.value::<i64>("energy"), Some(-1_500_000_000_000_i64));
+        assert_eq!(
+            events.value::<EventFields>("reading"),
+            Some(reading(21.5, 40))
+        );
+    }
+
+    #[tokio::test]
+    async fn events_with_rules() {
+        let manager = events_manager()
+            .f32_event("temperature", "A temperature.", |notifier| async move {
+                for value in [20., 20.2, 21., 20.8] {
+                    notifier.update_event(value).await;
+                }
+            })
+            .periodic_bool(
+                "door",
+                "A door.",
+                Duration::from_millis(1),
+                |notifier| async move {
+                    notifier.update_event(true).await;
+                    notifier.update_event(false).await;
+                },
+            )
+            .rules("temperature", EmissionRules::new().deadband(0.5))
+            .rules(
+                "door",
+                EmissionRules::new().min_interval(Duration::from_secs(60)),
+            )
+            .rules("missing", EmissionRules::new().on_change());
+
+        let shared = manager.shared.clone();
+        let (description, mut tasks) = manager.run(Some(MAC)).await.unwrap();
+
+        // Rules are advertised in the events description.
+        assert_eq!(
+            description.events.events_as_slice()[0].rules,
+            EmissionRules::new().deadband(0.5)
+        );
+
+        for _ in 0..2 {
+            tasks.join_next().await;
+        }
+
+        // Values which do not satisfy the rules are never emitted.
+        let events = shared.events.lock().await;
+        assert!(
+            events
+                .value::<f32>("temperature")
+                .is_some_and(|value| (value - 21.).abs() < f32::EPSILON)
+        );
+        assert_eq!(events.value::<bool>("door"), Some(true));
+    }
+
+    #[cfg(feature = "cbor")]
+    #[tokio::test]
+    async fn cbor_events() {
+        use tosca::encoding::Encoding;
+
+        let (description, _tasks) = events_manager()
+            .bool_event("button", "A button.", |_| async {})
+            .run(Some(MAC))
+            .await
+            .unwrap();
+        assert_eq!(description.encoding, Encoding::Json);
+
+        let manager = EventsManager::config(
+            EventsConfig::new(
+                BrokerData::ip(IpAddr::V4(Ipv4Addr::LOCALHOST), 1883),
+                "tosca",
+            )
+            .cbor(),
+        );
+        let (description, _tasks) = manager
+            .bool_event("button", "A button.", |_| async {})
+            .run(Some(MAC))
+            .await
+            .unwrap();
+        assert_eq!(description.encoding, Encoding::Cbor);
+    }
+}
@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use tosca::events::EventType;
+
+use tokio::time::sleep;
+
+use super::{LastEmission, SharedEvents};
+
+/// A notifier for signaling a [`tosca::events::PeriodicEvent`].
+#[derive(Debug)]
+pub struct PeriodicNotifier<T> {
+    index: usize,
+    time_interval: Duration,
+    shared: SharedEvents,
+    last_emission: LastEmission,
+    phantom: PhantomData<T>,
+}
+
+impl<T: EventType> PeriodicNotifier<T> {
+    /// Updates the [`tosca::events::PeriodicEvent`] and then waits for
+    /// its time interval before checking again the event.
+    ///
+    /// The event is not updated when the value does not satisfy its
+    /// emission rules.
+    #[inline]
+    pub async fn update_event(&self, value: T) {
+        // The lock will be released at the end of this scope.
+        let emit = {
+            let mut events = self.shared.events.lock().await;
+            let value = value.into_value();
+            let emit = events.periodic_events_as_slice()[self.index]
+                .event
+                .should_emit(&value, self.last_emission.elapsed());
+            if emit {
+                events.update_periodic_value(self.index, value);
+                self.last_emission.record();
+            }
+            emit
+        };
+        // Notify the network task and wait for the chosen time interval.
+        if emit {
+            self.shared.notify.notify_one();
+        }
+        sleep(self.time_interval).await;
+    }
+
+    pub(crate) const fn new(index: usize, time_interval: Duration, shared: SharedEvents) -> Self {
+        Self {
+            index,
+            time_interval,
+            shared,
+            last_emission: LastEmission::new(),
+            phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use tosca::events::Topic;
+
+pub(crate) struct TopicBuilder<'a> {
+    prefix: &'a str,
+    mac: [u8; 6],
+    suffix: &'a str,
+}
+
+impl<'a> TopicBuilder<'a> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            prefix: "",
+            mac: [0; 6],
+            suffix: "",
+        }
+    }
+
+    pub(crate) const fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    pub(crate) const fn mac(mut self, mac: [u8; 6]) -> Self {
+        self.mac = mac;
+        self
+    }
+
+    pub(crate) const fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn build(self) -> Topic {
+        Topic::new(format!(
+            "{}/{:02X}{:02X}{:02X}{:02X}{:02X}{:02X}/{}",
+            self.prefix,
+            self.mac[0],
+            self.mac[1],
+            self.mac[2],
+            self.mac[3],
+            self.mac[4],
+            self.mac[5],
+            self.suffix
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TopicBuilder;
+
+    #[test]
+    fn build_topic() {
+        let topic = TopicBuilder::new()
+            .prefix("tosca")
+            .mac([0x0a, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f])
+            .suffix("events")
+            .build();
+
+        assert_eq!(topic.as_str(), "tosca/0A1B2C3D4E5F/events");
+    }
+}
@@ -43,6 +43,8 @@ pub mod devices;
 pub mod device;
 /// Error management.
 pub mod error;
+/// Events publishing over the network.
+pub mod events;
 /// All responses kinds along with their payloads.
 pub mod responses;
 /// The firmware server.
@@ -0,0 +1,145 @@
+use tosca::encoding::Encoding;
+
+use axum::{
+    body::{Body, to_bytes},
+    extract::Request,
+    http::{
+        HeaderValue,
+        header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, VARY},
+    },
+    middleware::Next,
+    response::Response,
+};
+
+use serde_json::Value;
+
+use tracing::error;
+
+// Maximum size of a response body which can be encoded.
+const BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+// Encodes `JSON` responses with the encoding negotiated through the `Accept`
+// header of the request.
+//
+// Since all encodings share the `JSON` data model, responses are always
+// produced as `JSON` and then converted. Any other response, such as a byte
+// stream, is sent unchanged.
+pub(crate) async fn encode_response(request: Request, next: Next) -> Response {
+    let encoding = request
+        .headers()
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .map_or(Encoding::Json, Encoding::negotiate);
+
+    let mut response = next.run(request).await;
+    response
+        .headers_mut()
+        .append(VARY, HeaderValue::from_static("accept"));
+
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(Encoding::from_content_type)
+        .is_some_and(|content_encoding| content_encoding.is_json());
+
+    if encoding.is_json() || !is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, BODY_LIMIT).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            error!("Unable to read the response body: {e}");
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    let encoded = serde_json::from_slice::<Value>(&bytes)
+        .map_err(|e| e.to_string())
+        .and_then(|value| encoding.encode(&value).map_err(|e| e.to_string()));
+
+    match encoded {
+        Ok(encoded) => {
+            parts.headers.remove(CONTENT_LENGTH);
+            parts.headers.insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static(encoding.content_type()),
+            );
+            Response::from_parts(parts, Body::from(encoded))
+        }
+        // Fall back to the `JSON` response, which a controller can
+        // always decode.
+        Err(e) => {
+            error!("Unable to encode the response as {encoding}: {e}");
+            Response::from_parts(parts, Body::from(bytes))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tosca::encoding::{CBOR_CONTENT_TYPE, Encoding, JSON_CONTENT_TYPE};
+    use tosca::response::OkResponse;
+
+    use axum::body::{Body, to_bytes};
+    use axum::http::{Request, header::ACCEPT, header::CONTENT_TYPE};
+    use axum::middleware::from_fn;
+    use axum::{Json, Router};
+
+    use tower::ServiceExt;
+
+    use super::{BODY_LIMIT, encode_response};
+
+    async fn response(accept: Option<&str>, path: &str) -> (String, Vec<u8>) {
+        let router = Router::new()
+            .route(
+                "/ok",
+                axum::routing::get(|| async { Json(OkResponse::ok()) }),
+            )
+            .route("/text", axum::routing::get(|| async { "text" }))
+            .layer(from_fn(encode_response));
+
+        let mut request = Request::get(path);
+        if let Some(accept) = accept {
+            request = request.header(ACCEPT, accept);
+        }
+
+        let response = router
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let content_type = response.headers()[CONTENT_TYPE].to_str().unwrap().into();
+        let body = to_bytes(response.into_body(), BODY_LIMIT).await.unwrap();
+        (content_type, body.to_vec())
+    }
+
+    #[tokio::test]
+    async fn encode_responses() {
+        let json = Encoding::Json.encode(&OkResponse::ok()).unwrap();
+
+        assert_eq!(
+            response(None, "/ok").await,
+            (JSON_CONTENT_TYPE.into(), json.clone())
+        );
+        assert_eq!(
+            response(Some("application/json"), "/ok").await,
+            (JSON_CONTENT_TYPE.into(), json)
+        );
+
+        let (content_type, body) =
+            response(Some("application/cbor, application/json"), "/ok").await;
+        assert_eq!(content_type, CBOR_CONTENT_TYPE);
+        assert_eq!(
+            Encoding::Cbor.decode::<OkResponse>(&body).unwrap(),
+            OkResponse::ok()
+        );
+
+        // Responses which are not `JSON` are never encoded.
+        let (content_type, body) = response(Some("application/cbor"), "/text").await;
+        assert!(content_type.starts_with("text/plain"));
+        assert_eq!(body, b"text");
+    }
+}
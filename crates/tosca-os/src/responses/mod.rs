@@ -12,12 +12,18 @@ pub mod serial;
 #[cfg(feature = "stream")]
 pub mod stream;
 
+#[cfg(feature = "cbor")]
+pub(crate) mod encoding;
+mod validation;
+
+use std::sync::Arc;
+
 use tosca::hazards::Hazard;
 use tosca::parameters::Parameters;
 use tosca::response::ResponseKind;
 use tosca::route::{RestKind, Route, RouteConfig};
 
-use axum::{Router, handler::Handler};
+use axum::{Router, handler::Handler, middleware::from_fn_with_state};
 
 use tracing::info;
 
@@ -117,6 +123,17 @@ impl BaseResponse {
             )
             .with_state(state);
 
+        // Validate the incoming parameters before running the handler, so
+        // handlers always receive values compliant with the route parameters.
+        let router = if route.parameters().is_empty() {
+            router
+        } else {
+            router.route_layer(from_fn_with_state(
+                Arc::new(route.parameters().clone().serialize_data()),
+                validation::validate_parameters,
+            ))
+        };
+
         Self {
             router,
             route,
@@ -0,0 +1,427 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tosca::parameters::{ParameterKind, ParameterValue, ParametersData};
+
+use axum::{
+    RequestPartsExt,
+    body::{Body, to_bytes},
+    extract::{RawPathParams, Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use serde_json::Value;
+
+use tracing::warn;
+
+use super::error::ErrorResponse;
+
+// Maximum size of a request body containing parameters.
+//
+// It is the same limit adopted by default for the `Json` extractor.
+const BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+// Checks the values of the incoming parameters against the route parameters,
+// returning a description of the first invalid parameter.
+//
+// Raw values are decoded according to the kinds of the route parameters.
+fn check_parameters<T>(
+    parameters_data: &ParametersData,
+    values: &HashMap<String, T>,
+    decode: impl Fn(&ParameterKind, &T) -> Option<ParameterValue>,
+) -> Result<(), String> {
+    for (name, parameter_kind) in parameters_data {
+        let Some(raw) = values.get(name) else {
+            return Err(format!("Missing parameter `{name}`"));
+        };
+
+        let Some(value) = decode(parameter_kind, raw) else {
+            return Err(format!(
+                "Invalid `{name}`: expected a value of type `{}`",
+                parameter_kind.as_type()
+            ));
+        };
+
+        parameter_kind
+            .validate(&value)
+            .map_err(|violation| format!("Invalid `{name}`: {violation}"))?;
+    }
+
+    Ok(())
+}
+
+// Validates the parameters of an incoming request before running
+// the route handler.
+//
+// Parameters of `GET` requests are contained in the path, while parameters
+// of all other requests are contained in a `JSON` body.
+pub(super) async fn validate_parameters(
+    State(parameters_data): State<Arc<ParametersData>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (mut parts, body) = request.into_parts();
+
+    let (checked, body) = if parts.method == Method::GET {
+        let segments: HashMap<String, String> = match parts.extract::<RawPathParams>().await {
+            Ok(params) => params
+                .iter()
+                .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                .collect(),
+            Err(e) => return ErrorResponse::invalid_data(&e.body_text()).into_response(),
+        };
+        let checked = check_parameters(&parameters_data, &segments, |kind, segment| {
+            ParameterValue::from_path_segment(kind, segment)
+        });
+        (checked, body)
+    } else {
+        let Ok(bytes) = to_bytes(body, BODY_LIMIT).await else {
+            return ErrorResponse::invalid_data("Unable to read the request body").into_response();
+        };
+        let values: HashMap<String, Value> = if bytes.is_empty() {
+            HashMap::new()
+        } else {
+            match serde_json::from_slice(&bytes) {
+                Ok(values) => values,
+                Err(e) => {
+                    return ErrorResponse::invalid_data_with_error(
+                        "Parameters must be a JSON object",
+                        &e.to_string(),
+                    )
+                    .into_response();
+                }
+            }
+        };
+        let checked = check_parameters(&parameters_data, &values, ParameterValue::from_json);
+        (checked, Body::from(bytes))
+    };
+
+    if let Err(description) = checked {
+        warn!("Request to `{}` rejected: {description}", parts.uri);
+        return ErrorResponse::invalid_data(&description).into_response();
+    }
+
+    next.run(Request::from_parts(parts, body)).await
+}
+
+#[cfg(test)]
+// Test route handlers return the `ErrorResponse` type of the library.
+#[allow(clippy::result_large_err)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use tosca::parameters::{
+        DecimalPrecision, ParameterKind, ParameterValue, Parameters, ParametersData,
+    };
+    use tosca::route::Route;
+    use tosca::values::{Color, TimeOfDay, Timestamp};
+
+    use axum::body::Body;
+    use axum::extract::{Json, Path};
+    use axum::http::{Request, StatusCode};
+
+    use serde_json::{Value, json};
+
+    use tower::ServiceExt;
+
+    use crate::responses::BaseResponse;
+    use crate::responses::error::ErrorResponse;
+    use crate::responses::ok::OkResponse;
+
+    use super::check_parameters;
+
+    fn check_body(parameters_data: &ParametersData, value: Value) -> Result<(), String> {
+        let values: HashMap<String, Value> = serde_json::from_value(value).unwrap();
+        check_parameters(parameters_data, &values, ParameterValue::from_json)
+    }
+
+    fn check_path(
+        parameters_data: &ParametersData,
+        segments: &[(&str, &str)],
+    ) -> Result<(), String> {
+        let segments: HashMap<String, String> = segments
+            .iter()
+            .map(|(name, segment)| ((*name).to_owned(), (*segment).to_owned()))
+            .collect();
+        check_parameters(parameters_data, &segments, |kind, segment| {
+            ParameterValue::from_path_segment(kind, segment)
+        })
+    }
+
+    #[test]
+    fn check_parameters_values() {
+        let parameters_data = Parameters::new()
+            .bool("save-energy", false)
+            .u8_with_limits("brightness", 5, 1, 10)
+            .f32_with_limits("ratio", 0.5, 0., 1., DecimalPrecision::OneDigit)
+            .i16_with_limits("offset", 0, -50, 50)
+            .choice("mode", ["auto", "night"])
+            .serialize_data();
+
+        // JSON values.
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"save-energy": true, "brightness": 10, "ratio": 0.2, "offset": -50, "mode": "auto"})
+            ),
+            Ok(())
+        );
+
+        // Path segments.
+        assert_eq!(
+            check_path(
+                &parameters_data,
+                &[
+                    ("save-energy", "true"),
+                    ("brightness", "1"),
+                    ("ratio", "0.2"),
+                    ("offset", "-3"),
+                    ("mode", "night"),
+                ]
+            ),
+            Ok(())
+        );
+
+        // Numbers and booleans are not strings in a body.
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"save-energy": true, "brightness": "1", "ratio": 0.2, "offset": -3, "mode": "auto"})
+            ),
+            Err("Invalid `brightness`: expected a value of type `u8`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"save-energy": true, "ratio": 0.2, "offset": 0, "mode": "auto"})
+            ),
+            Err("Missing parameter `brightness`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"save-energy": 1, "brightness": 10, "ratio": 0.2, "offset": -50, "mode": "auto"})
+            ),
+            Err("Invalid `save-energy`: expected a value of type `bool`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"save-energy": true, "brightness": 11, "ratio": 0.2, "offset": 0, "mode": "auto"})
+            ),
+            Err("Invalid `brightness`: value greater than the maximum `10`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"save-energy": true, "brightness": 10, "ratio": 0.25, "offset": 0, "mode": "auto"})
+            ),
+            Err("Invalid `ratio`: value not a multiple of the step `0.1`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"save-energy": true, "brightness": 10, "ratio": 0.2, "offset": -51, "mode": "auto"})
+            ),
+            Err("Invalid `offset`: value lower than the minimum `-50`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"save-energy": true, "brightness": 10, "ratio": 0.2, "offset": 0, "mode": "day"})
+            ),
+            Err("Invalid `mode`: value not among the allowed options: `auto`, `night`".into())
+        );
+    }
+
+    #[test]
+    fn check_color_and_time_parameters() {
+        let parameters_data = Parameters::new()
+            .color("color", Color::rgb(255, 255, 255))
+            .color_temperature_with_limits("temperature", 2700, 2000, 6500)
+            .duration_with_limits(
+                "fade",
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::from_secs(10),
+            )
+            .time_of_day("alarm", TimeOfDay::MIDNIGHT)
+            .timestamp("start", Timestamp::UNIX_EPOCH)
+            .serialize_data();
+
+        let valid = json!({
+            "color": "hsv(120,50,100)",
+            "temperature": 3000,
+            "fade": "2s",
+            "alarm": "07:30",
+            "start": "2024-05-01T12:30:00Z"
+        });
+        assert_eq!(check_body(&parameters_data, valid.clone()), Ok(()));
+
+        let mut invalid = valid.clone();
+        invalid["color"] = json!("red");
+        assert_eq!(
+            check_body(&parameters_data, invalid),
+            Err("Invalid `color`: expected a value of type `Color`".into())
+        );
+
+        let mut invalid = valid.clone();
+        invalid["fade"] = json!("1m");
+        assert_eq!(
+            check_body(&parameters_data, invalid),
+            Err("Invalid `fade`: value greater than the maximum `10000ms`".into())
+        );
+
+        let mut invalid = valid;
+        invalid["alarm"] = json!("25:00");
+        assert_eq!(
+            check_body(&parameters_data, invalid),
+            Err("Invalid `alarm`: expected a value of type `TimeOfDay`".into())
+        );
+    }
+
+    #[test]
+    fn check_structured_parameters() {
+        let parameters_data = Parameters::new()
+            .list_with_length(
+                "zones",
+                ParameterKind::U16 {
+                    default: 0,
+                    min: u16::MAX,
+                    max: u16::MIN,
+                    unit: None,
+                },
+                1,
+                4,
+            )
+            .object(
+                "time",
+                Parameters::new()
+                    .u8_with_limits("hour", 0, 0, 23)
+                    .u8_with_limits("minute", 0, 0, 59),
+            )
+            .serialize_data();
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"zones": [1, 2], "time": {"hour": 7, "minute": 30}})
+            ),
+            Ok(())
+        );
+
+        // JSON-encoded path segments.
+        assert_eq!(
+            check_path(
+                &parameters_data,
+                &[("zones", "[3]"), ("time", r#"{"hour": 7, "minute": 30}"#)]
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"zones": [], "time": {"hour": 7, "minute": 30}})
+            ),
+            Err("Invalid `zones`: fewer elements than the minimum `1`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"zones": [1, "a"], "time": {"hour": 7, "minute": 30}})
+            ),
+            Err("Invalid `zones`: expected a value of type `List`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"zones": [1], "time": {"hour": 24, "minute": 30}})
+            ),
+            Err("Invalid `time`: field `hour`: value greater than the maximum `23`".into())
+        );
+
+        assert_eq!(
+            check_body(
+                &parameters_data,
+                json!({"zones": [1], "time": {"hour": 7, "minute": 30, "second": 0}})
+            ),
+            Err("Invalid `time`: unknown field `second`".into())
+        );
+    }
+
+    async fn status(base_response: &BaseResponse, request: Request<Body>) -> StatusCode {
+        base_response
+            .router
+            .clone()
+            .oneshot(request)
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn validate_requests() {
+        async fn get_handler(Path(_): Path<(u8,)>) -> Result<OkResponse, ErrorResponse> {
+            Ok(OkResponse::ok())
+        }
+
+        async fn put_handler(Json(_): Json<Value>) -> Result<OkResponse, ErrorResponse> {
+            Ok(OkResponse::ok())
+        }
+
+        let parameters = Parameters::new().u8_with_limits("brightness", 5, 1, 10);
+
+        let get = BaseResponse::stateless(
+            Route::get("Get", "/get").with_parameters(parameters.clone()),
+            tosca::response::ResponseKind::Ok,
+            get_handler,
+        );
+
+        assert_eq!(
+            status(&get, Request::get("/get/4").body(Body::empty()).unwrap()).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&get, Request::get("/get/40").body(Body::empty()).unwrap()).await,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+
+        let put = BaseResponse::stateless(
+            Route::put("Put", "/put").with_parameters(parameters),
+            tosca::response::ResponseKind::Ok,
+            put_handler,
+        );
+
+        let put_request = |body: Value| {
+            Request::put("/put")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+
+        assert_eq!(
+            status(&put, put_request(json!({"brightness": 4}))).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&put, put_request(json!({"brightness": 0}))).await,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        assert_eq!(
+            status(&put, put_request(json!({}))).await,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -41,6 +41,8 @@ where
     scheme: &'a str,
     // Well-known service.
     well_known_service: &'a str,
+    // Whether the OpenAPI description of the device is served.
+    openapi: bool,
     // Service configurator.
     service_config: Option<ServiceConfig<'a>>,
     // Device.
@@ -68,6 +70,7 @@ where
                 port: DEFAULT_SERVER_PORT,
                 scheme: DEFAULT_SCHEME,
                 well_known_service: DEFAULT_WELL_KNOWN_SERVICE,
+                openapi: false,
                 service_config: None,
                 device,
             },
@@ -102,6 +105,16 @@ where
         self
     }
 
+    /// Serves the `OpenAPI` description of the device.
+    ///
+    /// The description is available at the `openapi.json` path of the
+    /// well-known `URI`, as in `/.well-known/tosca/openapi.json`.
+    #[must_use]
+    pub const fn openapi(mut self) -> Self {
+        self.data.openapi = true;
+        self
+    }
+
     /// Sets the configuration for the discovery service.
     #[must_use]
     #[inline]
@@ -168,7 +181,27 @@ where
         let listener_bind = format!("{}:{}", self.data.http_address, self.data.port);
 
         // Consume a device returning all server information.
-        let (device_main_route, device_info, device_router) = self.data.device.finalize();
+        let (device_main_route, mut device_info, device_router, events) =
+            self.data.device.finalize();
+
+        // Run events tasks if present.
+        //
+        // Tasks are aborted when the server stops.
+        let _events_tasks = if let Some(events) = events {
+            let (events_description, events_tasks) = events
+                .run(device_info.wifi_mac.or(device_info.ethernet_mac))
+                .await?;
+            device_info = device_info.events_description(events_description);
+            Some(events_tasks)
+        } else {
+            None
+        };
+
+        // Describe the device through the OpenAPI format if requested.
+        let openapi = self
+            .data
+            .openapi
+            .then(|| tosca::openapi::document(&device_info));
 
         // Serialize device information returning a json format.
         let device_info = serde_json::to_value(device_info)?;
@@ -206,6 +239,24 @@ where
             )
             .nest(device_main_route, device_router);
 
+        // Serve the OpenAPI description next to the well-known URI.
+        let router = if let Some(openapi) = openapi {
+            let openapi_uri = format!("{well_known_uri}/openapi.json");
+            info!("Server route: [GET, \"{}\"]", openapi_uri);
+            router.route(
+                &openapi_uri,
+                axum::routing::get(move || async { axum::Json(openapi) }),
+            )
+        } else {
+            router
+        };
+
+        // Encode responses as negotiated with the controller.
+        #[cfg(feature = "cbor")]
+        let router = router.layer(axum::middleware::from_fn(
+            crate::responses::encoding::encode_response,
+        ));
+
         // Print server Ip and port.
         info!("Device reachable at this HTTP address: {listener_bind}");
 
@@ -1,9 +1,20 @@
+use hashbrown::DefaultHashBuilder;
+
+use indexmap::set::{IndexSet, IntoIter, Iter};
+
 use serde::Serialize;
 
 use crate::economy::Economy;
 use crate::energy::Energy;
 use crate::events::EventsDescription;
-use crate::route::RouteConfigs;
+use crate::route::{
+    BATTERY_ROUTES, CAMERA_ROUTES, COLOR_ROUTES, COVER_ROUTES, CapabilityRoute, DIMMABLE_ROUTES,
+    DOOR_LOCK_ROUTES, DeviceKindRoutes, ENERGY_METERING_ROUTES, LIGHT_ROUTES, MOTION_SENSOR_ROUTES,
+    ON_OFF_ROUTES, RouteConfigs, SWITCH_ROUTES, TEMPERATURE_HUMIDITY_SENSOR_ROUTES,
+    THERMOSTAT_ROUTES, UNKNOWN_ROUTES,
+};
+
+use crate::macros::set;
 
 /// Trait for device kind types.
 ///
@@ -34,7 +45,23 @@ pub trait DeviceKindTrait {
     fn name(&self) -> &'static str;
 }
 
-/// A device kind.
+/// All standard [`DeviceKind`]s.
+pub const ALL_DEVICE_KINDS: &[DeviceKind] = &[
+    DeviceKind::Unknown,
+    DeviceKind::Light,
+    DeviceKind::Switch,
+    DeviceKind::Thermostat,
+    DeviceKind::TemperatureHumiditySensor,
+    DeviceKind::MotionSensor,
+    DeviceKind::Camera,
+    DeviceKind::DoorLock,
+    DeviceKind::Cover,
+];
+
+/// A standard device kind.
+///
+/// Each kind defines its mandatory routes and allowed hazards, so a
+/// controller can operate any device of a known kind.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub enum DeviceKind {
@@ -42,6 +69,20 @@ pub enum DeviceKind {
     Unknown,
     /// Light.
     Light,
+    /// Switch or smart plug.
+    Switch,
+    /// Thermostat.
+    Thermostat,
+    /// Temperature and humidity sensor.
+    TemperatureHumiditySensor,
+    /// Motion sensor.
+    MotionSensor,
+    /// Camera.
+    Camera,
+    /// Door lock.
+    DoorLock,
+    /// Blind or cover.
+    Cover,
 }
 
 impl DeviceKindTrait for DeviceKind {
@@ -49,6 +90,43 @@ impl DeviceKindTrait for DeviceKind {
         match self {
             Self::Unknown => "Unknown",
             Self::Light => "Light",
+            Self::Switch => "Switch",
+            Self::Thermostat => "Thermostat",
+            Self::TemperatureHumiditySensor => "TemperatureHumiditySensor",
+            Self::MotionSensor => "MotionSensor",
+            Self::Camera => "Camera",
+            Self::DoorLock => "DoorLock",
+            Self::Cover => "Cover",
+        }
+    }
+}
+
+impl DeviceKind {
+    /// Returns the [`DeviceKind`] associated with the given name.
+    ///
+    /// If [`None`], the name does not identify any standard device kind.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_DEVICE_KINDS
+            .iter()
+            .find(|kind| kind.name() == name)
+            .copied()
+    }
+
+    /// Returns the mandatory routes and the allowed hazards of
+    /// the [`DeviceKind`].
+    #[must_use]
+    pub const fn routes(&self) -> DeviceKindRoutes {
+        match self {
+            Self::Unknown => UNKNOWN_ROUTES,
+            Self::Light => LIGHT_ROUTES,
+            Self::Switch => SWITCH_ROUTES,
+            Self::Thermostat => THERMOSTAT_ROUTES,
+            Self::TemperatureHumiditySensor => TEMPERATURE_HUMIDITY_SENSOR_ROUTES,
+            Self::MotionSensor => MOTION_SENSOR_ROUTES,
+            Self::Camera => CAMERA_ROUTES,
+            Self::DoorLock => DOOR_LOCK_ROUTES,
+            Self::Cover => COVER_ROUTES,
         }
     }
 }
@@ -90,6 +168,14 @@ impl DeviceKindId {
     pub fn matches<K: DeviceKindTrait>(&self, kind: &K) -> bool {
         self.0 == kind.name()
     }
+
+    /// Returns the standard [`DeviceKind`] identified by this ID.
+    ///
+    /// If [`None`], the ID identifies a custom device kind.
+    #[must_use]
+    pub fn standard_kind(&self) -> Option<DeviceKind> {
+        DeviceKind::from_name(&self.0)
+    }
 }
 
 impl core::fmt::Display for DeviceKindId {
@@ -104,6 +190,81 @@ impl<K: DeviceKindTrait> From<&K> for DeviceKindId {
     }
 }
 
+/// All [`Capability`]s.
+pub const ALL_CAPABILITIES: &[Capability] = &[
+    Capability::OnOff,
+    Capability::Dimmable,
+    Capability::Color,
+    Capability::EnergyMetering,
+    Capability::Battery,
+];
+
+/// A device capability.
+///
+/// A capability describes a behavior shared by devices of different kinds,
+/// such as being dimmable. Each capability maps to a standard set of routes
+/// and parameters, so a controller can operate a device through its
+/// capabilities regardless of its kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum Capability {
+    /// The device can be turned on and off.
+    OnOff,
+    /// The device brightness can be adjusted.
+    Dimmable,
+    /// The device color can be changed.
+    Color,
+    /// The device measures its energy consumption.
+    EnergyMetering,
+    /// The device is powered by a battery.
+    Battery,
+}
+
+impl core::fmt::Display for Capability {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OnOff => "OnOff",
+            Self::Dimmable => "Dimmable",
+            Self::Color => "Color",
+            Self::EnergyMetering => "EnergyMetering",
+            Self::Battery => "Battery",
+        }
+        .fmt(f)
+    }
+}
+
+impl Capability {
+    /// Returns the routes required by the [`Capability`].
+    #[must_use]
+    pub const fn routes(&self) -> &'static [CapabilityRoute] {
+        match self {
+            Self::OnOff => ON_OFF_ROUTES,
+            Self::Dimmable => DIMMABLE_ROUTES,
+            Self::Color => COLOR_ROUTES,
+            Self::EnergyMetering => ENERGY_METERING_ROUTES,
+            Self::Battery => BATTERY_ROUTES,
+        }
+    }
+
+    /// Checks whether the given [`RouteConfigs`] implement all the routes
+    /// required by the [`Capability`].
+    #[must_use]
+    pub fn is_implemented_by(&self, route_configs: &RouteConfigs) -> bool {
+        self.routes().iter().all(|capability_route| {
+            route_configs
+                .iter()
+                .any(|route_config| capability_route.is_implemented_by(route_config))
+        })
+    }
+}
+
+set! {
+  /// A collection of [`Capability`]s.
+  #[derive(Debug, Clone, PartialEq, Serialize)]
+  #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+  pub struct Capabilities(IndexSet<Capability, DefaultHashBuilder>);
+}
+
 /// Device environment.
 ///
 /// Specifies the underlying hardware architecture of a device,
@@ -118,6 +279,119 @@ pub enum DeviceEnvironment {
     Esp32,
 }
 
+/// Device identity.
+///
+/// Identifies a device among the others of a fleet. All fields are optional,
+/// so a device only advertises the ones it knows.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct DeviceIdentity {
+    /// Manufacturer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manufacturer: Option<alloc::borrow::Cow<'static, str>>,
+    /// Model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<alloc::borrow::Cow<'static, str>>,
+    /// Hardware revision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hardware_revision: Option<alloc::borrow::Cow<'static, str>>,
+    /// Firmware version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firmware_version: Option<alloc::borrow::Cow<'static, str>>,
+    /// Serial number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<alloc::borrow::Cow<'static, str>>,
+    /// Location, such as the room where the device is installed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<alloc::borrow::Cow<'static, str>>,
+}
+
+impl DeviceIdentity {
+    /// Creates an empty [`DeviceIdentity`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            manufacturer: None,
+            model: None,
+            hardware_revision: None,
+            firmware_version: None,
+            serial_number: None,
+            location: None,
+        }
+    }
+
+    /// Sets the manufacturer.
+    #[must_use]
+    #[inline]
+    pub fn manufacturer(
+        mut self,
+        manufacturer: impl Into<alloc::borrow::Cow<'static, str>>,
+    ) -> Self {
+        self.manufacturer = Some(manufacturer.into());
+        self
+    }
+
+    /// Sets the model.
+    #[must_use]
+    #[inline]
+    pub fn model(mut self, model: impl Into<alloc::borrow::Cow<'static, str>>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Sets the hardware revision.
+    #[must_use]
+    #[inline]
+    pub fn hardware_revision(
+        mut self,
+        hardware_revision: impl Into<alloc::borrow::Cow<'static, str>>,
+    ) -> Self {
+        self.hardware_revision = Some(hardware_revision.into());
+        self
+    }
+
+    /// Sets the firmware version.
+    #[must_use]
+    #[inline]
+    pub fn firmware_version(
+        mut self,
+        firmware_version: impl Into<alloc::borrow::Cow<'static, str>>,
+    ) -> Self {
+        self.firmware_version = Some(firmware_version.into());
+        self
+    }
+
+    /// Sets the serial number.
+    #[must_use]
+    #[inline]
+    pub fn serial_number(
+        mut self,
+        serial_number: impl Into<alloc::borrow::Cow<'static, str>>,
+    ) -> Self {
+        self.serial_number = Some(serial_number.into());
+        self
+    }
+
+    /// Sets the location.
+    #[must_use]
+    #[inline]
+    pub fn location(mut self, location: impl Into<alloc::borrow::Cow<'static, str>>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Checks whether the [`DeviceIdentity`] is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.manufacturer.is_none()
+            && self.model.is_none()
+            && self.hardware_revision.is_none()
+            && self.firmware_version.is_none()
+            && self.serial_number.is_none()
+            && self.location.is_none()
+    }
+}
+
 /// Device information.
 #[derive(Debug, PartialEq, Clone, Serialize)]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
@@ -157,16 +431,84 @@ impl DeviceInfo {
     }
 }
 
+/// The protocol version implemented by this crate.
+///
+/// The protocol defines the wire format of [`DeviceData`] and of all the
+/// structures it contains, and evolves according to these rules:
+///
+/// - The **minor** version increases for backward-compatible changes, such as
+///   a new optional field or a new parameter kind. Data produced with an
+///   older minor version is always understood.
+/// - The **major** version increases for breaking changes, such as a renamed,
+///   removed or retyped field, or a different encoding of an existing value.
+///
+/// A device and a controller are compatible when they implement the same
+/// major version.
+pub const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::new(2, 0);
+
+/// A protocol version.
+///
+/// See [`PROTOCOL_VERSION`] for the evolution rules of the protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct ProtocolVersion {
+    /// Major version.
+    pub major: u16,
+    /// Minor version.
+    pub minor: u16,
+}
+
+impl core::fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl ProtocolVersion {
+    /// The protocol version of devices which do not declare any version.
+    ///
+    /// It is also the default protocol version.
+    pub const INITIAL: Self = Self::new(1, 0);
+
+    /// Creates a [`ProtocolVersion`].
+    #[must_use]
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+
+    /// Checks whether the [`ProtocolVersion`] is compatible with
+    /// the given one.
+    #[must_use]
+    pub const fn is_compatible(&self, other: &Self) -> bool {
+        self.major == other.major
+    }
+}
+
+impl Default for ProtocolVersion {
+    fn default() -> Self {
+        Self::INITIAL
+    }
+}
+
 /// Device data.
 #[derive(Debug, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 pub struct DeviceData {
+    /// Protocol version.
+    #[serde(default)]
+    pub protocol_version: ProtocolVersion,
     /// Device kind.
     pub kind: DeviceKindId,
     /// Device environment.
     pub environment: DeviceEnvironment,
     /// Device description.
     pub description: Option<alloc::borrow::Cow<'static, str>>,
+    /// Device identity.
+    #[serde(default, skip_serializing_if = "DeviceIdentity::is_empty")]
+    pub identity: DeviceIdentity,
+    /// Device capabilities.
+    #[serde(default, skip_serializing_if = "Capabilities::is_empty")]
+    pub capabilities: Capabilities,
     /// Wi-Fi MAC address.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wifi_mac: Option<[u8; 6]>,
@@ -198,9 +540,12 @@ impl DeviceData {
         mandatory_routes: u8,
     ) -> Self {
         Self {
+            protocol_version: PROTOCOL_VERSION,
             kind,
             environment,
             description: None,
+            identity: DeviceIdentity::new(),
+            capabilities: Capabilities::new(),
             wifi_mac,
             ethernet_mac,
             main_route: main_route.into(),
@@ -217,6 +562,22 @@ impl DeviceData {
         self
     }
 
+    /// Sets the [`DeviceIdentity`].
+    #[must_use]
+    #[inline]
+    pub fn identity(mut self, identity: DeviceIdentity) -> Self {
+        self.identity = identity;
+        self
+    }
+
+    /// Sets the device [`Capabilities`].
+    #[must_use]
+    #[inline]
+    pub fn capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
     /// Adds an [`EventsDescription`].
     #[must_use]
     #[inline]
@@ -229,6 +590,9 @@ impl DeviceData {
 #[cfg(test)]
 #[cfg(feature = "deserialize")]
 mod tests {
+    use alloc::string::ToString;
+
+    use crate::parameters::Parameters;
     use crate::route::{Route, RouteConfigs};
 
     use crate::economy::{Cost, CostTimespan, Costs, Economy, Roi, Rois};
@@ -238,7 +602,11 @@ mod tests {
     };
     use crate::{deserialize, serialize};
 
-    use super::{DeviceData, DeviceEnvironment, DeviceInfo, DeviceKind, DeviceKindId};
+    use super::{
+        ALL_CAPABILITIES, ALL_DEVICE_KINDS, Capabilities, Capability, DeviceData,
+        DeviceEnvironment, DeviceIdentity, DeviceInfo, DeviceKind, DeviceKindId, DeviceKindTrait,
+        PROTOCOL_VERSION, ProtocolVersion,
+    };
 
     fn energy() -> Energy {
         let energy_efficiencies =
@@ -273,12 +641,53 @@ mod tests {
 
     #[test]
     fn test_device_kind() {
-        for device_kind in &[DeviceKind::Unknown, DeviceKind::Light] {
+        for device_kind in ALL_DEVICE_KINDS {
             assert_eq!(
                 deserialize::<DeviceKind>(serialize(device_kind)),
                 *device_kind
             );
+            assert_eq!(
+                DeviceKind::from_name(device_kind.name()),
+                Some(*device_kind)
+            );
+            assert_eq!(
+                DeviceKindId::from(device_kind).standard_kind(),
+                Some(*device_kind)
+            );
         }
+
+        assert_eq!(DeviceKindId::new("Relay").standard_kind(), None);
+    }
+
+    #[test]
+    fn test_device_kind_routes() {
+        for device_kind in ALL_DEVICE_KINDS {
+            let kind_routes = device_kind.routes();
+
+            // Mandatory routes of the same kind have distinct paths.
+            for (index, mandatory_route) in kind_routes.mandatory_routes.iter().enumerate() {
+                assert_ne!(mandatory_route.rest_kinds(), &[]);
+                assert!(
+                    kind_routes.mandatory_routes[index + 1..]
+                        .iter()
+                        .all(|other| other.path() != mandatory_route.path())
+                );
+            }
+
+            assert_ne!(kind_routes.allowed_hazards, &[]);
+        }
+
+        // The routes of a light implement a light and a switch, but not
+        // a door lock.
+        assert!(DeviceKind::Light.routes().is_implemented_by(&routes()));
+        assert!(DeviceKind::Switch.routes().is_implemented_by(&routes()));
+        assert!(!DeviceKind::DoorLock.routes().is_implemented_by(&routes()));
+        assert!(DeviceKind::Unknown.routes().is_implemented_by(&routes()));
+
+        // Wrong REST kind.
+        let routes = RouteConfigs::init(Route::put("Unlock", "/unlock").serialize_data())
+            .insert(Route::get("Lock", "/lock").serialize_data());
+        assert!(!DeviceKind::DoorLock.routes().is_implemented_by(&routes));
     }
 
     #[test]
@@ -321,4 +730,184 @@ mod tests {
             device_data
         );
     }
+
+    #[test]
+    fn test_capabilities() {
+        let brightness = |parameters| {
+            Route::put("Brightness", "/brightness")
+                .with_parameters(parameters)
+                .serialize_data()
+        };
+
+        let route_configs = routes().insert(brightness(
+            Parameters::new().rangeu64("brightness", (0, 100, 1)),
+        ));
+
+        assert!(Capability::OnOff.is_implemented_by(&route_configs));
+        assert!(Capability::Dimmable.is_implemented_by(&route_configs));
+        assert!(!Capability::Color.is_implemented_by(&route_configs));
+        assert!(!Capability::Battery.is_implemented_by(&route_configs));
+
+        // Wrong parameter name and wrong parameter shape.
+        for parameters in [
+            Parameters::new().rangeu64("level", (0, 100, 1)),
+            Parameters::new().bool("brightness", false),
+        ] {
+            let route_configs = routes().insert(brightness(parameters));
+            assert!(!Capability::Dimmable.is_implemented_by(&route_configs));
+        }
+
+        let capabilities = Capabilities::new()
+            .insert(Capability::OnOff)
+            .insert(Capability::Dimmable);
+
+        let device_data = DeviceData::new(
+            DeviceKindId::from(&DeviceKind::Light),
+            DeviceEnvironment::Os,
+            None,
+            None,
+            "/light",
+            route_configs,
+            2,
+        );
+
+        // Empty capabilities are not serialized.
+        assert!(serialize(&device_data).get("capabilities").is_none());
+
+        let device_data = device_data.capabilities(capabilities.clone());
+        assert_eq!(
+            serialize(&device_data)["capabilities"],
+            serde_json::json!(["OnOff", "Dimmable"])
+        );
+        assert_eq!(
+            deserialize::<DeviceData>(serialize(&device_data)).capabilities,
+            capabilities
+        );
+
+        for capability in ALL_CAPABILITIES {
+            assert_eq!(
+                deserialize::<Capability>(serialize(capability)),
+                *capability
+            );
+            assert_eq!(capability.to_string(), serialize(capability));
+        }
+    }
+
+    #[test]
+    fn test_device_identity() {
+        let identity = DeviceIdentity::new()
+            .manufacturer("Tosca Labs")
+            .model("L-100")
+            .hardware_revision("B")
+            .firmware_version("1.2.0")
+            .serial_number("SN0042")
+            .location("Kitchen");
+
+        assert!(DeviceIdentity::new().is_empty());
+        assert!(!identity.is_empty());
+        assert_eq!(
+            serialize(&identity),
+            serde_json::json!({
+                "manufacturer": "Tosca Labs",
+                "model": "L-100",
+                "hardware_revision": "B",
+                "firmware_version": "1.2.0",
+                "serial_number": "SN0042",
+                "location": "Kitchen",
+            })
+        );
+
+        let device_data = DeviceData::new(
+            DeviceKindId::from(&DeviceKind::Light),
+            DeviceEnvironment::Os,
+            None,
+            None,
+            "/light",
+            routes(),
+            2,
+        );
+
+        // An empty identity is not serialized.
+        assert!(serialize(&device_data).get("identity").is_none());
+
+        let device_data = device_data.identity(DeviceIdentity::new().model("L-100"));
+        assert_eq!(
+            serialize(&device_data)["identity"],
+            serde_json::json!({ "model": "L-100" })
+        );
+        assert_eq!(
+            deserialize::<DeviceData>(serialize(&device_data)),
+            device_data
+        );
+    }
+
+    #[test]
+    fn test_protocol_version() {
+        assert_eq!(PROTOCOL_VERSION.to_string(), "2.0");
+        assert!(PROTOCOL_VERSION.is_compatible(&ProtocolVersion::new(2, 7)));
+        assert!(!PROTOCOL_VERSION.is_compatible(&ProtocolVersion::INITIAL));
+        assert_eq!(
+            serialize(PROTOCOL_VERSION),
+            serde_json::json!({ "major": 2, "minor": 0 })
+        );
+    }
+
+    // The wire format of the protocol version 2.0.
+    //
+    // Changing any field name or encoding breaks compatibility, so this test
+    // must only be updated together with a major protocol version increase.
+    #[test]
+    fn test_protocol_wire_format() {
+        let wire_format = serde_json::json!({
+            "protocol_version": { "major": 2, "minor": 0 },
+            "kind": "Light",
+            "environment": "Os",
+            "description": "A light device.",
+            "wifi_mac": [1, 2, 3, 4, 5, 6],
+            "main route": "/light",
+            "route_configs": [
+                {
+                    "name": "On",
+                    "path": "/on",
+                    "description": null,
+                    "REST kind": "Put",
+                    "response kind": "Ok",
+                },
+                {
+                    "name": "Off",
+                    "path": "/off",
+                    "description": null,
+                    "REST kind": "Put",
+                    "response kind": "Ok",
+                },
+            ],
+            "mandatory_routes": 2,
+        });
+
+        let device_data = DeviceData::new(
+            DeviceKindId::from(&DeviceKind::Light),
+            DeviceEnvironment::Os,
+            Some([1, 2, 3, 4, 5, 6]),
+            None,
+            "/light",
+            routes(),
+            2,
+        )
+        .description("A light device.");
+
+        assert_eq!(serialize(&device_data), wire_format);
+        assert_eq!(deserialize::<DeviceData>(wire_format.clone()), device_data);
+
+        // Devices which do not declare a protocol version implement
+        // the initial one.
+        let mut legacy_format = wire_format;
+        legacy_format
+            .as_object_mut()
+            .unwrap()
+            .remove("protocol_version");
+        assert_eq!(
+            deserialize::<DeviceData>(legacy_format).protocol_version,
+            ProtocolVersion::INITIAL
+        );
+    }
 }
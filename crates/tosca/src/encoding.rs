@@ -0,0 +1,318 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use serde::Serialize;
+
+/// The `Content-Type` of `JSON` encoded data.
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// The `Content-Type` of `CBOR` encoded data.
+pub const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
+/// An error occurred while encoding or decoding data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingError {
+    encoding: Encoding,
+    description: String,
+}
+
+impl EncodingError {
+    fn new(encoding: Encoding, description: &impl ToString) -> Self {
+        Self {
+            encoding,
+            description: description.to_string(),
+        }
+    }
+
+    /// Returns the [`Encoding`] which raised the error.
+    #[must_use]
+    pub const fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+}
+
+impl core::fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} encoding error: {}", self.encoding, self.description)
+    }
+}
+
+impl core::error::Error for EncodingError {}
+
+/// Data encodings.
+///
+/// `JSON` is the default encoding and it is always supported, while the
+/// compact `CBOR` binary encoding requires the `cbor` feature.
+///
+/// A `CBOR` document mirrors the `JSON` data model: it contains exactly the
+/// same maps, sequences, strings and numbers of the equivalent `JSON`
+/// document, so data can be converted from one encoding to the other
+/// without any loss.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    /// `JSON` encoding.
+    #[default]
+    Json,
+    /// `CBOR` encoding.
+    Cbor,
+}
+
+impl core::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Json => "JSON",
+            Self::Cbor => "CBOR",
+        }
+        .fmt(f)
+    }
+}
+
+impl Encoding {
+    /// Returns the `Content-Type` associated with the [`Encoding`].
+    #[must_use]
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => JSON_CONTENT_TYPE,
+            Self::Cbor => CBOR_CONTENT_TYPE,
+        }
+    }
+
+    /// Checks whether the [`Encoding`] is `JSON`.
+    #[must_use]
+    pub const fn is_json(&self) -> bool {
+        matches!(self, Self::Json)
+    }
+
+    /// Checks whether the [`Encoding`] can be encoded and decoded.
+    #[must_use]
+    pub const fn is_supported(self) -> bool {
+        match self {
+            Self::Json => true,
+            Self::Cbor => cfg!(feature = "cbor"),
+        }
+    }
+
+    /// Retrieves the [`Encoding`] from a `Content-Type` header value.
+    ///
+    /// Returns [`None`] when the media type is not associated with
+    /// any encoding.
+    #[must_use]
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        if media_type.eq_ignore_ascii_case(JSON_CONTENT_TYPE) {
+            Some(Self::Json)
+        } else if media_type.eq_ignore_ascii_case(CBOR_CONTENT_TYPE) {
+            Some(Self::Cbor)
+        } else {
+            None
+        }
+    }
+
+    /// Negotiates the [`Encoding`] from an `Accept` header value.
+    ///
+    /// The supported encoding with the highest quality value is chosen,
+    /// preferring the first listed one among equal quality values.
+    /// `JSON` is returned when no supported encoding is accepted.
+    #[must_use]
+    pub fn negotiate(accept: &str) -> Self {
+        let mut negotiated = Self::Json;
+        let mut best_quality = 0.;
+
+        for media_range in accept.split(',') {
+            let mut fields = media_range.split(';');
+            let Some(encoding) = fields.next().and_then(Self::from_content_type) else {
+                continue;
+            };
+
+            let quality = fields
+                .filter_map(|field| field.trim().strip_prefix("q="))
+                .find_map(|quality| quality.trim().parse::<f32>().ok())
+                .unwrap_or(1.);
+
+            if encoding.is_supported() && quality > best_quality {
+                negotiated = encoding;
+                best_quality = quality;
+            }
+        }
+
+        negotiated
+    }
+
+    /// Encodes a value.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the value cannot be serialized, or
+    /// when the [`Encoding`] is not supported.
+    pub fn encode<T: Serialize + ?Sized>(self, value: &T) -> Result<Vec<u8>, EncodingError> {
+        match self {
+            Self::Json => serde_json::to_vec(value).map_err(|e| EncodingError::new(self, &e)),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => {
+                // Serialize through the `JSON` data model, so that values
+                // with a human-readable format keep it.
+                let value =
+                    serde_json::to_value(value).map_err(|e| EncodingError::new(self, &e))?;
+                let mut bytes = Vec::new();
+                ciborium::into_writer(&value, &mut bytes)
+                    .map_err(|e| EncodingError::new(self, &e))?;
+                Ok(bytes)
+            }
+            #[cfg(not(feature = "cbor"))]
+            Self::Cbor => Err(EncodingError::new(self, &"unsupported encoding")),
+        }
+    }
+
+    /// Decodes a value.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the bytes do not contain a valid value, or
+    /// when the [`Encoding`] is not supported.
+    #[cfg(feature = "deserialize")]
+    pub fn decode<T: serde::de::DeserializeOwned>(self, bytes: &[u8]) -> Result<T, EncodingError> {
+        match self {
+            Self::Json => serde_json::from_slice(bytes).map_err(|e| EncodingError::new(self, &e)),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => {
+                let value = ciborium::from_reader::<serde_json::Value, _>(bytes)
+                    .map_err(|e| EncodingError::new(self, &e))?;
+                serde_json::from_value(value).map_err(|e| EncodingError::new(self, &e))
+            }
+            #[cfg(not(feature = "cbor"))]
+            Self::Cbor => Err(EncodingError::new(self, &"unsupported encoding")),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "deserialize")]
+mod tests {
+    use core::net::{IpAddr, Ipv4Addr};
+
+    use crate::events::{BrokerData, Event, Events, EventsDescription, Topic};
+    use crate::serialize;
+
+    use super::{CBOR_CONTENT_TYPE, Encoding, JSON_CONTENT_TYPE};
+
+    fn events_description(encoding: Encoding) -> EventsDescription {
+        let mut events = Events::empty();
+        events.add_event(Event::f32("temperature").description("Temperature."));
+
+        EventsDescription::new(
+            BrokerData::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 1883),
+            Topic::new("tosca/events".into()),
+            events,
+        )
+        .encoding(encoding)
+    }
+
+    #[test]
+    fn test_content_type() {
+        assert_eq!(
+            Encoding::from_content_type("application/json; charset=utf-8"),
+            Some(Encoding::Json)
+        );
+        assert_eq!(
+            Encoding::from_content_type(" Application/CBOR "),
+            Some(Encoding::Cbor)
+        );
+        assert_eq!(Encoding::from_content_type("text/plain"), None);
+
+        for encoding in [Encoding::Json, Encoding::Cbor] {
+            assert_eq!(
+                Encoding::from_content_type(encoding.content_type()),
+                Some(encoding)
+            );
+        }
+    }
+
+    #[test]
+    fn test_negotiate() {
+        let cbor = if cfg!(feature = "cbor") {
+            Encoding::Cbor
+        } else {
+            Encoding::Json
+        };
+
+        assert_eq!(Encoding::negotiate(""), Encoding::Json);
+        assert_eq!(Encoding::negotiate("*/*"), Encoding::Json);
+        assert_eq!(Encoding::negotiate(JSON_CONTENT_TYPE), Encoding::Json);
+        assert_eq!(Encoding::negotiate(CBOR_CONTENT_TYPE), cbor);
+        assert_eq!(
+            Encoding::negotiate("application/cbor, application/json;q=0.9"),
+            cbor
+        );
+        assert_eq!(
+            Encoding::negotiate("application/json, application/cbor"),
+            Encoding::Json
+        );
+        assert_eq!(
+            Encoding::negotiate("application/json;q=0.5, application/cbor;q=0.8"),
+            cbor
+        );
+        assert_eq!(
+            Encoding::negotiate("application/cbor;q=0, application/json;q=0.1"),
+            Encoding::Json
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let description = events_description(Encoding::Json);
+
+        // The default encoding is not serialized.
+        assert!(serialize(&description).get("encoding").is_none());
+
+        let bytes = Encoding::Json.encode(&description).unwrap();
+        assert_eq!(
+            Encoding::Json.decode::<EventsDescription>(&bytes).unwrap(),
+            description
+        );
+        assert!(Encoding::Json.decode::<EventsDescription>(&[0xa0]).is_err());
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_cbor() {
+        let description = events_description(Encoding::Cbor);
+
+        assert_eq!(
+            crate::deserialize::<EventsDescription>(serialize(&description)),
+            description
+        );
+
+        let json = Encoding::Json.encode(&description).unwrap();
+        let cbor = Encoding::Cbor.encode(&description).unwrap();
+        assert!(cbor.len() < json.len());
+
+        assert_eq!(
+            Encoding::Cbor.decode::<EventsDescription>(&cbor).unwrap(),
+            description
+        );
+
+        // Data converted from `JSON` to `CBOR` is decoded as the original one.
+        let value = Encoding::Json.decode::<serde_json::Value>(&json).unwrap();
+        assert_eq!(
+            Encoding::Cbor
+                .decode::<EventsDescription>(&Encoding::Cbor.encode(&value).unwrap())
+                .unwrap(),
+            description
+        );
+
+        let error = Encoding::Cbor
+            .decode::<EventsDescription>(&json)
+            .unwrap_err();
+        assert_eq!(error.encoding(), Encoding::Cbor);
+    }
+
+    #[cfg(not(feature = "cbor"))]
+    #[test]
+    fn test_unsupported_cbor() {
+        assert!(!Encoding::Cbor.is_supported());
+        assert!(Encoding::Cbor.encode(&true).is_err());
+        assert!(Encoding::Cbor.decode::<bool>(&[0xf5]).is_err());
+    }
+}
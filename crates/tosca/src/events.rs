@@ -5,8 +5,17 @@ use core::fmt;
 use core::net::IpAddr;
 use core::time::Duration;
 
+use hashbrown::DefaultHashBuilder;
+
+use indexmap::map::{IndexMap, Iter};
+
 use serde::Serialize;
 
+use crate::encoding::Encoding;
+use crate::macros::map;
+use crate::units::Unit;
+use crate::values::Timestamp;
+
 /// Event broker data.
 #[derive(Debug, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
@@ -25,146 +34,578 @@ impl BrokerData {
     }
 }
 
-// A fake trait to print the type of an event.
+// A fake trait to seal the types of event values.
 mod private {
     #[doc(hidden)]
-    pub trait TypeName {
-        const TYPE: &'static str;
-    }
+    pub trait Sealed {}
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(not(feature = "deserialize"), derive(Copy))]
-#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
-/// An event of a specific type.
-pub struct Event<T: Clone + Copy + private::TypeName> {
-    /// Event name.
-    #[cfg(not(feature = "deserialize"))]
-    pub name: &'static str,
-    /// Event name.
-    #[cfg(feature = "deserialize")]
-    pub name: alloc::borrow::Cow<'static, str>,
+// Position of a value with respect to the emission thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Zone {
+    Below,
+    Inside,
+    Above,
+}
 
-    /// Event description.
-    #[cfg(not(feature = "deserialize"))]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'static str>,
-    /// Event description.
-    #[cfg(feature = "deserialize")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<alloc::borrow::Cow<'static, str>>,
+/// Rules which decide when a new event value is emitted.
+///
+/// Without any rule, every new value is emitted. The first value of an event
+/// is always emitted, while any other value is emitted only when:
+///
+/// - at least the minimum interval has elapsed since the last emission
+/// - when thresholds are defined, the value crosses the upper or lower
+///   threshold with respect to the last emitted value. The deadband acts as
+///   hysteresis, so a value leaves a threshold zone only when it moves back
+///   beyond the threshold by at least the deadband
+/// - when only a deadband is defined, the value differs from the last
+///   emitted value by at least the deadband
+/// - when only the on change rule is defined, the value differs from the last
+///   emitted value
+///
+/// Boolean values are compared as `0` and `1`, while texts and objects only
+/// honour the minimum interval and the on change rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct EmissionRules {
+    /// Emits a value only when it changes.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub on_change: bool,
+    /// Minimum change with respect to the last emitted value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadband: Option<f64>,
+    /// Upper threshold.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upper_threshold: Option<f64>,
+    /// Lower threshold.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lower_threshold: Option<f64>,
+    /// Minimum interval between two emissions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_interval: Option<Duration>,
+}
 
-    /// Event value.
-    pub value: T,
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_false(value: &bool) -> bool {
+    !*value
 }
 
-impl<T: Clone + Copy + fmt::Display + private::TypeName> fmt::Display for Event<T> {
+impl fmt::Display for EmissionRules {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        writeln!(f, "Name: \"{}\"", self.name)?;
-        if let Some(description) = &self.description {
-            writeln!(f, "Description: \"{description}\"")?;
+        let mut separator = "";
+        if self.on_change {
+            write!(f, "on change")?;
+            separator = ", ";
         }
-        writeln!(f, "Type: {}", T::TYPE)?;
-        writeln!(f, "Value: {}", self.value)
+        if let Some(deadband) = self.deadband {
+            write!(f, "{separator}deadband {deadband}")?;
+            separator = ", ";
+        }
+        if let Some(upper_threshold) = self.upper_threshold {
+            write!(f, "{separator}upper threshold {upper_threshold}")?;
+            separator = ", ";
+        }
+        if let Some(lower_threshold) = self.lower_threshold {
+            write!(f, "{separator}lower threshold {lower_threshold}")?;
+            separator = ", ";
+        }
+        if let Some(min_interval) = self.min_interval {
+            write!(
+                f,
+                "{separator}minimum interval {}s {}ms",
+                min_interval.as_secs(),
+                min_interval.subsec_millis()
+            )?;
+        }
+        Ok(())
     }
 }
 
-impl Event<bool> {
-    /// Creates an [`Event<bool>`].
+impl EmissionRules {
+    /// Creates an [`EmissionRules`] which emits every value.
     #[must_use]
-    pub const fn bool(name: &'static str) -> Self {
+    pub const fn new() -> Self {
         Self {
-            #[cfg(not(feature = "deserialize"))]
-            name,
-            #[cfg(feature = "deserialize")]
-            name: alloc::borrow::Cow::Borrowed(name),
-            description: None,
-            value: false,
+            on_change: false,
+            deadband: None,
+            upper_threshold: None,
+            lower_threshold: None,
+            min_interval: None,
+        }
+    }
+
+    /// Emits a value only when it changes.
+    #[must_use]
+    pub const fn on_change(mut self) -> Self {
+        self.on_change = true;
+        self
+    }
+
+    /// Sets the minimum change, with respect to the last emitted value,
+    /// required to emit a value.
+    ///
+    /// When thresholds are defined, the deadband is their hysteresis.
+    #[must_use]
+    pub const fn deadband(mut self, deadband: f64) -> Self {
+        self.deadband = Some(deadband.abs());
+        self
+    }
+
+    /// Sets the upper threshold.
+    #[must_use]
+    pub const fn upper_threshold(mut self, upper_threshold: f64) -> Self {
+        self.upper_threshold = Some(upper_threshold);
+        self
+    }
+
+    /// Sets the lower threshold.
+    #[must_use]
+    pub const fn lower_threshold(mut self, lower_threshold: f64) -> Self {
+        self.lower_threshold = Some(lower_threshold);
+        self
+    }
+
+    /// Sets the minimum interval between two emissions.
+    #[must_use]
+    pub const fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = Some(min_interval);
+        self
+    }
+
+    /// Checks whether [`EmissionRules`] has no rules, so that every value
+    /// is emitted.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        !self.on_change
+            && self.deadband.is_none()
+            && self.upper_threshold.is_none()
+            && self.lower_threshold.is_none()
+            && self.min_interval.is_none()
+    }
+
+    /// Checks whether a value must be emitted.
+    ///
+    /// `last_emission` contains the last emitted value together with the
+    /// time elapsed since its emission, and it is [`None`] when no value has
+    /// been emitted yet.
+    #[must_use]
+    pub fn should_emit(&self, value: f64, last_emission: Option<(f64, Duration)>) -> bool {
+        let Some((last_value, elapsed)) = last_emission else {
+            return true;
+        };
+
+        if self
+            .min_interval
+            .is_some_and(|min_interval| elapsed < min_interval)
+        {
+            return false;
+        }
+
+        if self.upper_threshold.is_some() || self.lower_threshold.is_some() {
+            let last_zone = self.zone(last_value, Zone::Inside);
+            return self.zone(value, last_zone) != last_zone;
+        }
+
+        if let Some(deadband) = self.deadband {
+            return (value - last_value).abs() >= deadband;
         }
+
+        !self.on_change || value.to_bits() != last_value.to_bits()
+    }
+
+    // Checks whether a value which is not a number must be emitted, given
+    // whether it differs from the last emitted value.
+    fn should_emit_change(&self, changed: bool, elapsed: Option<Duration>) -> bool {
+        let Some(elapsed) = elapsed else {
+            return true;
+        };
+
+        if self
+            .min_interval
+            .is_some_and(|min_interval| elapsed < min_interval)
+        {
+            return false;
+        }
+
+        !self.on_change || changed
+    }
+
+    // Returns the zone of a value, given the zone of the previous one.
+    fn zone(&self, value: f64, previous: Zone) -> Zone {
+        let hysteresis = self.deadband.unwrap_or(0.);
+
+        let above = self.upper_threshold.is_some_and(|upper_threshold| {
+            if previous == Zone::Above {
+                value > upper_threshold - hysteresis
+            } else {
+                value >= upper_threshold
+            }
+        });
+        if above {
+            return Zone::Above;
+        }
+
+        let below = self.lower_threshold.is_some_and(|lower_threshold| {
+            if previous == Zone::Below {
+                value < lower_threshold + hysteresis
+            } else {
+                value <= lower_threshold
+            }
+        });
+        if below { Zone::Below } else { Zone::Inside }
     }
 }
 
-impl private::TypeName for bool {
-    const TYPE: &'static str = "bool";
+map! {
+  /// A map associating each field name of an object value with
+  /// its [`EventValue`].
+  #[derive(Debug, Clone, PartialEq, Serialize)]
+  #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+  pub struct EventFields(IndexMap<String, EventValue, DefaultHashBuilder>);
 }
 
-impl Event<u8> {
-    /// Creates an [`Event<u8>`].
+impl EventFields {
+    /// Retrieves the [`EventValue`] of the field with the given name.
     #[must_use]
-    pub const fn u8(name: &'static str) -> Self {
-        Self {
-            #[cfg(not(feature = "deserialize"))]
-            name,
-            #[cfg(feature = "deserialize")]
-            name: alloc::borrow::Cow::Borrowed(name),
-            description: None,
-            value: 0,
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&EventValue> {
+        self.0.get(name)
+    }
+}
+
+impl fmt::Display for EventFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{{")?;
+        for (index, (name, value)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name}: {value}")?;
         }
+        write!(f, "}}")
     }
 }
 
-impl private::TypeName for u8 {
-    const TYPE: &'static str = "u8";
+/// An event value.
+///
+/// A value is serialized together with its type, so that the width of
+/// integers and the fields of objects are always known to a controller.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum EventValue {
+    /// A boolean.
+    Bool(bool),
+    /// An 8-bit unsigned integer.
+    U8(u8),
+    /// A 16-bit unsigned integer.
+    U16(u16),
+    /// A 32-bit unsigned integer.
+    U32(u32),
+    /// A 64-bit unsigned integer.
+    U64(u64),
+    /// An 8-bit signed integer.
+    I8(i8),
+    /// A 16-bit signed integer.
+    I16(i16),
+    /// A 32-bit signed integer.
+    I32(i32),
+    /// A 64-bit signed integer.
+    I64(i64),
+    /// A 32-bit float.
+    F32(f32),
+    /// A 64-bit float.
+    F64(f64),
+    /// A text.
+    Text(String),
+    /// An object made of named fields, such as multiple readings
+    /// of the same sensor.
+    Object(EventFields),
 }
 
-impl Event<i32> {
-    /// Creates an [`Event<i32>`].
+impl fmt::Display for EventValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::U8(value) => value.fmt(f),
+            Self::U16(value) => value.fmt(f),
+            Self::U32(value) => value.fmt(f),
+            Self::U64(value) => value.fmt(f),
+            Self::I8(value) => value.fmt(f),
+            Self::I16(value) => value.fmt(f),
+            Self::I32(value) => value.fmt(f),
+            Self::I64(value) => value.fmt(f),
+            Self::F32(value) => value.fmt(f),
+            Self::F64(value) => value.fmt(f),
+            Self::Text(text) => write!(f, "\"{text}\""),
+            Self::Object(fields) => fields.fmt(f),
+        }
+    }
+}
+
+impl EventValue {
+    /// Returns the name of the [`EventValue`] type.
     #[must_use]
-    pub const fn i32(name: &'static str) -> Self {
-        Self {
-            #[cfg(not(feature = "deserialize"))]
-            name,
-            #[cfg(feature = "deserialize")]
-            name: alloc::borrow::Cow::Borrowed(name),
-            description: None,
-            value: 0,
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::Bool(_) => "bool",
+            Self::U8(_) => "u8",
+            Self::U16(_) => "u16",
+            Self::U32(_) => "u32",
+            Self::U64(_) => "u64",
+            Self::I8(_) => "i8",
+            Self::I16(_) => "i16",
+            Self::I32(_) => "i32",
+            Self::I64(_) => "i64",
+            Self::F32(_) => "f32",
+            Self::F64(_) => "f64",
+            Self::Text(_) => "text",
+            Self::Object(_) => "object",
+        }
+    }
+
+    /// Returns the [`EventValue`] as a number.
+    ///
+    /// Booleans are converted to `0` and `1`, while texts and objects
+    /// are not numbers.
+    #[must_use]
+    // Integers wider than 52 bits might lose precision, which is acceptable
+    // to evaluate emission rules.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_f64(&self) -> Option<f64> {
+        match self {
+            Self::Bool(value) => Some(f64::from(u8::from(*value))),
+            Self::U8(value) => Some(f64::from(*value)),
+            Self::U16(value) => Some(f64::from(*value)),
+            Self::U32(value) => Some(f64::from(*value)),
+            Self::U64(value) => Some(*value as f64),
+            Self::I8(value) => Some(f64::from(*value)),
+            Self::I16(value) => Some(f64::from(*value)),
+            Self::I32(value) => Some(f64::from(*value)),
+            Self::I64(value) => Some(*value as f64),
+            Self::F32(value) => Some(f64::from(*value)),
+            Self::F64(value) => Some(*value),
+            Self::Text(_) | Self::Object(_) => None,
+        }
+    }
+
+    /// Checks whether the [`EventValue`] has the same type of the given one.
+    ///
+    /// Two objects have the same type when they have the same fields,
+    /// and each field has the same type.
+    #[must_use]
+    pub fn has_same_type(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Object(fields), Self::Object(other_fields)) => {
+                fields.len() == other_fields.len()
+                    && fields.iter().all(|(name, value)| {
+                        other_fields
+                            .get(name)
+                            .is_some_and(|other_value| value.has_same_type(other_value))
+                    })
+            }
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
 }
 
-impl private::TypeName for i32 {
-    const TYPE: &'static str = "i32";
+/// A type of event values.
+///
+/// This trait is sealed: it is implemented for [`bool`], all integers up to
+/// 64 bits, floats, [`String`], [`EventFields`] and [`EventValue`].
+pub trait EventType: private::Sealed + Sized {
+    /// Converts the value into an [`EventValue`].
+    fn into_value(self) -> EventValue;
+
+    /// Retrieves a value from an [`EventValue`].
+    ///
+    /// Returns [`None`] when the [`EventValue`] has a different type.
+    fn from_value(value: &EventValue) -> Option<Self>;
 }
 
-impl Event<f32> {
-    /// Creates an [`Event<f32>`].
-    #[must_use]
-    pub const fn f32(name: &'static str) -> Self {
-        Self {
-            #[cfg(not(feature = "deserialize"))]
-            name,
-            #[cfg(feature = "deserialize")]
-            name: alloc::borrow::Cow::Borrowed(name),
-            description: None,
-            value: 0.,
+// Implements the conversions among a primitive type and its `EventValue`
+// variant, together with the constructor of its events.
+macro_rules! primitive_event_types {
+    ($($ty:ident => $variant:ident($default:expr)),+ $(,)?) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl EventType for $ty {
+                fn into_value(self) -> EventValue {
+                    EventValue::$variant(self)
+                }
+
+                fn from_value(value: &EventValue) -> Option<Self> {
+                    match value {
+                        EventValue::$variant(value) => Some(*value),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl From<$ty> for EventValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )+
+
+        impl Event {
+            $(
+                #[doc = concat!("Creates an [`Event`] with a `", stringify!($ty), "` value.")]
+                #[must_use]
+                pub const fn $ty(name: &'static str) -> Self {
+                    Self::with_value(name, EventValue::$variant($default))
+                }
+            )+
+        }
+    };
+}
+
+primitive_event_types!(
+    bool => Bool(false),
+    u8 => U8(0),
+    u16 => U16(0),
+    u32 => U32(0),
+    u64 => U64(0),
+    i8 => I8(0),
+    i16 => I16(0),
+    i32 => I32(0),
+    i64 => I64(0),
+    f32 => F32(0.),
+    f64 => F64(0.),
+);
+
+impl private::Sealed for String {}
+
+impl EventType for String {
+    fn into_value(self) -> EventValue {
+        EventValue::Text(self)
+    }
+
+    fn from_value(value: &EventValue) -> Option<Self> {
+        match value {
+            EventValue::Text(text) => Some(text.clone()),
+            _ => None,
         }
     }
 }
 
-impl private::TypeName for f32 {
-    const TYPE: &'static str = "f32";
+impl From<String> for EventValue {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
 }
 
-impl Event<f64> {
-    /// Creates an [`Event<f64>`].
-    #[must_use]
-    pub const fn f64(name: &'static str) -> Self {
-        Self {
-            #[cfg(not(feature = "deserialize"))]
-            name,
-            #[cfg(feature = "deserialize")]
-            name: alloc::borrow::Cow::Borrowed(name),
-            description: None,
-            value: 0.,
+impl private::Sealed for EventFields {}
+
+impl EventType for EventFields {
+    fn into_value(self) -> EventValue {
+        EventValue::Object(self)
+    }
+
+    fn from_value(value: &EventValue) -> Option<Self> {
+        match value {
+            EventValue::Object(fields) => Some(fields.clone()),
+            _ => None,
         }
     }
 }
 
-impl private::TypeName for f64 {
-    const TYPE: &'static str = "f64";
+impl From<EventFields> for EventValue {
+    fn from(fields: EventFields) -> Self {
+        Self::Object(fields)
+    }
 }
 
-impl<T: Clone + Copy + private::TypeName> Event<T> {
+impl private::Sealed for EventValue {}
+
+impl EventType for EventValue {
+    fn into_value(self) -> EventValue {
+        self
+    }
+
+    fn from_value(value: &EventValue) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+/// An event.
+///
+/// The type of an event is defined by its initial value, and it never
+/// changes when the value is updated.
+pub struct Event {
+    /// Event name.
+    #[cfg(not(feature = "deserialize"))]
+    pub name: &'static str,
+    /// Event name.
+    #[cfg(feature = "deserialize")]
+    pub name: alloc::borrow::Cow<'static, str>,
+
+    /// Event description.
+    #[cfg(not(feature = "deserialize"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'static str>,
+    /// Event description.
+    #[cfg(feature = "deserialize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<alloc::borrow::Cow<'static, str>>,
+
+    /// Event measurement unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<Unit>,
+
+    /// Event emission rules.
+    #[serde(default, skip_serializing_if = "EmissionRules::is_empty")]
+    pub rules: EmissionRules,
+
+    /// Event value, serialized together with its type.
+    #[serde(flatten)]
+    pub value: EventValue,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        writeln!(f, "Name: \"{}\"", self.name)?;
+        if let Some(description) = &self.description {
+            writeln!(f, "Description: \"{description}\"")?;
+        }
+        writeln!(f, "Type: {}", self.value.type_name())?;
+        if let Some(unit) = &self.unit {
+            writeln!(f, "Unit: {unit}")?;
+        }
+        if !self.rules.is_empty() {
+            writeln!(f, "Emission: {}", self.rules)?;
+        }
+        writeln!(f, "Value: {}", self.value)
+    }
+}
+
+impl Event {
+    /// Creates an [`Event`] with an initial value of any [`EventType`].
+    #[must_use]
+    #[inline]
+    pub fn new<T: EventType>(name: &'static str, value: T) -> Self {
+        Self::with_value(name, value.into_value())
+    }
+
+    /// Creates an [`Event`] with a text value.
+    #[must_use]
+    pub const fn text(name: &'static str) -> Self {
+        Self::with_value(name, EventValue::Text(String::new()))
+    }
+
+    /// Creates an [`Event`] with an object value made of the given fields.
+    ///
+    /// The initial values of the fields define their types.
+    #[must_use]
+    #[inline]
+    pub fn object(name: &'static str, fields: EventFields) -> Self {
+        Self::with_value(name, EventValue::Object(fields))
+    }
+
     /// Sets the event description.
     #[must_use]
     #[cfg(not(feature = "deserialize"))]
@@ -182,6 +623,56 @@ impl<T: Clone + Copy + private::TypeName> Event<T> {
         self
     }
 
+    /// Sets the event measurement unit.
+    #[must_use]
+    #[cfg(not(feature = "deserialize"))]
+    pub const fn unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Sets the event measurement unit.
+    #[must_use]
+    #[inline]
+    #[cfg(feature = "deserialize")]
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Sets the event emission rules.
+    #[must_use]
+    #[cfg(not(feature = "deserialize"))]
+    pub const fn rules(mut self, rules: EmissionRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Sets the event emission rules.
+    #[must_use]
+    #[inline]
+    #[cfg(feature = "deserialize")]
+    pub fn rules(mut self, rules: EmissionRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Checks whether a new value must be emitted, according to the
+    /// event emission rules.
+    ///
+    /// The current event value is considered as the last emitted one, while
+    /// `elapsed` is the time elapsed since its emission. When [`None`], no
+    /// value has been emitted yet.
+    #[must_use]
+    pub fn should_emit(&self, value: &EventValue, elapsed: Option<Duration>) -> bool {
+        match (value.to_f64(), self.value.to_f64()) {
+            (Some(value), Some(last_value)) => self
+                .rules
+                .should_emit(value, elapsed.map(|elapsed| (last_value, elapsed))),
+            _ => self.rules.should_emit_change(*value != self.value, elapsed),
+        }
+    }
+
     /// Removes the event description.
     ///
     /// This method might be useful to reduce the payload sent over the network.
@@ -200,27 +691,45 @@ impl<T: Clone + Copy + private::TypeName> Event<T> {
         self.description = None;
     }
 
-    // Updates the event value.
-    pub(crate) const fn update_value(&mut self, value: T) {
-        self.value = value;
+    // Creates an event with the given initial value.
+    const fn with_value(name: &'static str, value: EventValue) -> Self {
+        Self {
+            #[cfg(not(feature = "deserialize"))]
+            name,
+            #[cfg(feature = "deserialize")]
+            name: alloc::borrow::Cow::Borrowed(name),
+            description: None,
+            unit: None,
+            rules: EmissionRules::new(),
+            value,
+        }
+    }
+
+    // Updates the event value, only when it has the same type of the
+    // current one.
+    fn update_value(&mut self, value: EventValue) -> bool {
+        let same_type = self.value.has_same_type(&value);
+        if same_type {
+            self.value = value;
+        }
+        same_type
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
-#[cfg_attr(not(feature = "deserialize"), derive(Copy))]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
 /// A periodic [`Event`].
 ///
 /// An event is considered periodic when it is triggered or checked at regular,
 /// fixed intervals of time.
-pub struct PeriodicEvent<T: Clone + Copy + private::TypeName> {
+pub struct PeriodicEvent {
     /// The [`Event`].
-    pub event: Event<T>,
+    pub event: Event,
     /// Time interval for checking if the event has occurred.
     pub interval: Duration,
 }
 
-impl<T: Clone + Copy + fmt::Display + private::TypeName> fmt::Display for PeriodicEvent<T> {
+impl fmt::Display for PeriodicEvent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         writeln!(
             f,
@@ -232,42 +741,10 @@ impl<T: Clone + Copy + fmt::Display + private::TypeName> fmt::Display for Period
     }
 }
 
-impl PeriodicEvent<bool> {
-    /// Creates a [`PeriodicEvent<bool>`].
-    #[must_use]
-    pub const fn bool(event: Event<bool>, interval: Duration) -> Self {
-        Self { event, interval }
-    }
-}
-
-impl PeriodicEvent<u8> {
-    /// Creates a [`PeriodicEvent<u8>`].
-    #[must_use]
-    pub const fn u8(event: Event<u8>, interval: Duration) -> Self {
-        Self { event, interval }
-    }
-}
-
-impl PeriodicEvent<i32> {
-    /// Creates a [`PeriodicEvent<i32>`].
-    #[must_use]
-    pub const fn i32(event: Event<i32>, interval: Duration) -> Self {
-        Self { event, interval }
-    }
-}
-
-impl PeriodicEvent<f32> {
-    /// Creates a [`PeriodicEvent<f32>`].
+impl PeriodicEvent {
+    /// Creates a [`PeriodicEvent`].
     #[must_use]
-    pub const fn f32(event: Event<f32>, interval: Duration) -> Self {
-        Self { event, interval }
-    }
-}
-
-impl PeriodicEvent<f64> {
-    /// Creates a [`PeriodicEvent<f64>`].
-    #[must_use]
-    pub const fn f64(event: Event<f64>, interval: Duration) -> Self {
+    pub const fn new(event: Event, interval: Duration) -> Self {
         Self { event, interval }
     }
 }
@@ -304,92 +781,51 @@ impl Topic {
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[allow(clippy::struct_field_names)]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
-/// All events types that can be generated by a device.
+/// All events that can be generated by a device.
 ///
-/// Events of the same type are stored and displayed sequentially.
+/// Events of any [`EventType`] are stored in the same sequence, and each
+/// event is identified by its index. The type of an event is checked
+/// whenever its value is updated. Periodic events are stored in their own
+/// sequence, and they are displayed after the other events.
+///
+/// Each time a device publishes its [`Events`], it starts a new frame
+/// through [`Events::next_frame`]. A frame carries a monotonic sequence
+/// number, which allows a controller to order frames and detect the
+/// dropped ones, and an optional device timestamp of the readings.
 pub struct Events {
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    bool_events: Vec<Event<bool>>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    u8_events: Vec<Event<u8>>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    i32_events: Vec<Event<i32>>,
+    events: Vec<Event>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    f32_events: Vec<Event<f32>>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    f64_events: Vec<Event<f64>>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    periodic_bool_events: Vec<PeriodicEvent<bool>>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    periodic_u8_events: Vec<PeriodicEvent<u8>>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    periodic_i32_events: Vec<PeriodicEvent<i32>>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    periodic_f32_events: Vec<PeriodicEvent<f32>>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    periodic_f64_events: Vec<PeriodicEvent<f64>>,
+    periodic_events: Vec<PeriodicEvent>,
+    // Sequence number of the published frame, zero when never published.
+    #[serde(skip_serializing_if = "is_zero", default)]
+    sequence: u64,
+    // Device time at which the frame has been published.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    timestamp: Option<Timestamp>,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_zero(value: &u64) -> bool {
+    *value == 0
 }
 
 impl fmt::Display for Events {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        if !self.bool_events.is_empty() {
-            for bool_event in &self.bool_events {
-                bool_event.fmt(f)?;
-            }
+        if self.sequence != 0 {
+            writeln!(f, "Sequence: {}", self.sequence)?;
         }
 
-        if !self.u8_events.is_empty() {
-            for u8_event in &self.u8_events {
-                u8_event.fmt(f)?;
-            }
+        if let Some(timestamp) = self.timestamp {
+            writeln!(f, "Timestamp: {timestamp}")?;
         }
 
-        if !self.i32_events.is_empty() {
-            for i32_event in &self.i32_events {
-                i32_event.fmt(f)?;
-            }
-        }
-
-        if !self.f32_events.is_empty() {
-            for f32_event in &self.f32_events {
-                f32_event.fmt(f)?;
-            }
-        }
-
-        if !self.f64_events.is_empty() {
-            for f64_event in &self.f64_events {
-                f64_event.fmt(f)?;
-            }
-        }
-
-        if !self.periodic_bool_events.is_empty() {
-            for periodic_bool_event in &self.periodic_bool_events {
-                periodic_bool_event.fmt(f)?;
-            }
-        }
-
-        if !self.periodic_u8_events.is_empty() {
-            for periodic_u8_event in &self.periodic_u8_events {
-                periodic_u8_event.fmt(f)?;
-            }
-        }
-
-        if !self.periodic_i32_events.is_empty() {
-            for periodic_i32_event in &self.periodic_i32_events {
-                periodic_i32_event.fmt(f)?;
-            }
-        }
-
-        if !self.periodic_f32_events.is_empty() {
-            for periodic_f32_event in &self.periodic_f32_events {
-                periodic_f32_event.fmt(f)?;
-            }
+        for event in &self.events {
+            event.fmt(f)?;
         }
 
-        if !self.periodic_f64_events.is_empty() {
-            for periodic_f64_event in &self.periodic_f64_events {
-                periodic_f64_event.fmt(f)?;
-            }
+        for periodic_event in &self.periodic_events {
+            periodic_event.fmt(f)?;
         }
 
         Ok(())
@@ -401,16 +837,10 @@ impl Events {
     #[must_use]
     pub const fn empty() -> Self {
         Self {
-            bool_events: Vec::new(),
-            u8_events: Vec::new(),
-            i32_events: Vec::new(),
-            f32_events: Vec::new(),
-            f64_events: Vec::new(),
-            periodic_bool_events: Vec::new(),
-            periodic_u8_events: Vec::new(),
-            periodic_i32_events: Vec::new(),
-            periodic_f32_events: Vec::new(),
-            periodic_f64_events: Vec::new(),
+            events: Vec::new(),
+            periodic_events: Vec::new(),
+            sequence: 0,
+            timestamp: None,
         }
     }
 
@@ -420,302 +850,162 @@ impl Events {
     #[must_use]
     pub fn with_capacity(size: usize) -> Self {
         Self {
-            bool_events: Vec::with_capacity(size),
-            u8_events: Vec::with_capacity(size),
-            i32_events: Vec::with_capacity(size),
-            f32_events: Vec::with_capacity(size),
-            f64_events: Vec::with_capacity(size),
-            periodic_bool_events: Vec::with_capacity(size),
-            periodic_u8_events: Vec::with_capacity(size),
-            periodic_i32_events: Vec::with_capacity(size),
-            periodic_f32_events: Vec::with_capacity(size),
-            periodic_f64_events: Vec::with_capacity(size),
+            events: Vec::with_capacity(size),
+            periodic_events: Vec::with_capacity(size),
+            sequence: 0,
+            timestamp: None,
         }
     }
 
-    /// Adds a sequence of [`Event<bool>`].
-    #[inline]
-    #[must_use]
-    pub fn bool_events(mut self, bool_events: Vec<Event<bool>>) -> Self {
-        self.bool_events = bool_events;
-        self
-    }
-
-    /// Adds a sequence of [`Event<u8>`].
-    #[inline]
-    #[must_use]
-    pub fn u8_events(mut self, u8_events: Vec<Event<u8>>) -> Self {
-        self.u8_events = u8_events;
-        self
-    }
-
-    /// Adds a sequence of [`Event<i32>`].
-    #[inline]
-    #[must_use]
-    pub fn i32_events(mut self, i32_events: Vec<Event<i32>>) -> Self {
-        self.i32_events = i32_events;
-        self
-    }
-
-    /// Adds a sequence of [`Event<f32>`].
-    #[inline]
-    #[must_use]
-    pub fn f32_events(mut self, f32_events: Vec<Event<f32>>) -> Self {
-        self.f32_events = f32_events;
-        self
-    }
-
-    /// Adds a sequence of [`Event<f64>`].
+    /// Adds a sequence of [`Event`].
     #[inline]
     #[must_use]
-    pub fn f64_events(mut self, f64_events: Vec<Event<f64>>) -> Self {
-        self.f64_events = f64_events;
+    pub fn events(mut self, events: Vec<Event>) -> Self {
+        self.events = events;
         self
     }
 
-    /// Adds a sequence of [`PeriodicEvent<bool>`].
+    /// Adds a sequence of [`PeriodicEvent`].
     #[inline]
     #[must_use]
-    pub fn periodic_bool_events(mut self, periodic_bool_events: Vec<PeriodicEvent<bool>>) -> Self {
-        self.periodic_bool_events = periodic_bool_events;
+    pub fn periodic_events(mut self, periodic_events: Vec<PeriodicEvent>) -> Self {
+        self.periodic_events = periodic_events;
         self
     }
 
-    /// Adds a sequence of [`PeriodicEvent<u8>`].
-    #[inline]
-    #[must_use]
-    pub fn periodic_u8_events(mut self, periodic_u8_events: Vec<PeriodicEvent<u8>>) -> Self {
-        self.periodic_u8_events = periodic_u8_events;
-        self
-    }
-
-    /// Adds a sequence of [`PeriodicEvent<i32>`].
-    #[inline]
-    #[must_use]
-    pub fn periodic_i32_events(mut self, periodic_i32_events: Vec<PeriodicEvent<i32>>) -> Self {
-        self.periodic_i32_events = periodic_i32_events;
-        self
-    }
-
-    /// Adds a sequence of [`PeriodicEvent<f32>`].
-    #[inline]
-    #[must_use]
-    pub fn periodic_f32_events(mut self, periodic_f32_events: Vec<PeriodicEvent<f32>>) -> Self {
-        self.periodic_f32_events = periodic_f32_events;
-        self
-    }
-
-    /// Adds a sequence of [`PeriodicEvent<f64>`].
-    #[inline]
-    #[must_use]
-    pub fn periodic_f64_events(mut self, periodic_f64_events: Vec<PeriodicEvent<f64>>) -> Self {
-        self.periodic_f64_events = periodic_f64_events;
-        self
-    }
-
-    /// Adds a single [`Event<bool>`].
-    #[inline]
-    pub fn add_bool_event(&mut self, bool_event: Event<bool>) {
-        self.bool_events.push(bool_event);
-    }
-
-    /// Adds a single [`Event<u8>`].
-    #[inline]
-    pub fn add_u8_event(&mut self, u8_event: Event<u8>) {
-        self.u8_events.push(u8_event);
-    }
-
-    /// Adds a single [`Event<i32>`].
-    #[inline]
-    pub fn add_i32_event(&mut self, i32_event: Event<i32>) {
-        self.i32_events.push(i32_event);
-    }
-
-    /// Adds a single [`Event<f32>`].
-    #[inline]
-    pub fn add_f32_event(&mut self, f32_event: Event<f32>) {
-        self.f32_events.push(f32_event);
-    }
-
-    /// Adds a single [`Event<f64>`].
-    #[inline]
-    pub fn add_f64_event(&mut self, f64_event: Event<f64>) {
-        self.f64_events.push(f64_event);
-    }
-
-    /// Adds a single [`PeriodicEvent<bool>`].
-    #[inline]
-    pub fn add_periodic_bool_event(&mut self, periodic_bool_event: PeriodicEvent<bool>) {
-        self.periodic_bool_events.push(periodic_bool_event);
-    }
-
-    /// Adds a single [`PeriodicEvent<u8>`].
-    #[inline]
-    pub fn add_periodic_u8_event(&mut self, periodic_u8_event: PeriodicEvent<u8>) {
-        self.periodic_u8_events.push(periodic_u8_event);
-    }
-
-    /// Adds a single [`PeriodicEvent<i32>`].
-    #[inline]
-    pub fn add_periodic_i32_event(&mut self, periodic_i32_event: PeriodicEvent<i32>) {
-        self.periodic_i32_events.push(periodic_i32_event);
-    }
-
-    /// Adds a single [`PeriodicEvent<f32>`].
-    #[inline]
-    pub fn add_periodic_f32_event(&mut self, periodic_f32_event: PeriodicEvent<f32>) {
-        self.periodic_f32_events.push(periodic_f32_event);
-    }
-
-    /// Adds a single [`PeriodicEvent<f64>`].
-    #[inline]
-    pub fn add_periodic_f64_event(&mut self, periodic_f64_event: PeriodicEvent<f64>) {
-        self.periodic_f64_events.push(periodic_f64_event);
-    }
-
-    /// Updates the [`Event<bool>`] value located at the given index.
-    #[inline]
-    pub fn update_bool_value(&mut self, index: usize, value: bool) {
-        self.bool_events[index].update_value(value);
-    }
-
-    /// Updates the [`Event<u8>`] value located at the given index.
-    #[inline]
-    pub fn update_u8_value(&mut self, index: usize, value: u8) {
-        self.u8_events[index].update_value(value);
-    }
-
-    /// Updates the [`Event<i32>`] value located at the given index.
-    #[inline]
-    pub fn update_i32_value(&mut self, index: usize, value: i32) {
-        self.i32_events[index].update_value(value);
-    }
-
-    /// Updates the [`Event<f32>`] value located at the given index.
-    #[inline]
-    pub fn update_f32_value(&mut self, index: usize, value: f32) {
-        self.f32_events[index].update_value(value);
-    }
-
-    /// Updates the [`Event<f64>`] value located at the given index.
-    #[inline]
-    pub fn update_f64_value(&mut self, index: usize, value: f64) {
-        self.f64_events[index].update_value(value);
-    }
-
-    /// Updates the [`PeriodicEvent<bool>`] value located at the given index.
-    #[inline]
-    pub fn update_periodic_bool_value(&mut self, index: usize, value: bool) {
-        self.periodic_bool_events[index].event.update_value(value);
-    }
-
-    /// Updates the [`PeriodicEvent<u8>`] value located at the given index.
-    #[inline]
-    pub fn update_periodic_u8_value(&mut self, index: usize, value: u8) {
-        self.periodic_u8_events[index].event.update_value(value);
-    }
-
-    /// Updates the [`PeriodicEvent<i32>`] value located at the given index.
+    /// Adds a single [`Event`].
+    ///
+    /// Returns the index of the event.
     #[inline]
-    pub fn update_periodic_i32_value(&mut self, index: usize, value: i32) {
-        self.periodic_i32_events[index].event.update_value(value);
+    pub fn add_event(&mut self, event: Event) -> usize {
+        self.events.push(event);
+        self.events.len() - 1
     }
 
-    /// Updates the [`PeriodicEvent<f32>`] value located at the given index.
+    /// Adds a single [`PeriodicEvent`].
+    ///
+    /// Returns the index of the periodic event.
     #[inline]
-    pub fn update_periodic_f32_value(&mut self, index: usize, value: f32) {
-        self.periodic_f32_events[index].event.update_value(value);
+    pub fn add_periodic_event(&mut self, periodic_event: PeriodicEvent) -> usize {
+        self.periodic_events.push(periodic_event);
+        self.periodic_events.len() - 1
     }
 
-    /// Updates the [`PeriodicEvent<f64>`] value located at the given index.
-    #[inline]
-    pub fn update_periodic_f64_value(&mut self, index: usize, value: f64) {
-        self.periodic_f64_events[index].event.update_value(value);
+    /// Sets the [`EmissionRules`] of all events with the given name.
+    ///
+    /// Returns `false` when no event has the given name.
+    pub fn set_rules(&mut self, name: &str, rules: EmissionRules) -> bool {
+        let mut found = false;
+        let periodic_events = self
+            .periodic_events
+            .iter_mut()
+            .map(|periodic_event| &mut periodic_event.event);
+        for event in self
+            .events
+            .iter_mut()
+            .chain(periodic_events)
+            .filter(|event| event.name == name)
+        {
+            event.rules = rules;
+            found = true;
+        }
+        found
     }
 
-    /// Returns an immutable slice of the [`Event<bool>`] sequence.
+    /// Updates the value of the [`Event`] located at the given index.
+    ///
+    /// Returns `false` when there is no event at the given index, or when
+    /// the value type differs from the event one.
     #[inline]
-    #[must_use]
-    pub fn bool_events_as_slice(&self) -> &[Event<bool>] {
-        self.bool_events.as_slice()
+    pub fn update_value<T: EventType>(&mut self, index: usize, value: T) -> bool {
+        self.events
+            .get_mut(index)
+            .is_some_and(|event| event.update_value(value.into_value()))
     }
 
-    /// Returns an immutable slice of the [`Event<u8>`] sequence.
+    /// Updates the value of the [`PeriodicEvent`] located at the given index.
+    ///
+    /// Returns `false` when there is no periodic event at the given index,
+    /// or when the value type differs from the event one.
     #[inline]
-    #[must_use]
-    pub fn u8_events_as_slice(&self) -> &[Event<u8>] {
-        self.u8_events.as_slice()
+    pub fn update_periodic_value<T: EventType>(&mut self, index: usize, value: T) -> bool {
+        self.periodic_events
+            .get_mut(index)
+            .is_some_and(|periodic_event| periodic_event.event.update_value(value.into_value()))
     }
 
-    /// Returns an immutable slice of the [`Event<i32>`] sequence.
-    #[inline]
+    /// Retrieves the [`Event`] with the given name, either periodic or not.
     #[must_use]
-    pub fn i32_events_as_slice(&self) -> &[Event<i32>] {
-        self.i32_events.as_slice()
-    }
-
-    /// Returns an immutable slice of the [`Event<f32>`] sequence.
-    #[inline]
-    #[must_use]
-    pub fn f32_events_as_slice(&self) -> &[Event<f32>] {
-        self.f32_events.as_slice()
-    }
-
-    /// Returns an immutable slice of the [`Event<f64>`] sequence.
-    #[inline]
+    pub fn event(&self, name: &str) -> Option<&Event> {
+        self.events
+            .iter()
+            .chain(
+                self.periodic_events
+                    .iter()
+                    .map(|periodic_event| &periodic_event.event),
+            )
+            .find(|event| event.name == name)
+    }
+
+    /// Retrieves the value of the event with the given name.
+    ///
+    /// Returns [`None`] when no event has the given name, or when the event
+    /// value has a different type.
     #[must_use]
-    pub fn f64_events_as_slice(&self) -> &[Event<f64>] {
-        self.f64_events.as_slice()
+    pub fn value<T: EventType>(&self, name: &str) -> Option<T> {
+        self.event(name)
+            .and_then(|event| T::from_value(&event.value))
     }
 
-    /// Returns an immutable slice of the [`PeriodicEvent<bool>`] sequence.
+    /// Returns an immutable slice of the [`Event`] sequence.
     #[inline]
     #[must_use]
-    pub fn periodic_bool_events_as_slice(&self) -> &[PeriodicEvent<bool>] {
-        self.periodic_bool_events.as_slice()
+    pub fn events_as_slice(&self) -> &[Event] {
+        self.events.as_slice()
     }
 
-    /// Returns an immutable slice of the [`PeriodicEvent<u8>`] sequence.
+    /// Returns an immutable slice of the [`PeriodicEvent`] sequence.
     #[inline]
     #[must_use]
-    pub fn periodic_u8_events_as_slice(&self) -> &[PeriodicEvent<u8>] {
-        self.periodic_u8_events.as_slice()
+    pub fn periodic_events_as_slice(&self) -> &[PeriodicEvent] {
+        self.periodic_events.as_slice()
     }
 
-    /// Returns an immutable slice of the [`PeriodicEvent<i32>`] sequence.
-    #[inline]
-    #[must_use]
-    pub fn periodic_i32_events_as_slice(&self) -> &[PeriodicEvent<i32>] {
-        self.periodic_i32_events.as_slice()
+    /// Starts a new published frame.
+    ///
+    /// Increments the frame sequence number, which is never zero after the
+    /// first frame, and sets the device timestamp of the frame.
+    ///
+    /// Returns the sequence number of the new frame.
+    pub const fn next_frame(&mut self, timestamp: Option<Timestamp>) -> u64 {
+        self.sequence = match self.sequence.checked_add(1) {
+            Some(sequence) => sequence,
+            None => 1,
+        };
+        self.timestamp = timestamp;
+        self.sequence
     }
 
-    /// Returns an immutable slice of the [`PeriodicEvent<f32>`] sequence.
-    #[inline]
+    /// Returns the sequence number of the published frame.
+    ///
+    /// Zero means that the [`Events`] have never been published, or that
+    /// their publisher does not number frames.
     #[must_use]
-    pub fn periodic_f32_events_as_slice(&self) -> &[PeriodicEvent<f32>] {
-        self.periodic_f32_events.as_slice()
+    pub const fn sequence(&self) -> u64 {
+        self.sequence
     }
 
-    /// Returns an immutable slice of the [`PeriodicEvent<f64>`] sequence.
-    #[inline]
+    /// Returns the device time at which the frame has been published,
+    /// if known.
     #[must_use]
-    pub fn periodic_f64_events_as_slice(&self) -> &[PeriodicEvent<f64>] {
-        self.periodic_f64_events.as_slice()
+    pub const fn timestamp(&self) -> Option<Timestamp> {
+        self.timestamp
     }
 
     /// Checks if [`Events`] is **entirely** empty.
     #[must_use]
     pub const fn is_empty(&self) -> bool {
-        self.bool_events.is_empty()
-            && self.u8_events.is_empty()
-            && self.i32_events.is_empty()
-            && self.f32_events.is_empty()
-            && self.f64_events.is_empty()
-            && self.periodic_bool_events.is_empty()
-            && self.periodic_u8_events.is_empty()
-            && self.periodic_i32_events.is_empty()
-            && self.periodic_f32_events.is_empty()
-            && self.periodic_f64_events.is_empty()
+        self.events.is_empty() && self.periodic_events.is_empty()
     }
 }
 
@@ -730,6 +1020,9 @@ pub struct EventsDescription {
     pub topic: Topic,
     /// All device events.
     pub events: Events,
+    /// Encoding of the published events.
+    #[serde(default, skip_serializing_if = "Encoding::is_json")]
+    pub encoding: Encoding,
 }
 
 impl EventsDescription {
@@ -740,8 +1033,16 @@ impl EventsDescription {
             broker_data,
             topic,
             events,
+            encoding: Encoding::Json,
         }
     }
+
+    /// Sets the encoding of the published events.
+    #[must_use]
+    pub const fn encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -750,101 +1051,283 @@ mod tests {
     use core::net::Ipv4Addr;
     use core::time::Duration;
 
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    use crate::units::Unit;
+    use crate::values::Timestamp;
     use crate::{deserialize, serialize};
 
-    use super::{BrokerData, Event, Events, EventsDescription, PeriodicEvent, Topic};
+    use super::{
+        BrokerData, EmissionRules, Event, EventFields, EventValue, Events, EventsDescription,
+        PeriodicEvent, Topic,
+    };
 
     const DEFAULT_DURATION: Duration = Duration::from_secs(1);
 
+    fn all_event_kinds() -> Vec<Event> {
+        [
+            Event::bool("bool_event"),
+            Event::u8("u8_event"),
+            Event::u16("u16_event"),
+            Event::u32("u32_event"),
+            Event::u64("u64_event"),
+            Event::i8("i8_event"),
+            Event::i16("i16_event"),
+            Event::i32("i32_event"),
+            Event::i64("i64_event"),
+            Event::f32("f32_event"),
+            Event::f64("f64_event"),
+            Event::text("text_event"),
+            Event::object("object_event", reading(21.5, 40)),
+        ]
+        .into_iter()
+        .map(|event| event.description("An event"))
+        .collect()
+    }
+
+    fn reading(temperature: f32, humidity: u8) -> EventFields {
+        EventFields::init("temperature".into(), temperature.into())
+            .insert("humidity".into(), humidity.into())
+    }
+
     #[test]
-    #[allow(clippy::similar_names)]
     fn test_all_event_kinds() {
-        let bool_event = Event::bool("bool_event").description("A bool event");
+        for event in all_event_kinds() {
+            assert_eq!(deserialize::<Event>(serialize(&event)), event);
+
+            let periodic_event = PeriodicEvent::new(event, DEFAULT_DURATION);
+            assert_eq!(
+                deserialize::<PeriodicEvent>(serialize(&periodic_event)),
+                periodic_event
+            );
+        }
+    }
+
+    #[test]
+    fn test_event_values() {
+        // Values are serialized together with their types.
         assert_eq!(
-            deserialize::<Event<bool>>(serialize(&bool_event)),
-            bool_event
+            serialize(Event::u16("counter")),
+            serde_json::json!({ "name": "counter", "type": "u16", "value": 0 })
         );
-
-        let periodic_bool_event = PeriodicEvent::bool(bool_event, DEFAULT_DURATION);
         assert_eq!(
-            deserialize::<PeriodicEvent<bool>>(serialize(&periodic_bool_event)),
-            periodic_bool_event
+            serialize(Event::object("reading", reading(21.5, 40))),
+            serde_json::json!({
+                "name": "reading",
+                "type": "object",
+                "value": {
+                    "temperature": { "type": "f32", "value": 21.5 },
+                    "humidity": { "type": "u8", "value": 40 },
+                },
+            })
         );
 
-        let u8_event = Event::u8("u8_event").description("An u8 event");
-        assert_eq!(deserialize::<Event<u8>>(serialize(&u8_event)), u8_event);
+        let value = EventValue::from(String::from("idle"));
+        assert_eq!(value.type_name(), "text");
+        assert_eq!(value.to_f64(), None);
+        assert_eq!(value.to_string(), "\"idle\"");
 
-        let periodic_u8_event = PeriodicEvent::u8(u8_event, DEFAULT_DURATION);
-        assert_eq!(
-            deserialize::<PeriodicEvent<u8>>(serialize(&periodic_u8_event)),
-            periodic_u8_event
+        assert_eq!(EventValue::from(true).to_f64(), Some(1.));
+        assert_eq!(EventValue::from(-3_i64).to_f64(), Some(-3.));
+
+        let value = EventValue::from(reading(21.5, 40));
+        assert_eq!(value.to_string(), "{temperature: 21.5, humidity: 40}");
+        assert!(value.has_same_type(&reading(19., 55).into()));
+        assert!(
+            !value.has_same_type(&EventFields::init("temperature".into(), 21.5_f32.into()).into())
         );
+        assert!(!EventValue::from(1_u8).has_same_type(&EventValue::from(1_u16)));
+    }
+
+    #[test]
+    fn test_events_with_single_event_kind() {
+        let bool_event = Event::bool("bool_event").description("A bool event");
+
+        let mut events = Events::empty();
+        events.add_event(bool_event);
+
+        assert_eq!(deserialize::<Events>(serialize(&events)), events);
+    }
+
+    #[test]
+    fn test_events_with_all_event_kinds() {
+        let mut events = Events::empty();
+        for event in all_event_kinds() {
+            events.add_event(event.clone());
+            events.add_periodic_event(PeriodicEvent::new(event, DEFAULT_DURATION));
+        }
 
-        let i32_event = Event::i32("i32_event").description("An i32 event");
-        assert_eq!(deserialize::<Event<i32>>(serialize(&i32_event)), i32_event);
+        assert_eq!(deserialize::<Events>(serialize(&events)), events);
+    }
 
-        let periodic_i32_event = PeriodicEvent::i32(i32_event, DEFAULT_DURATION);
+    #[test]
+    fn test_events_registry() {
+        let mut events = Events::with_capacity(2);
+        assert_eq!(events.add_event(Event::u16("counter")), 0);
+        assert_eq!(events.add_event(Event::text("status")), 1);
         assert_eq!(
-            deserialize::<PeriodicEvent<i32>>(serialize(&periodic_i32_event)),
-            periodic_i32_event
+            events.add_periodic_event(PeriodicEvent::new(
+                Event::object("reading", reading(0., 0)),
+                DEFAULT_DURATION
+            )),
+            0
         );
 
-        let f32_event = Event::f32("f32_event").description("An f32 event");
-        assert_eq!(deserialize::<Event<f32>>(serialize(&f32_event)), f32_event);
-
-        let periodic_f32_event = PeriodicEvent::f32(f32_event, DEFAULT_DURATION);
+        assert!(events.update_value(0, 3_u16));
+        assert!(events.update_value(1, String::from("running")));
+        assert!(events.update_periodic_value(0, reading(21.5, 40)));
+        assert_eq!(events.value::<u16>("counter"), Some(3));
+        assert_eq!(events.value::<String>("status").as_deref(), Some("running"));
         assert_eq!(
-            deserialize::<PeriodicEvent<f32>>(serialize(&periodic_f32_event)),
-            periodic_f32_event
+            events
+                .value::<EventFields>("reading")
+                .and_then(|fields| fields.get("humidity").cloned()),
+            Some(EventValue::U8(40))
         );
 
-        let f64_event = Event::f64("f64_event").description("An f64 event");
-        assert_eq!(deserialize::<Event<f64>>(serialize(&f64_event)), f64_event);
+        // Values with a different type are discarded.
+        assert!(!events.update_value(0, 3_u32));
+        assert!(!events.update_value(0, EventValue::Text("3".into())));
+        assert!(
+            !events
+                .update_periodic_value(0, EventFields::init("temperature".into(), 21.5_f32.into()))
+        );
+        assert_eq!(events.value::<u32>("counter"), None);
+        assert_eq!(events.value::<u16>("counter"), Some(3));
+
+        // Missing events are never updated.
+        assert!(!events.update_value(2, 3_u16));
+        assert!(!events.update_periodic_value(1, 3_u16));
+        assert_eq!(events.value::<u16>("missing"), None);
 
-        let periodic_f64_event = PeriodicEvent::f64(f64_event, DEFAULT_DURATION);
         assert_eq!(
-            deserialize::<PeriodicEvent<f64>>(serialize(&periodic_f64_event)),
-            periodic_f64_event
+            events.event("reading").map(|event| event.value.type_name()),
+            Some("object")
         );
+        assert!(events.to_string().contains("Value: \"running\"\n"));
     }
 
     #[test]
-    fn test_events_with_single_event_kind() {
-        let bool_event = Event::bool("bool_event").description("A bool event");
+    fn test_emission_rules() {
+        const SECOND: Duration = Duration::from_secs(1);
+
+        // Without rules, every value is emitted.
+        let rules = EmissionRules::new();
+        assert!(rules.is_empty());
+        assert!(rules.should_emit(1., Some((1., Duration::ZERO))));
+
+        // The first value is always emitted.
+        let rules = EmissionRules::new()
+            .on_change()
+            .min_interval(Duration::from_secs(10));
+        assert!(rules.should_emit(1., None));
+        assert!(!rules.should_emit(2., Some((1., SECOND))));
+        assert!(!rules.should_emit(1., Some((1., Duration::from_secs(10)))));
+        assert!(rules.should_emit(2., Some((1., Duration::from_secs(10)))));
+
+        let rules = EmissionRules::new().deadband(-0.5);
+        assert_eq!(rules.deadband, Some(0.5));
+        assert!(!rules.should_emit(20.4, Some((20., SECOND))));
+        assert!(rules.should_emit(19.5, Some((20., SECOND))));
+
+        // The deadband is the hysteresis of the thresholds.
+        let rules = EmissionRules::new()
+            .upper_threshold(30.)
+            .lower_threshold(10.)
+            .deadband(1.);
+        assert!(!rules.should_emit(25., Some((20., SECOND))));
+        assert!(rules.should_emit(30., Some((25., SECOND))));
+        assert!(!rules.should_emit(35., Some((30., SECOND))));
+        assert!(!rules.should_emit(29.5, Some((30., SECOND))));
+        assert!(rules.should_emit(28.9, Some((30., SECOND))));
+        assert!(rules.should_emit(5., Some((20., SECOND))));
+        assert!(!rules.should_emit(10.5, Some((5., SECOND))));
+        assert!(rules.should_emit(40., Some((5., SECOND))));
+
+        // Boolean events are compared as numbers.
+        let mut event = Event::bool("door").rules(EmissionRules::new().on_change());
+        assert!(event.should_emit(&false.into(), None));
+        assert!(!event.should_emit(&false.into(), Some(SECOND)));
+        assert!(event.should_emit(&true.into(), Some(SECOND)));
+        assert!(event.update_value(true.into()));
+        assert!(!event.should_emit(&true.into(), Some(SECOND)));
+
+        // Texts and objects only honour the on change rule and the
+        // minimum interval.
+        let event = Event::text("status").rules(
+            EmissionRules::new()
+                .on_change()
+                .deadband(1.)
+                .min_interval(Duration::from_secs(10)),
+        );
+        assert!(event.should_emit(&String::new().into(), None));
+        assert!(!event.should_emit(&String::from("idle").into(), Some(SECOND)));
+        assert!(!event.should_emit(&String::new().into(), Some(Duration::from_secs(10))));
+        assert!(event.should_emit(&String::from("idle").into(), Some(Duration::from_secs(10))));
+
+        let event = Event::f32("temperature").rules(
+            EmissionRules::new()
+                .deadband(0.5)
+                .upper_threshold(30.)
+                .min_interval(Duration::from_millis(1500)),
+        );
+        let value = serialize(&event);
+        assert_eq!(value["rules"]["deadband"], 0.5);
+        assert!(value["rules"].get("on_change").is_none());
+        assert!(value["rules"].get("lower_threshold").is_none());
+        assert_eq!(deserialize::<Event>(value), event);
+        assert!(
+            event.to_string().contains(
+                "Emission: deadband 0.5, upper threshold 30, minimum interval 1s 500ms\n"
+            )
+        );
 
-        let mut events = Events::empty();
-        events.add_bool_event(bool_event);
+        // Events without rules do not serialize them.
+        assert!(serialize(Event::u8("u8_event")).get("rules").is_none());
 
-        assert_eq!(deserialize::<Events>(serialize(&events)), events);
+        let mut events = Events::empty();
+        events.add_event(Event::f32("temperature"));
+        events.add_periodic_event(PeriodicEvent::new(Event::u8("level"), SECOND));
+        assert!(events.set_rules("level", EmissionRules::new().on_change()));
+        assert!(!events.set_rules("missing", EmissionRules::new().on_change()));
+        assert!(events.periodic_events_as_slice()[0].event.rules.on_change);
+        assert!(events.events_as_slice()[0].rules.is_empty());
     }
 
     #[test]
-    #[allow(clippy::similar_names)]
-    fn test_events_with_all_event_kinds() {
-        let bool_event = Event::bool("bool_event").description("A bool event");
-        let periodic_bool_event = PeriodicEvent::bool(bool_event.clone(), DEFAULT_DURATION);
-        let u8_event = Event::u8("u8_event").description("An u8 event");
-        let periodic_u8_event = PeriodicEvent::u8(u8_event.clone(), DEFAULT_DURATION);
-        let i32_event = Event::i32("i32_event").description("An i32 event");
-        let periodic_i32_event = PeriodicEvent::i32(i32_event.clone(), DEFAULT_DURATION);
-        let f32_event = Event::f32("f32_event").description("An f32 event");
-        let periodic_f32_event = PeriodicEvent::f32(f32_event.clone(), DEFAULT_DURATION);
-        let f64_event = Event::f64("f64_event").description("An f64 event");
-        let periodic_f64_event = PeriodicEvent::f64(f64_event.clone(), DEFAULT_DURATION);
-
+    fn test_events_frames() {
         let mut events = Events::empty();
-        events.add_bool_event(bool_event);
-        events.add_periodic_bool_event(periodic_bool_event);
-        events.add_u8_event(u8_event);
-        events.add_periodic_u8_event(periodic_u8_event);
-        events.add_i32_event(i32_event);
-        events.add_periodic_i32_event(periodic_i32_event);
-        events.add_f32_event(f32_event);
-        events.add_periodic_f32_event(periodic_f32_event);
-        events.add_f64_event(f64_event);
-        events.add_periodic_f64_event(periodic_f64_event);
+        events.add_event(Event::f32("temperature").unit(Unit::Celsius));
+
+        // Frame data is not serialized before the first frame.
+        let value = serialize(&events);
+        assert!(value.get("sequence").is_none());
+        assert!(value.get("timestamp").is_none());
+        assert_eq!(events.sequence(), 0);
+
+        assert_eq!(events.next_frame(None), 1);
+        assert_eq!(events.next_frame(Some(Timestamp::from_unix(60))), 2);
+
+        let value = serialize(&events);
+        assert_eq!(value["sequence"], 2);
+        assert_eq!(value["timestamp"], "1970-01-01T00:01:00Z");
+
+        let deserialized = deserialize::<Events>(value);
+        assert_eq!(deserialized.sequence(), 2);
+        assert_eq!(deserialized.timestamp(), Some(Timestamp::from_unix(60)));
+        assert_eq!(deserialized, events);
+
+        assert!(
+            events
+                .to_string()
+                .starts_with("Sequence: 2\nTimestamp: 1970-01-01T00:01:00Z\n")
+        );
 
-        assert_eq!(deserialize::<Events>(serialize(&events)), events);
+        // The sequence number restarts from one, skipping zero.
+        events.sequence = u64::MAX;
+        assert_eq!(events.next_frame(None), 1);
+        assert_eq!(events.timestamp(), None);
     }
 
     #[test]
@@ -860,7 +1343,7 @@ mod tests {
 
         let bool_event = Event::bool("bool_event").description("A bool event");
         let mut events = Events::empty();
-        events.add_bool_event(bool_event);
+        events.add_event(bool_event);
 
         let events_description = EventsDescription::new(broker_data, topic, events);
         assert_eq!(
@@ -868,4 +1351,30 @@ mod tests {
             events_description
         );
     }
+    #[test]
+    fn test_event_unit() {
+        let event = Event::f32("temperature")
+            .description("Room temperature")
+            .unit(Unit::Celsius);
+
+        let value = serialize(&event);
+        assert_eq!(value["unit"], "°C");
+        assert_eq!(deserialize::<Event>(value), event);
+
+        let periodic_event = PeriodicEvent::new(event, DEFAULT_DURATION);
+        assert_eq!(
+            deserialize::<PeriodicEvent>(serialize(&periodic_event)),
+            periodic_event
+        );
+        assert_eq!(
+            periodic_event.to_string(),
+            "Interval: 1s 0ms\nName: \"temperature\"\nDescription: \"Room temperature\"\nType: f32\nUnit: °C\nValue: 0\n"
+        );
+
+        // An event without unit does not serialize it.
+        assert!(serialize(Event::u8("u8_event")).get("unit").is_none());
+
+        let event = Event::i32("speed").unit(Unit::custom("kn"));
+        assert_eq!(deserialize::<Event>(serialize(&event)), event);
+    }
 }
@@ -1,5 +1,6 @@
 use hashbrown::DefaultHashBuilder;
 
+use indexmap::map::{self, IndexMap};
 use indexmap::set::{IndexSet, IntoIter, Iter};
 
 use serde::{Deserialize, Serialize};
@@ -311,6 +312,74 @@ impl Hazard {
         }
     }
 
+    /// Returns the default [`Severity`] of the [`Hazard`].
+    ///
+    /// A route can override it through [`HazardRisks`].
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        match self {
+            Self::AirPoisoning | Self::Asphyxia | Self::Explosion | Self::FireHazard => {
+                Severity::Critical
+            }
+            Self::AudioVideoRecordAndStore
+            | Self::PowerSurge
+            | Self::SpendMoney
+            | Self::TakePictures
+            | Self::UnauthorisedPhysicalAccess
+            | Self::VideoRecordAndStore
+            | Self::WaterFlooding => Severity::High,
+            Self::AudioVideoDisplay
+            | Self::PaySubscriptionFee
+            | Self::PowerOutage
+            | Self::RecordIssuedCommands
+            | Self::RecordUserPreferences
+            | Self::SpoiledFood
+            | Self::TakeDeviceScreenshots
+            | Self::VideoDisplay => Severity::Medium,
+            Self::GasConsumption | Self::LogEnergyConsumption | Self::LogUsageTime => Severity::Low,
+            Self::ElectricEnergyConsumption | Self::WaterConsumption => Severity::Negligible,
+        }
+    }
+
+    /// Returns the default [`Likelihood`] of the [`Hazard`].
+    ///
+    /// A route can override it through [`HazardRisks`].
+    #[must_use]
+    pub const fn likelihood(&self) -> Likelihood {
+        match self {
+            Self::Explosion => Likelihood::Rare,
+            Self::AirPoisoning
+            | Self::Asphyxia
+            | Self::FireHazard
+            | Self::PowerSurge
+            | Self::WaterFlooding => Likelihood::Unlikely,
+            Self::PowerOutage | Self::SpoiledFood | Self::UnauthorisedPhysicalAccess => {
+                Likelihood::Possible
+            }
+            Self::AudioVideoDisplay
+            | Self::AudioVideoRecordAndStore
+            | Self::SpendMoney
+            | Self::TakeDeviceScreenshots
+            | Self::TakePictures
+            | Self::VideoDisplay
+            | Self::VideoRecordAndStore => Likelihood::Likely,
+            Self::ElectricEnergyConsumption
+            | Self::GasConsumption
+            | Self::LogEnergyConsumption
+            | Self::LogUsageTime
+            | Self::PaySubscriptionFee
+            | Self::RecordIssuedCommands
+            | Self::RecordUserPreferences
+            | Self::WaterConsumption => Likelihood::Certain,
+        }
+    }
+
+    /// Returns the default [`HazardRisk`] of the [`Hazard`].
+    #[must_use]
+    pub const fn risk(&self) -> HazardRisk {
+        HazardRisk::new(self.severity(), self.likelihood())
+    }
+
     /// Returns the [`HazardData`] constructed from the given [`Hazard`].
     #[must_use]
     pub const fn data(&self) -> HazardData {
@@ -320,6 +389,8 @@ impl Hazard {
             description: self.description(),
             category_name: self.category().name(),
             category_description: self.category().description(),
+            severity_name: self.severity().name(),
+            likelihood_name: self.likelihood().name(),
         }
     }
 }
@@ -342,6 +413,32 @@ impl Hazards {
         }
         elements
     }
+
+    /// Returns the highest [`Severity`] among the [`Hazards`].
+    ///
+    /// The severity of each hazard is taken from the given [`HazardRisks`],
+    /// falling back to the hazard default.
+    ///
+    /// If [`None`], the collection is empty.
+    #[must_use]
+    pub fn max_severity(&self, risks: &HazardRisks) -> Option<Severity> {
+        self.iter().map(|hazard| risks.risk(*hazard).severity).max()
+    }
+
+    /// Returns the risk score of the [`Hazards`], that is the highest
+    /// [`HazardRisk::score`] among them.
+    ///
+    /// The risk of each hazard is taken from the given [`HazardRisks`],
+    /// falling back to the hazard default.
+    ///
+    /// An empty collection has a score of zero.
+    #[must_use]
+    pub fn risk_score(&self, risks: &HazardRisks) -> u8 {
+        self.iter()
+            .map(|hazard| risks.risk(*hazard).score())
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 /// All [`Hazard`] data.
@@ -357,6 +454,293 @@ pub struct HazardData {
     pub category_name: &'static str,
     /// Category description.
     pub category_description: &'static str,
+    /// Default severity name.
+    pub severity_name: &'static str,
+    /// Default likelihood name.
+    pub likelihood_name: &'static str,
+}
+
+/// All [`Severity`] levels, from the lowest to the highest.
+pub const ALL_SEVERITIES: &[Severity] = &[
+    Severity::Negligible,
+    Severity::Low,
+    Severity::Medium,
+    Severity::High,
+    Severity::Critical,
+];
+
+/// The severity of the harm caused by a [`Hazard`].
+///
+/// Severity levels are ordered, so a [`Severity`] can be compared with
+/// another one.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum Severity {
+    /// The harm is negligible.
+    Negligible,
+    /// The harm is minor and easily recoverable.
+    Low,
+    /// The harm is noticeable but recoverable.
+    Medium,
+    /// The harm is serious and hardly recoverable.
+    High,
+    /// The harm threatens human lives or is irreversible.
+    Critical,
+}
+
+impl core::fmt::Debug for Severity {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl core::fmt::Display for Severity {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl Severity {
+    /// Returns the [`Severity`] name.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Negligible => "Negligible",
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+            Self::Critical => "Critical",
+        }
+    }
+
+    /// Returns the [`Severity`] level, from 1 up to 5.
+    #[must_use]
+    pub const fn level(&self) -> u8 {
+        match self {
+            Self::Negligible => 1,
+            Self::Low => 2,
+            Self::Medium => 3,
+            Self::High => 4,
+            Self::Critical => 5,
+        }
+    }
+}
+
+/// All [`Likelihood`] levels, from the lowest to the highest.
+pub const ALL_LIKELIHOODS: &[Likelihood] = &[
+    Likelihood::Rare,
+    Likelihood::Unlikely,
+    Likelihood::Possible,
+    Likelihood::Likely,
+    Likelihood::Certain,
+];
+
+/// The likelihood that a [`Hazard`] occurs when a route is executed.
+///
+/// Likelihood levels are ordered, so a [`Likelihood`] can be compared with
+/// another one.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum Likelihood {
+    /// The hazard occurs only in exceptional circumstances.
+    Rare,
+    /// The hazard could occur, but it is not expected.
+    Unlikely,
+    /// The hazard might occur.
+    Possible,
+    /// The hazard occurs in most circumstances.
+    Likely,
+    /// The hazard occurs at every execution.
+    Certain,
+}
+
+impl core::fmt::Debug for Likelihood {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl core::fmt::Display for Likelihood {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl Likelihood {
+    /// Returns the [`Likelihood`] name.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Rare => "Rare",
+            Self::Unlikely => "Unlikely",
+            Self::Possible => "Possible",
+            Self::Likely => "Likely",
+            Self::Certain => "Certain",
+        }
+    }
+
+    /// Returns the [`Likelihood`] level, from 1 up to 5.
+    #[must_use]
+    pub const fn level(&self) -> u8 {
+        match self {
+            Self::Rare => 1,
+            Self::Unlikely => 2,
+            Self::Possible => 3,
+            Self::Likely => 4,
+            Self::Certain => 5,
+        }
+    }
+}
+
+/// The risk of a [`Hazard`], defined by its [`Severity`] and [`Likelihood`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct HazardRisk {
+    /// Severity.
+    pub severity: Severity,
+    /// Likelihood.
+    pub likelihood: Likelihood,
+}
+
+impl core::fmt::Display for HazardRisk {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{} severity, {} likelihood (score {})",
+            self.severity,
+            self.likelihood,
+            self.score()
+        )
+    }
+}
+
+impl HazardRisk {
+    /// Creates a [`HazardRisk`].
+    #[must_use]
+    pub const fn new(severity: Severity, likelihood: Likelihood) -> Self {
+        Self {
+            severity,
+            likelihood,
+        }
+    }
+
+    /// Returns the risk score, that is the product of the [`Severity`] level
+    /// and the [`Likelihood`] level.
+    ///
+    /// The score ranges from 1 up to 25.
+    #[must_use]
+    pub const fn score(&self) -> u8 {
+        self.severity.level() * self.likelihood.level()
+    }
+}
+
+/// A map associating a [`Hazard`] with the [`HazardRisk`] which overrides
+/// its default one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct HazardRisks(IndexMap<Hazard, HazardRisk, DefaultHashBuilder>);
+
+impl<'a> IntoIterator for &'a HazardRisks {
+    type Item = (&'a Hazard, &'a HazardRisk);
+    type IntoIter = map::Iter<'a, Hazard, HazardRisk>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Default for HazardRisks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HazardRisks {
+    /// Creates an empty [`HazardRisks`].
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self(IndexMap::with_hasher(DefaultHashBuilder::default()))
+    }
+
+    /// Initializes [`HazardRisks`] with a specific element.
+    #[must_use]
+    #[inline]
+    pub fn init(hazard: Hazard, risk: HazardRisk) -> Self {
+        Self::new().insert(hazard, risk)
+    }
+
+    /// Inserts a new element into [`HazardRisks`].
+    #[must_use]
+    #[inline]
+    pub fn insert(mut self, hazard: Hazard, risk: HazardRisk) -> Self {
+        self.0.insert(hazard, risk);
+        self
+    }
+
+    /// Adds a new element into [`HazardRisks`].
+    ///
+    /// Unlike [`Self::insert`], this method does not return a modified
+    /// [`HazardRisks`].
+    #[inline]
+    pub fn add(&mut self, hazard: Hazard, risk: HazardRisk) {
+        self.0.insert(hazard, risk);
+    }
+
+    /// Checks if [`HazardRisks`] is empty.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Provides the number of elements in [`HazardRisks`].
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over [`HazardRisks`].
+    ///
+    /// **Iterates over the elements in the order they were inserted.**
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> map::Iter<'_, Hazard, HazardRisk> {
+        self.0.iter()
+    }
+
+    /// Returns the [`HazardRisk`] of a [`Hazard`].
+    ///
+    /// If the hazard has no overriding risk, its default one is returned.
+    #[must_use]
+    pub fn risk(&self, hazard: Hazard) -> HazardRisk {
+        self.0
+            .get(&hazard)
+            .copied()
+            .unwrap_or_else(|| hazard.risk())
+    }
+
+    /// Returns the [`HazardRisk`] of a [`Hazard`], where the overriding
+    /// risk can only raise the default [`Severity`] and [`Likelihood`].
+    ///
+    /// This is the risk to be checked against a policy, since lowering the
+    /// default risk of a hazard would let a request get past it.
+    #[must_use]
+    pub fn raised_risk(&self, hazard: Hazard) -> HazardRisk {
+        let default = hazard.risk();
+        let risk = self.risk(hazard);
+        HazardRisk::new(
+            default.severity.max(risk.severity),
+            default.likelihood.max(risk.likelihood),
+        )
+    }
+
+    /// Retains only the overriding risks of the given [`Hazards`].
+    #[inline]
+    pub fn retain(&mut self, hazards: &Hazards) {
+        self.0.retain(|hazard, _| hazards.contains(hazard));
+    }
 }
 
 /// All [`Category`]s.
@@ -447,9 +831,14 @@ impl Category {
 #[cfg(test)]
 #[cfg(feature = "deserialize")]
 mod tests {
+    use alloc::string::ToString;
+
     use crate::{deserialize, serialize};
 
-    use super::{ALL_CATEGORIES, ALL_HAZARDS, Category, Hazard};
+    use super::{
+        ALL_CATEGORIES, ALL_HAZARDS, ALL_LIKELIHOODS, ALL_SEVERITIES, Category, Hazard, HazardRisk,
+        HazardRisks, Hazards, Likelihood, Severity,
+    };
 
     #[test]
     fn test_hazard() {
@@ -467,9 +856,9 @@ mod tests {
                         "description": hazard.description(),
                         "category_name": hazard.category().name(),
                         "category_description": hazard.category().description(),
+                        "severity_name": hazard.severity().name(),
+                        "likelihood_name": hazard.likelihood().name(),
                     }
-
-
                 )
             );
             assert_eq!(deserialize::<Hazard>(serialize(hazard)), *hazard);
@@ -483,4 +872,80 @@ mod tests {
             assert_eq!(deserialize::<Category>(serialize(category)), *category);
         }
     }
+
+    #[test]
+    fn test_severity_and_likelihood() {
+        for (level, severity) in (1..).zip(ALL_SEVERITIES) {
+            assert_eq!(severity.level(), level);
+            assert_eq!(deserialize::<Severity>(serialize(severity)), *severity);
+        }
+        for (level, likelihood) in (1..).zip(ALL_LIKELIHOODS) {
+            assert_eq!(likelihood.level(), level);
+            assert_eq!(
+                deserialize::<Likelihood>(serialize(likelihood)),
+                *likelihood
+            );
+        }
+
+        assert!(Severity::Critical > Severity::High);
+        assert!(Likelihood::Rare < Likelihood::Certain);
+        assert!(Hazard::FireHazard.severity() > Hazard::LogUsageTime.severity());
+    }
+
+    #[test]
+    fn test_hazard_risks() {
+        let risk = HazardRisk::new(Severity::High, Likelihood::Possible);
+        assert_eq!(risk.score(), 12);
+        assert_eq!(
+            risk.to_string(),
+            "High severity, Possible likelihood (score 12)"
+        );
+        assert_eq!(deserialize::<HazardRisk>(serialize(risk)), risk);
+
+        let hazards = Hazards::init_from_hazards([Hazard::FireHazard, Hazard::LogUsageTime]);
+
+        // Without overriding risks, the default ones are used.
+        let risks = HazardRisks::new();
+        assert_eq!(hazards.max_severity(&risks), Some(Severity::Critical));
+        assert_eq!(hazards.risk_score(&risks), 10);
+
+        // An overriding risk replaces the default one.
+        let mut risks = HazardRisks::init(
+            Hazard::FireHazard,
+            HazardRisk::new(Severity::High, Likelihood::Rare),
+        )
+        .insert(Hazard::SpendMoney, risk);
+        assert_eq!(risks.risk(Hazard::FireHazard).score(), 4);
+        assert_eq!(
+            risks.risk(Hazard::LogUsageTime),
+            Hazard::LogUsageTime.risk()
+        );
+        assert_eq!(hazards.max_severity(&risks), Some(Severity::High));
+        assert_eq!(hazards.risk_score(&risks), 10);
+
+        // An overriding risk cannot lower the default risk of a policy.
+        assert_eq!(
+            risks.raised_risk(Hazard::FireHazard),
+            Hazard::FireHazard.risk()
+        );
+
+        // An overriding risk can raise the default risk of a policy.
+        let raised = HazardRisk::new(Severity::Critical, Likelihood::Certain);
+        assert_eq!(
+            risks
+                .clone()
+                .insert(Hazard::LogUsageTime, raised)
+                .raised_risk(Hazard::LogUsageTime),
+            raised
+        );
+        assert_eq!(deserialize::<HazardRisks>(serialize(&risks)), risks);
+
+        // Only the risks of the given hazards are retained.
+        risks.retain(&hazards);
+        assert_eq!(risks.len(), 1);
+
+        // Empty hazards carry no risk.
+        assert_eq!(Hazards::new().max_severity(&risks), None);
+        assert_eq!(Hazards::new().risk_score(&risks), 0);
+    }
 }
@@ -7,7 +7,8 @@
 //!   more tasks on a device. Each route is always associated with a response.
 //! - Manage hazards that may arise during the execution of a route.
 //!   Hazards describe safety, privacy, and financial risks, and are always
-//!   associated with a route.
+//!   associated with a route. Each hazard has a severity and a likelihood,
+//!   which define its risk score.
 //! - Manage route parameters. Parameters may represent external
 //!   information required for device tasks or conditions that control whether
 //!   instructions are executed. For example, a boolean parameter might control
@@ -20,6 +21,10 @@
 //! A device can avoid importing deserialization functions by disabling the
 //! `deserialize` feature at compile time.
 //!
+//! Data is encoded as `JSON` by default. The `cbor` feature enables the
+//! compact `CBOR` binary encoding, which reduces the size of the data sent
+//! by constrained devices.
+//!
 //! This crate can be compiled for both `std` and `no_std` environments.
 
 #![forbid(unsafe_code)]
@@ -34,18 +39,26 @@ mod macros;
 pub mod device;
 /// Economic information about a device.
 pub mod economy;
+/// Data encodings used to exchange data with a controller.
+pub mod encoding;
 /// Energy information about a device.
 pub mod energy;
 /// Event descriptions and methods.
 pub mod events;
 /// Hazard descriptions and methods.
 pub mod hazards;
+/// `OpenAPI` description of a device.
+pub mod openapi;
 /// Route parameters.
 pub mod parameters;
 /// All possible responses from a device.
 pub mod response;
 /// Route definitions.
 pub mod route;
+/// Measurement units of events and parameters.
+pub mod units;
+/// Colors, times and durations used as route parameters.
+pub mod values;
 
 #[cfg(test)]
 #[cfg(feature = "deserialize")]
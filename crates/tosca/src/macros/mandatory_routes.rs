@@ -4,27 +4,49 @@
 /// before it can be built. This provides compile-time safety for device
 /// construction.
 ///
+/// The path and the allowed methods of the route are also exposed through
+/// the `MANDATORY` associated constant, a
+/// [`MandatoryRoute`](crate::route::MandatoryRoute) which allows a controller
+/// to find the route on a device.
+///
+/// Attributes, such as documentation comments, can precede the route name.
+///
 /// # Example
 ///
 /// ```rust,ignore
 /// use tosca::mandatory_route;
 ///
 /// mandatory_route!(MyRoute, "/my-path", methods: [put, get]);
+///
+/// mandatory_route!(
+///     /// Documentation of the route.
+///     MyDocumentedRoute,
+///     "/my-documented-path",
+///     methods: [post]
+/// );
 /// ```
 #[macro_export]
 macro_rules! mandatory_route {
     (
+        $(#[$attr:meta])*
         $name:ident,
         $path:expr,
         methods: [$($method:ident),* $(,)?]
     ) => {
         #[doc = concat!("A mandatory [`", stringify!($name), "`].")]
+        $(#[$attr])*
         #[derive(Debug)]
         pub struct $name {
             route: $crate::route::Route,
         }
 
         impl $name {
+            #[doc = "The path and the allowed methods of the route."]
+            pub const MANDATORY: $crate::route::MandatoryRoute = $crate::route::MandatoryRoute::new(
+                $path,
+                &[$($crate::mandatory_route!(@rest_kind $method)),*],
+            );
+
             $(
                 $crate::mandatory_route!(@method_fn $method, $name, $path);
             )*
@@ -107,6 +129,22 @@ macro_rules! mandatory_route {
         }
     };
 
+    (@rest_kind get) => {
+        $crate::route::RestKind::Get
+    };
+
+    (@rest_kind put) => {
+        $crate::route::RestKind::Put
+    };
+
+    (@rest_kind post) => {
+        $crate::route::RestKind::Post
+    };
+
+    (@rest_kind delete) => {
+        $crate::route::RestKind::Delete
+    };
+
     (@method_fn get, $name:ident, $path:expr) => {
         #[doc = concat!("Creates a [`", stringify!($name), "`] through a `GET` API.")]
         #[must_use]
@@ -154,7 +192,7 @@ macro_rules! mandatory_route {
 
 #[cfg(test)]
 mod tests {
-    use crate::hazards::Hazard;
+    use crate::hazards::{Hazard, Hazards};
     use crate::parameters::Parameters;
     use crate::route::RestKind;
 
@@ -189,6 +227,33 @@ mod tests {
         assert!(!route.parameters().is_empty());
     }
 
+    #[test]
+    fn test_mandatory_route_hazards_and_name() {
+        let route = TestRoute::post("On")
+            .change_name("Off")
+            .with_hazards(Hazards::new().insert(Hazard::FireHazard))
+            .with_array_of_hazards([Hazard::ElectricEnergyConsumption])
+            .into_route();
+
+        assert_eq!(route.route(), "/test");
+        assert_eq!(route.hazards().len(), 1);
+        assert!(route.hazards().contains(&Hazard::ElectricEnergyConsumption));
+    }
+
+    #[test]
+    fn test_mandatory_route_data() {
+        assert_eq!(TestRoute::MANDATORY.path(), "/test");
+        assert_eq!(
+            TestRoute::MANDATORY.rest_kinds(),
+            &[
+                RestKind::Get,
+                RestKind::Put,
+                RestKind::Post,
+                RestKind::Delete
+            ]
+        );
+    }
+
     #[test]
     fn test_mandatory_route_into_route() {
         let route = TestRoute::get("Toggle").description("Toggle.").into_route();
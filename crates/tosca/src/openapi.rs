@@ -0,0 +1,630 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use serde::Serialize;
+
+use serde_json::{Map, Value, json};
+
+use crate::device::DeviceData;
+use crate::hazards::{HazardRisks, Hazards};
+use crate::parameters::{ParameterKind, ParameterValue, ParametersData, STEP_TOLERANCE};
+use crate::response::ResponseKind;
+use crate::route::{RestKind, RouteConfig};
+
+/// The `OpenAPI` specification version of the generated documents.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+// Location of the schemas shared among all operations.
+const SCHEMAS: &str = "#/components/schemas";
+
+// Document version of a device without a firmware version.
+const UNKNOWN_VERSION: &str = "unknown";
+
+/// Converts a [`DeviceData`] into an `OpenAPI` document.
+///
+/// Each route becomes an operation of its full path, the device main route
+/// followed by the route path. Parameters of `GET` routes are path
+/// parameters, while parameters of any other route are the fields of a
+/// `JSON` request body.
+///
+/// Route hazards are described by the `x-tosca-hazards` extension, while
+/// the response kind by the `x-tosca-response-kind` extension. The device
+/// identity, when present, is described by the `x-tosca-identity` extension
+/// and its firmware version becomes the document version, which is
+/// otherwise `unknown`. The device capabilities, when present, are described
+/// by the `x-tosca-capabilities` extension.
+#[must_use]
+pub fn document(device_data: &DeviceData) -> Value {
+    let mut paths = Map::new();
+    for route_config in &device_data.route_configs {
+        let (path, operation) = operation(&device_data.main_route, route_config);
+        let method = match route_config.rest_kind {
+            RestKind::Get => "get",
+            RestKind::Put => "put",
+            RestKind::Post => "post",
+            RestKind::Delete => "delete",
+        };
+
+        if let Value::Object(path_item) = paths
+            .entry(path)
+            .or_insert_with(|| Value::Object(Map::new()))
+        {
+            path_item.insert(method.into(), operation);
+        }
+    }
+
+    let mut info = Map::new();
+    info.insert("title".into(), json!(device_data.kind.name()));
+    info.insert(
+        "version".into(),
+        json!(
+            device_data
+                .identity
+                .firmware_version
+                .as_deref()
+                .unwrap_or(UNKNOWN_VERSION)
+        ),
+    );
+    if let Some(description) = &device_data.description {
+        info.insert("description".into(), json!(description));
+    }
+    if !device_data.identity.is_empty() {
+        info.insert("x-tosca-identity".into(), json!(device_data.identity));
+    }
+    if !device_data.capabilities.is_empty() {
+        info.insert(
+            "x-tosca-capabilities".into(),
+            json!(device_data.capabilities),
+        );
+    }
+
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": info,
+        "paths": paths,
+        "components": {
+            "schemas": {
+                "OkResponse": {
+                    "type": "object",
+                    "properties": {
+                        "action_terminated_correctly": { "type": "boolean" },
+                    },
+                    "required": ["action_terminated_correctly"],
+                },
+                "InfoResponse": {
+                    "type": "object",
+                    "properties": {
+                        "energy": { "type": "object" },
+                        "economy": { "type": "object" },
+                    },
+                },
+                "ErrorResponse": {
+                    "type": "object",
+                    "properties": {
+                        "error": { "enum": ["InvalidData", "Internal"] },
+                        "description": { "type": "string" },
+                        "info": { "type": ["string", "null"] },
+                    },
+                    "required": ["error", "description"],
+                },
+            },
+        },
+    })
+}
+
+/// Returns the `JSON` schema of the values of a [`ParameterKind`].
+///
+/// The schema contains the parameter limits and its default value. The
+/// measurement unit of a numeric parameter, when present, is described by
+/// the `x-tosca-unit` extension.
+#[must_use]
+pub fn parameter_schema(parameter_kind: &ParameterKind) -> Value {
+    let mut schema = match parameter_kind {
+        ParameterKind::Bool { .. } => json!({ "type": "boolean" }),
+        ParameterKind::U8 { min, max, .. } => integer("uint8", (*min, *max), None),
+        ParameterKind::U16 { min, max, .. } | ParameterKind::ColorTemperature { min, max, .. } => {
+            integer("uint16", (*min, *max), None)
+        }
+        ParameterKind::U32 { min, max, .. } => integer("uint32", (*min, *max), None),
+        ParameterKind::U64 { min, max, .. } => integer("uint64", (*min, *max), None),
+        ParameterKind::I8 { min, max, .. } => integer("int8", (*min, *max), None),
+        ParameterKind::I16 { min, max, .. } => integer("int16", (*min, *max), None),
+        ParameterKind::I32 { min, max, .. } => integer("int32", (*min, *max), None),
+        ParameterKind::I64 { min, max, .. } => integer("int64", (*min, *max), None),
+        ParameterKind::RangeU32 { min, max, step, .. } => {
+            integer("uint32", (*min, *max), Some(*step))
+        }
+        ParameterKind::RangeU64 { min, max, step, .. } => {
+            integer("uint64", (*min, *max), Some(*step))
+        }
+        ParameterKind::RangeI32 { min, max, step, .. } => {
+            integer("int32", (*min, *max), Some(*step))
+        }
+        ParameterKind::RangeI64 { min, max, step, .. } => {
+            integer("int64", (*min, *max), Some(*step))
+        }
+        ParameterKind::F32 { min, max, step, .. } => number(
+            "float",
+            (f32_number(*min), f32_number(*max)),
+            f32_number(*step),
+        ),
+        ParameterKind::F64 { min, max, step, .. }
+        | ParameterKind::RangeF64 { min, max, step, .. } => number("double", (*min, *max), *step),
+        ParameterKind::CharsSequence { .. } => json!({ "type": "string" }),
+        ParameterKind::Choice { options, .. } => json!({ "type": "string", "enum": options }),
+        ParameterKind::Color { .. } => json!({
+            "type": "string",
+            "format": "color",
+            "pattern": r"^(#[0-9a-fA-F]{6}|hsv\([^)]*\))$",
+        }),
+        ParameterKind::Duration { min, max, .. } => {
+            let mut schema = json!({
+                "type": "string",
+                "format": "duration",
+                "pattern": "^[0-9]+(ms|s|m|h)$",
+            });
+            // When the minimum is greater than the maximum, the duration
+            // has no limits.
+            if min <= max {
+                schema["x-tosca-minimum"] = json!(ParameterValue::Duration(*min));
+                schema["x-tosca-maximum"] = json!(ParameterValue::Duration(*max));
+            }
+            schema
+        }
+        ParameterKind::TimeOfDay { .. } => json!({
+            "type": "string",
+            "format": "time",
+            "pattern": "^[0-9]{2}:[0-9]{2}(:[0-9]{2})?$",
+        }),
+        ParameterKind::Timestamp { .. } => json!({ "type": "string", "format": "date-time" }),
+        ParameterKind::List {
+            kind,
+            min_length,
+            max_length,
+        } => {
+            let mut schema = json!({ "type": "array", "items": parameter_schema(kind) });
+            if *min_length > 0 {
+                schema["minItems"] = json!(min_length);
+            }
+            if *max_length < u32::MAX {
+                schema["maxItems"] = json!(max_length);
+            }
+            schema
+        }
+        ParameterKind::Object { parameters } => object_schema(parameters),
+    };
+
+    if let Some(unit) = parameter_kind.unit() {
+        schema["x-tosca-unit"] = json!(unit);
+    }
+
+    schema["default"] = match parameter_kind {
+        ParameterKind::F32 { default, .. } => json!(f32_number(*default)),
+        _ => json!(ParameterValue::from_parameter_kind(parameter_kind)),
+    };
+    schema
+}
+
+// Describes a route as an operation of its full path.
+fn operation(main_route: &str, route_config: &RouteConfig) -> (String, Value) {
+    let data = &route_config.data;
+    let mut path = format!("{main_route}{}", data.path);
+
+    let mut operation = Map::new();
+    operation.insert("operationId".into(), json!(data.name));
+    operation.insert("summary".into(), json!(data.name));
+    if let Some(description) = &data.description {
+        operation.insert("description".into(), json!(description));
+    }
+
+    if !data.parameters.is_empty() {
+        if route_config.rest_kind == RestKind::Get {
+            let mut parameters = Vec::new();
+            for (name, kind) in &data.parameters {
+                path.push_str("/{");
+                path.push_str(name);
+                path.push('}');
+                parameters.push(json!({
+                    "name": name,
+                    "in": "path",
+                    "required": true,
+                    "schema": parameter_schema(kind),
+                }));
+            }
+            operation.insert("parameters".into(), Value::Array(parameters));
+        } else {
+            operation.insert(
+                "requestBody".into(),
+                json!({
+                    "required": true,
+                    "content": {
+                        "application/json": { "schema": object_schema(&data.parameters) },
+                    },
+                }),
+            );
+        }
+    }
+
+    operation.insert(
+        "responses".into(),
+        json!({
+            "200": response(route_config),
+            "400": error_response("Invalid or malformed data."),
+            "500": error_response("Internal device error."),
+        }),
+    );
+    operation.insert(
+        "x-tosca-response-kind".into(),
+        json!(route_config.response_kind.to_string()),
+    );
+    if !data.hazards.is_empty() {
+        operation.insert(
+            "x-tosca-hazards".into(),
+            hazards(&data.hazards, &data.risks),
+        );
+    }
+
+    (path, Value::Object(operation))
+}
+
+// Describes the successful response of an operation.
+fn response(route_config: &RouteConfig) -> Value {
+    let (description, content_type, schema) = match route_config.response_kind {
+        ResponseKind::Ok => (
+            "The operation completed successfully.",
+            "application/json",
+            json!({ "$ref": format!("{SCHEMAS}/OkResponse") }),
+        ),
+        ResponseKind::Serial => (
+            "The data produced during the operation.",
+            "application/json",
+            // A payload without declared fields can be any value.
+            if route_config.data.serial_payload.is_empty() {
+                json!({})
+            } else {
+                object_schema(&route_config.data.serial_payload)
+            },
+        ),
+        ResponseKind::Info => (
+            "The device energy and economy information.",
+            "application/json",
+            json!({ "$ref": format!("{SCHEMAS}/InfoResponse") }),
+        ),
+        #[cfg(feature = "stream")]
+        ResponseKind::Stream => (
+            "A byte stream of data.",
+            "application/octet-stream",
+            json!({ "type": "string", "format": "binary" }),
+        ),
+    };
+
+    json!({
+        "description": description,
+        "content": { content_type: { "schema": schema } },
+    })
+}
+
+// Describes an error response of an operation.
+fn error_response(description: &str) -> Value {
+    json!({
+        "description": description,
+        "content": {
+            "application/json": { "schema": { "$ref": format!("{SCHEMAS}/ErrorResponse") } },
+        },
+    })
+}
+
+// Describes the hazards of a route together with their route risks.
+fn hazards(hazards: &Hazards, risks: &HazardRisks) -> Value {
+    hazards
+        .iter()
+        .map(|hazard| {
+            let risk = risks.risk(*hazard);
+            json!({
+                "id": hazard.id(),
+                "name": hazard.name(),
+                "description": hazard.description(),
+                "category": hazard.category().name(),
+                "severity": risk.severity.name(),
+                "likelihood": risk.likelihood.name(),
+            })
+        })
+        .collect()
+}
+
+// Describes an object whose fields are all mandatory parameters.
+fn object_schema(parameters: &ParametersData) -> Value {
+    let properties = parameters
+        .iter()
+        .map(|(name, kind)| (name.clone(), parameter_schema(kind)))
+        .collect::<Map<_, _>>();
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": parameters.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+        "additionalProperties": false,
+    })
+}
+
+// Describes an integer value.
+//
+// When the minimum is greater than the maximum, the value has no limits.
+// The step is counted from the minimum, so it can only be expressed as a
+// multiple when the minimum is a multiple of the step as well.
+fn integer<T>(format: &str, (min, max): (T, T), step: Option<T>) -> Value
+where
+    T: Copy + PartialOrd + Into<i128> + Serialize,
+{
+    let mut schema = json!({ "type": "integer", "format": format });
+    if min <= max {
+        schema["minimum"] = json!(min);
+        schema["maximum"] = json!(max);
+    }
+    if let Some(step) = step.filter(|step| (*step).into() > 0)
+        && min.into() % step.into() == 0
+    {
+        schema["multipleOf"] = json!(step);
+    }
+    schema
+}
+
+// Describes a floating-point value.
+//
+// When the minimum is greater than the maximum, the value has no limits,
+// and the step is counted from zero.
+fn number(format: &str, (min, max): (f64, f64), step: f64) -> Value {
+    let mut schema = json!({ "type": "number", "format": format });
+    let limited = min <= max;
+    if limited {
+        schema["minimum"] = json!(min);
+        schema["maximum"] = json!(max);
+    }
+    let fraction = if limited { (min / step % 1.).abs() } else { 0. };
+    if step > 0. && fraction.min(1. - fraction) < STEP_TOLERANCE {
+        schema["multipleOf"] = json!(step);
+    }
+    schema
+}
+
+// Widens an `f32` value through its shortest decimal representation,
+// so that `0.1` is not described as `0.10000000149011612`.
+fn f32_number(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(f64::from(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::device::{
+        Capabilities, Capability, DeviceData, DeviceEnvironment, DeviceIdentity, DeviceKind,
+        DeviceKindId,
+    };
+    use crate::hazards::Hazard;
+    use crate::parameters::{DecimalPrecision, ParameterKind, Parameters};
+    use crate::response::ResponseKind;
+    use crate::route::{Route, RouteConfigs};
+    use crate::units::Unit;
+
+    use super::{OPENAPI_VERSION, document, parameter_schema};
+
+    fn device_data() -> DeviceData {
+        let route_configs = RouteConfigs::init(
+            Route::get("Brightness", "/brightness")
+                .description("Sets the brightness.")
+                .with_parameters(
+                    Parameters::new()
+                        .rangeu64_with_default("level", (0, 20, 5), 5)
+                        .choice("mode", ["warm", "cold"]),
+                )
+                .serialize_data(),
+        )
+        .insert(
+            Route::put("On", "/on")
+                .with_hazard(Hazard::FireHazard)
+                .with_parameters(Parameters::new().bool("on", false))
+                .serialize_data(),
+        )
+        .insert(
+            Route::get("Temperature", "/temperature")
+                .with_serial_payload(Parameters::new().f64("celsius", 0.))
+                .serialize_data()
+                .change_response_kind(ResponseKind::Serial),
+        )
+        .insert(
+            Route::get("Info", "/info")
+                .serialize_data()
+                .change_response_kind(ResponseKind::Info),
+        );
+
+        DeviceData::new(
+            DeviceKindId::from(&DeviceKind::Light),
+            DeviceEnvironment::Os,
+            None,
+            None,
+            "/light",
+            route_configs,
+            1,
+        )
+        .description("A light.")
+    }
+
+    #[test]
+    fn test_document() {
+        let document = document(&device_data());
+
+        assert_eq!(document["openapi"], OPENAPI_VERSION);
+        assert_eq!(document["info"]["title"], "Light");
+        assert_eq!(document["info"]["description"], "A light.");
+        assert_eq!(document["info"]["version"], "unknown");
+        assert!(document["info"].get("x-tosca-identity").is_none());
+        assert!(document["info"].get("x-tosca-capabilities").is_none());
+
+        let identified = super::document(
+            &device_data()
+                .identity(
+                    DeviceIdentity::new()
+                        .manufacturer("Tosca Labs")
+                        .firmware_version("2.1.0"),
+                )
+                .capabilities(Capabilities::init(Capability::Dimmable)),
+        );
+        assert_eq!(identified["info"]["version"], "2.1.0");
+        assert_eq!(
+            identified["info"]["x-tosca-identity"],
+            json!({ "manufacturer": "Tosca Labs", "firmware_version": "2.1.0" })
+        );
+        assert_eq!(
+            identified["info"]["x-tosca-capabilities"],
+            json!(["Dimmable"])
+        );
+
+        let brightness = &document["paths"]["/light/brightness/{level}/{mode}"]["get"];
+        assert_eq!(brightness["operationId"], "Brightness");
+        assert_eq!(brightness["description"], "Sets the brightness.");
+        assert_eq!(
+            brightness["parameters"][0],
+            json!({
+                "name": "level",
+                "in": "path",
+                "required": true,
+                "schema": {
+                    "type": "integer",
+                    "format": "uint64",
+                    "minimum": 0,
+                    "maximum": 20,
+                    "multipleOf": 5,
+                    "default": 5,
+                },
+            })
+        );
+        assert_eq!(
+            brightness["parameters"][1]["schema"]["enum"],
+            json!(["warm", "cold"])
+        );
+        assert!(brightness.get("x-tosca-hazards").is_none());
+
+        let on = &document["paths"]["/light/on"]["put"];
+        assert!(on.get("parameters").is_none());
+        assert_eq!(
+            on["requestBody"]["content"]["application/json"]["schema"],
+            json!({
+                "type": "object",
+                "properties": { "on": { "type": "boolean", "default": false } },
+                "required": ["on"],
+                "additionalProperties": false,
+            })
+        );
+        assert_eq!(
+            on["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/OkResponse"
+        );
+        assert_eq!(on["x-tosca-response-kind"], "Ok");
+        assert_eq!(
+            on["x-tosca-hazards"],
+            json!([{
+                "id": Hazard::FireHazard.id(),
+                "name": "Fire Hazard",
+                "description": "The execution may cause fire.",
+                "category": "Safety",
+                "severity": "Critical",
+                "likelihood": "Unlikely",
+            }])
+        );
+
+        let temperature = &document["paths"]["/light/temperature"]["get"];
+        assert_eq!(
+            temperature["responses"]["200"]["content"]["application/json"]["schema"],
+            json!({
+                "type": "object",
+                "properties": {
+                    "celsius": { "type": "number", "format": "double", "default": 0.0 },
+                },
+                "required": ["celsius"],
+                "additionalProperties": false,
+            })
+        );
+
+        let info = &document["paths"]["/light/info"]["get"];
+        assert_eq!(info["x-tosca-response-kind"], "Info");
+        assert_eq!(
+            info["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/InfoResponse"
+        );
+    }
+
+    #[test]
+    fn test_parameter_schema() {
+        let parameters = Parameters::new()
+            .u8("u8", 3)
+            .f32_with_limits("f32", 0.5, 0.1, 1., DecimalPrecision::OneDigit)
+            .f64("f64", 1.5)
+            .rangeu32("rangeu32", (0, 100, 5))
+            .unit("rangeu32", Unit::Percent)
+            .duration_with_limits(
+                "duration",
+                core::time::Duration::from_secs(1),
+                core::time::Duration::ZERO,
+                core::time::Duration::from_secs(60),
+            )
+            .list_with_length("list", ParameterKind::Bool { default: true }, 1, 4)
+            .serialize_data();
+
+        let schema = |name| parameter_schema(parameters.get(name).unwrap());
+
+        assert_eq!(
+            schema("u8"),
+            json!({ "type": "integer", "format": "uint8", "default": 3 })
+        );
+        assert_eq!(
+            schema("f32"),
+            json!({
+                "type": "number",
+                "format": "float",
+                "minimum": 0.1,
+                "maximum": 1.0,
+                "multipleOf": 0.1,
+                "default": 0.5,
+            })
+        );
+        assert_eq!(
+            schema("f64"),
+            json!({ "type": "number", "format": "double", "default": 1.5 })
+        );
+        assert_eq!(
+            schema("rangeu32"),
+            json!({
+                "type": "integer",
+                "format": "uint32",
+                "minimum": 0,
+                "maximum": 100,
+                "multipleOf": 5,
+                "x-tosca-unit": "%",
+                "default": 0,
+            })
+        );
+        assert_eq!(
+            schema("duration"),
+            json!({
+                "type": "string",
+                "format": "duration",
+                "pattern": "^[0-9]+(ms|s|m|h)$",
+                "x-tosca-minimum": "0ms",
+                "x-tosca-maximum": "60000ms",
+                "default": "1000ms",
+            })
+        );
+        assert_eq!(
+            schema("list"),
+            json!({
+                "type": "array",
+                "items": { "type": "boolean", "default": true },
+                "minItems": 1,
+                "maxItems": 4,
+                "default": [true],
+            })
+        );
+    }
+}
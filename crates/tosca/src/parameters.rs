@@ -2,7 +2,11 @@
 #![allow(clippy::trivially_copy_pass_by_ref)]
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::time::Duration;
 
 use hashbrown::DefaultHashBuilder;
 
@@ -10,7 +14,14 @@ use indexmap::map::{IndexMap, IntoIter, Iter, Keys};
 
 use serde::{Deserialize, Serialize};
 
+use serde_json::{Map, Value};
+
 use crate::macros::map;
+use crate::units::Unit;
+use crate::values::{Color, TimeOfDay, Timestamp, duration_text, parse_duration};
+
+// The longest duration representable in the textual format.
+const DURATION_MAX: Duration = Duration::from_millis(u64::MAX);
 
 fn is_u8_max(value: &u8) -> bool {
     *value == u8::MAX
@@ -64,6 +75,91 @@ fn u64_max() -> u64 {
     u64::MAX
 }
 
+fn is_i8_max(value: &i8) -> bool {
+    *value == i8::MAX
+}
+
+fn is_i8_min(value: &i8) -> bool {
+    *value == i8::MIN
+}
+
+#[cfg(feature = "deserialize")]
+fn i8_min() -> i8 {
+    i8::MIN
+}
+
+#[cfg(feature = "deserialize")]
+fn i8_max() -> i8 {
+    i8::MAX
+}
+
+fn is_i16_max(value: &i16) -> bool {
+    *value == i16::MAX
+}
+
+fn is_i16_min(value: &i16) -> bool {
+    *value == i16::MIN
+}
+
+#[cfg(feature = "deserialize")]
+fn i16_min() -> i16 {
+    i16::MIN
+}
+
+#[cfg(feature = "deserialize")]
+fn i16_max() -> i16 {
+    i16::MAX
+}
+
+fn is_i32_max(value: &i32) -> bool {
+    *value == i32::MAX
+}
+
+fn is_i32_min(value: &i32) -> bool {
+    *value == i32::MIN
+}
+
+#[cfg(feature = "deserialize")]
+fn i32_min() -> i32 {
+    i32::MIN
+}
+
+#[cfg(feature = "deserialize")]
+fn i32_max() -> i32 {
+    i32::MAX
+}
+
+fn is_i64_max(value: &i64) -> bool {
+    *value == i64::MAX
+}
+
+fn is_i64_min(value: &i64) -> bool {
+    *value == i64::MIN
+}
+
+#[cfg(feature = "deserialize")]
+fn i64_min() -> i64 {
+    i64::MIN
+}
+
+#[cfg(feature = "deserialize")]
+fn i64_max() -> i64 {
+    i64::MAX
+}
+
+fn is_duration_max(value: &Duration) -> bool {
+    *value == DURATION_MAX
+}
+
+fn is_duration_zero(value: &Duration) -> bool {
+    value.is_zero()
+}
+
+#[cfg(feature = "deserialize")]
+fn duration_max() -> Duration {
+    DURATION_MAX
+}
+
 fn is_f32_max(value: &f32) -> bool {
     (f32::MAX - (*value).abs()) == 0.0
 }
@@ -123,6 +219,9 @@ pub enum ParameterKind {
         #[serde(skip_serializing_if = "is_u8_min")]
         #[serde(default = "u8_max")]
         max: u8,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// An [`u16`] value.
     U16 {
@@ -137,6 +236,9 @@ pub enum ParameterKind {
         #[serde(skip_serializing_if = "is_u16_min")]
         #[serde(default = "u16_max")]
         max: u16,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// An [`u32`] value.
     U32 {
@@ -151,6 +253,9 @@ pub enum ParameterKind {
         #[serde(skip_serializing_if = "is_u32_min")]
         #[serde(default = "u32_max")]
         max: u32,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// An [`u64`] value.
     U64 {
@@ -165,6 +270,77 @@ pub enum ParameterKind {
         #[serde(skip_serializing_if = "is_u64_min")]
         #[serde(default = "u64_max")]
         max: u64,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
+    },
+    /// An [`i8`] value.
+    I8 {
+        /// The initial [`i8`] value, also used as the default
+        /// when the parameter is missing.
+        default: i8,
+        /// The minimum allowed [`i8`] value.
+        #[serde(skip_serializing_if = "is_i8_max")]
+        #[serde(default = "i8_min")]
+        min: i8,
+        /// The maximum allowed [`i8`] value.
+        #[serde(skip_serializing_if = "is_i8_min")]
+        #[serde(default = "i8_max")]
+        max: i8,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
+    },
+    /// An [`i16`] value.
+    I16 {
+        /// The initial [`i16`] value, also used as the default
+        /// when the parameter is missing.
+        default: i16,
+        /// The minimum allowed [`i16`] value.
+        #[serde(skip_serializing_if = "is_i16_max")]
+        #[serde(default = "i16_min")]
+        min: i16,
+        /// The maximum allowed [`i16`] value.
+        #[serde(skip_serializing_if = "is_i16_min")]
+        #[serde(default = "i16_max")]
+        max: i16,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
+    },
+    /// An [`i32`] value.
+    I32 {
+        /// The initial [`i32`] value, also used as the default
+        /// when the parameter is missing.
+        default: i32,
+        /// The minimum allowed [`i32`] value.
+        #[serde(skip_serializing_if = "is_i32_max")]
+        #[serde(default = "i32_min")]
+        min: i32,
+        /// The maximum allowed [`i32`] value.
+        #[serde(skip_serializing_if = "is_i32_min")]
+        #[serde(default = "i32_max")]
+        max: i32,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
+    },
+    /// An [`i64`] value.
+    I64 {
+        /// The initial [`i64`] value, also used as the default
+        /// when the parameter is missing.
+        default: i64,
+        /// The minimum allowed [`i64`] value.
+        #[serde(skip_serializing_if = "is_i64_max")]
+        #[serde(default = "i64_min")]
+        min: i64,
+        /// The maximum allowed [`i64`] value.
+        #[serde(skip_serializing_if = "is_i64_min")]
+        #[serde(default = "i64_max")]
+        max: i64,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// A [`f32`] value.
     F32 {
@@ -183,6 +359,9 @@ pub enum ParameterKind {
         #[serde(skip_serializing_if = "is_f32_min")]
         #[serde(default)]
         step: f32,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// A [`f64`] value.
     F64 {
@@ -201,6 +380,9 @@ pub enum ParameterKind {
         #[serde(skip_serializing_if = "is_f64_min")]
         #[serde(default)]
         step: f64,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// A range of [`u32`] values.
     RangeU32 {
@@ -212,6 +394,9 @@ pub enum ParameterKind {
         step: u32,
         /// The default [`u32`] value for the range.
         default: u32,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// A range of [`u64`] values.
     RangeU64 {
@@ -223,6 +408,39 @@ pub enum ParameterKind {
         step: u64,
         /// The default [`u64`] value for the range.
         default: u64,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
+    },
+    /// A range of [`i32`] values.
+    RangeI32 {
+        /// The minimum allowed [`i32`] value.
+        min: i32,
+        /// The maximum allowed [`i32`] value.
+        max: i32,
+        /// The [`i32`] step between consecutive allowed values in the range.
+        /// Always a positive value.
+        step: i32,
+        /// The default [`i32`] value for the range.
+        default: i32,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
+    },
+    /// A range of [`i64`] values.
+    RangeI64 {
+        /// The minimum allowed [`i64`] value.
+        min: i64,
+        /// The maximum allowed [`i64`] value.
+        max: i64,
+        /// The [`i64`] step between consecutive allowed values in the range.
+        /// Always a positive value.
+        step: i64,
+        /// The default [`i64`] value for the range.
+        default: i64,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// A range of [`f64`] values.
     RangeF64 {
@@ -235,12 +453,84 @@ pub enum ParameterKind {
         step: f64,
         /// The default [`f64`] value for the range.
         default: f64,
+        /// The measurement unit of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
     },
     /// A sequence of characters.
     CharsSequence {
         /// A sequence of characters representing the default value.
         default: Cow<'static, str>,
     },
+    /// A sequence of characters chosen among a fixed set of options.
+    Choice {
+        /// The option used as default value.
+        default: Cow<'static, str>,
+        /// The allowed options.
+        options: Vec<Cow<'static, str>>,
+    },
+    /// A color.
+    Color {
+        /// The default color.
+        default: Color,
+    },
+    /// A color temperature expressed in Kelvin.
+    ColorTemperature {
+        /// The default color temperature.
+        default: u16,
+        /// The minimum allowed color temperature.
+        #[serde(skip_serializing_if = "is_u16_max")]
+        #[serde(default)]
+        min: u16,
+        /// The maximum allowed color temperature.
+        #[serde(skip_serializing_if = "is_u16_min")]
+        #[serde(default = "u16_max")]
+        max: u16,
+    },
+    /// A duration, with a resolution of one millisecond.
+    Duration {
+        /// The default duration.
+        #[serde(with = "duration_text")]
+        default: Duration,
+        /// The minimum allowed duration.
+        #[serde(with = "duration_text")]
+        #[serde(skip_serializing_if = "is_duration_max")]
+        #[serde(default)]
+        min: Duration,
+        /// The maximum allowed duration.
+        #[serde(with = "duration_text")]
+        #[serde(skip_serializing_if = "is_duration_zero")]
+        #[serde(default = "duration_max")]
+        max: Duration,
+    },
+    /// A wall-clock time of the day.
+    TimeOfDay {
+        /// The default time of the day.
+        default: TimeOfDay,
+    },
+    /// A point in time.
+    Timestamp {
+        /// The default point in time.
+        default: Timestamp,
+    },
+    /// A list of values of the same kind.
+    List {
+        /// The kind of each element of the list.
+        kind: Box<ParameterKind>,
+        /// The minimum number of elements.
+        #[serde(skip_serializing_if = "is_u32_min")]
+        #[serde(default)]
+        min_length: u32,
+        /// The maximum number of elements.
+        #[serde(skip_serializing_if = "is_u32_max")]
+        #[serde(default = "u32_max")]
+        max_length: u32,
+    },
+    /// An object made of named parameters.
+    Object {
+        /// The parameters contained in the object.
+        parameters: ParametersData,
+    },
 }
 
 impl ParameterKind {
@@ -255,10 +545,24 @@ impl ParameterKind {
             Self::RangeU32 { .. } => "RangeU32",
             Self::U64 { .. } => "U64",
             Self::RangeU64 { .. } => "RangeU64",
+            Self::I8 { .. } => "I8",
+            Self::I16 { .. } => "I16",
+            Self::I32 { .. } => "I32",
+            Self::I64 { .. } => "I64",
+            Self::RangeI32 { .. } => "RangeI32",
+            Self::RangeI64 { .. } => "RangeI64",
             Self::F32 { .. } => "F32",
             Self::F64 { .. } => "F64",
             Self::RangeF64 { .. } => "RangeF64",
             Self::CharsSequence { .. } => "CharsSequence",
+            Self::Choice { .. } => "Choice",
+            Self::Color { .. } => "Color",
+            Self::ColorTemperature { .. } => "ColorTemperature",
+            Self::Duration { .. } => "Duration",
+            Self::TimeOfDay { .. } => "TimeOfDay",
+            Self::Timestamp { .. } => "Timestamp",
+            Self::List { .. } => "List",
+            Self::Object { .. } => "Object",
         }
     }
 
@@ -268,16 +572,363 @@ impl ParameterKind {
         match self {
             Self::Bool { .. } => "bool",
             Self::U8 { .. } => "u8",
-            Self::U16 { .. } => "u16",
+            Self::U16 { .. } | Self::ColorTemperature { .. } => "u16",
             Self::U32 { .. } | Self::RangeU32 { .. } => "u32",
             Self::U64 { .. } | Self::RangeU64 { .. } => "u64",
+            Self::I8 { .. } => "i8",
+            Self::I16 { .. } => "i16",
+            Self::I32 { .. } | Self::RangeI32 { .. } => "i32",
+            Self::I64 { .. } | Self::RangeI64 { .. } => "i64",
             Self::F32 { .. } => "f32",
             Self::F64 { .. } | Self::RangeF64 { .. } => "f64",
-            Self::CharsSequence { .. } => "String",
+            Self::CharsSequence { .. } | Self::Choice { .. } => "String",
+            Self::Color { .. } => "Color",
+            Self::Duration { .. } => "Duration",
+            Self::TimeOfDay { .. } => "TimeOfDay",
+            Self::Timestamp { .. } => "Timestamp",
+            Self::List { .. } => "List",
+            Self::Object { .. } => "Object",
+        }
+    }
+
+    /// Returns the measurement [`Unit`] of the [`ParameterKind`].
+    ///
+    /// Only numeric kinds can have a measurement unit.
+    #[must_use]
+    pub const fn unit(&self) -> Option<&Unit> {
+        match self {
+            Self::U8 { unit, .. }
+            | Self::U16 { unit, .. }
+            | Self::U32 { unit, .. }
+            | Self::U64 { unit, .. }
+            | Self::I8 { unit, .. }
+            | Self::I16 { unit, .. }
+            | Self::I32 { unit, .. }
+            | Self::I64 { unit, .. }
+            | Self::F32 { unit, .. }
+            | Self::F64 { unit, .. }
+            | Self::RangeU32 { unit, .. }
+            | Self::RangeU64 { unit, .. }
+            | Self::RangeI32 { unit, .. }
+            | Self::RangeI64 { unit, .. }
+            | Self::RangeF64 { unit, .. } => unit.as_ref(),
+            _ => None,
+        }
+    }
+
+    // Returns the measurement unit slot of a numeric kind.
+    const fn unit_mut(&mut self) -> Option<&mut Option<Unit>> {
+        match self {
+            Self::U8 { unit, .. }
+            | Self::U16 { unit, .. }
+            | Self::U32 { unit, .. }
+            | Self::U64 { unit, .. }
+            | Self::I8 { unit, .. }
+            | Self::I16 { unit, .. }
+            | Self::I32 { unit, .. }
+            | Self::I64 { unit, .. }
+            | Self::F32 { unit, .. }
+            | Self::F64 { unit, .. }
+            | Self::RangeU32 { unit, .. }
+            | Self::RangeU64 { unit, .. }
+            | Self::RangeI32 { unit, .. }
+            | Self::RangeI64 { unit, .. }
+            | Self::RangeF64 { unit, .. } => Some(unit),
+            _ => None,
+        }
+    }
+
+    /// Validates a [`ParameterValue`] against the type and the limits
+    /// of the [`ParameterKind`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`ParameterViolation`] found.
+    pub fn validate(&self, value: &ParameterValue) -> Result<(), ParameterViolation> {
+        match (self, value) {
+            (Self::U8 { min, max, .. }, ParameterValue::U8(v)) => {
+                check_integer(*v, *min, *max, None, ParameterValue::U8)
+            }
+            (
+                Self::U16 { min, max, .. } | Self::ColorTemperature { min, max, .. },
+                ParameterValue::U16(v),
+            ) => check_integer(*v, *min, *max, None, ParameterValue::U16),
+            (Self::U32 { min, max, .. }, ParameterValue::U32(v)) => {
+                check_integer(*v, *min, *max, None, ParameterValue::U32)
+            }
+            (Self::U64 { min, max, .. }, ParameterValue::U64(v)) => {
+                check_integer(*v, *min, *max, None, ParameterValue::U64)
+            }
+            (Self::RangeU32 { min, max, step, .. }, ParameterValue::U32(v)) => {
+                check_integer(*v, *min, *max, Some(*step), ParameterValue::U32)
+            }
+            (Self::RangeU64 { min, max, step, .. }, ParameterValue::U64(v)) => {
+                check_integer(*v, *min, *max, Some(*step), ParameterValue::U64)
+            }
+            (Self::I8 { min, max, .. }, ParameterValue::I8(v)) => {
+                check_integer(*v, *min, *max, None, ParameterValue::I8)
+            }
+            (Self::I16 { min, max, .. }, ParameterValue::I16(v)) => {
+                check_integer(*v, *min, *max, None, ParameterValue::I16)
+            }
+            (Self::I32 { min, max, .. }, ParameterValue::I32(v)) => {
+                check_integer(*v, *min, *max, None, ParameterValue::I32)
+            }
+            (Self::I64 { min, max, .. }, ParameterValue::I64(v)) => {
+                check_integer(*v, *min, *max, None, ParameterValue::I64)
+            }
+            (Self::RangeI32 { min, max, step, .. }, ParameterValue::I32(v)) => {
+                check_integer(*v, *min, *max, Some(*step), ParameterValue::I32)
+            }
+            (Self::RangeI64 { min, max, step, .. }, ParameterValue::I64(v)) => {
+                check_integer(*v, *min, *max, Some(*step), ParameterValue::I64)
+            }
+            (Self::F32 { min, max, step, .. }, ParameterValue::F32(v)) => check_float(
+                f64::from(*v),
+                f64::from(*min),
+                f64::from(*max),
+                f64::from(*step),
+                f32_value,
+            ),
+            (
+                Self::F64 { min, max, step, .. } | Self::RangeF64 { min, max, step, .. },
+                ParameterValue::F64(v),
+            ) => check_float(*v, *min, *max, *step, ParameterValue::F64),
+            (Self::Choice { options, .. }, ParameterValue::CharsSequence(v)) => {
+                if options.contains(v) {
+                    Ok(())
+                } else {
+                    Err(ParameterViolation::Choice(options.clone()))
+                }
+            }
+            (Self::Duration { min, max, .. }, ParameterValue::Duration(v)) => {
+                check_duration(*v, *min, *max)
+            }
+            (
+                Self::List {
+                    kind,
+                    min_length,
+                    max_length,
+                },
+                ParameterValue::List(values),
+            ) => check_list(kind, *min_length, *max_length, values),
+            (Self::Object { parameters }, ParameterValue::Object(values)) => {
+                check_object(parameters, values)
+            }
+            _ if value.match_kind(self) => Ok(()),
+            _ => Err(ParameterViolation::Type {
+                expected: self.as_type(),
+                found: value.as_type(),
+            }),
+        }
+    }
+}
+
+/// A [`ParameterKind`] constraint violated by a [`ParameterValue`].
+#[derive(Debug, PartialEq)]
+pub enum ParameterViolation {
+    /// The value type differs from the parameter type.
+    Type {
+        /// The parameter type.
+        expected: &'static str,
+        /// The value type.
+        found: &'static str,
+    },
+    /// The value is lower than the minimum allowed value.
+    Min(ParameterValue),
+    /// The value is greater than the maximum allowed value.
+    Max(ParameterValue),
+    /// The value is not a multiple of the step, starting from the
+    /// minimum allowed value.
+    Step(ParameterValue),
+    /// The value is not among the allowed options.
+    Choice(Vec<Cow<'static, str>>),
+    /// The list has fewer elements than the minimum allowed number.
+    MinLength(u32),
+    /// The list has more elements than the maximum allowed number.
+    MaxLength(u32),
+    /// An element of the list is not valid.
+    Element {
+        /// The position of the element in the list.
+        index: usize,
+        /// The violation of the element.
+        violation: Box<ParameterViolation>,
+    },
+    /// A field of the object is not valid.
+    Field {
+        /// The field name.
+        name: String,
+        /// The violation of the field.
+        violation: Box<ParameterViolation>,
+    },
+    /// A field of the object is missing.
+    MissingField(String),
+    /// The object contains a field which has not been declared.
+    UnknownField(String),
+}
+
+impl core::fmt::Display for ParameterViolation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Type { expected, found } => {
+                write!(f, "found type `{found}`, expected type `{expected}`")
+            }
+            Self::Min(min) => write!(f, "value lower than the minimum `{min}`"),
+            Self::Max(max) => write!(f, "value greater than the maximum `{max}`"),
+            Self::Step(step) => write!(f, "value not a multiple of the step `{step}`"),
+            Self::Choice(options) => {
+                write!(f, "value not among the allowed options")?;
+                for (index, option) in options.iter().enumerate() {
+                    let separator = if index == 0 { ":" } else { "," };
+                    write!(f, "{separator} `{option}`")?;
+                }
+                Ok(())
+            }
+            Self::MinLength(min) => write!(f, "fewer elements than the minimum `{min}`"),
+            Self::MaxLength(max) => write!(f, "more elements than the maximum `{max}`"),
+            Self::Element { index, violation } => write!(f, "element `{index}`: {violation}"),
+            Self::Field { name, violation } => write!(f, "field `{name}`: {violation}"),
+            Self::MissingField(name) => write!(f, "missing field `{name}`"),
+            Self::UnknownField(name) => write!(f, "unknown field `{name}`"),
+        }
+    }
+}
+
+// Checks the limits of a duration.
+//
+// When the minimum is greater than the maximum, the duration has no limits.
+fn check_duration(value: Duration, min: Duration, max: Duration) -> Result<(), ParameterViolation> {
+    if min > max {
+        Ok(())
+    } else if value < min {
+        Err(ParameterViolation::Min(ParameterValue::Duration(min)))
+    } else if value > max {
+        Err(ParameterViolation::Max(ParameterValue::Duration(max)))
+    } else {
+        Ok(())
+    }
+}
+
+// Checks the length of a list and each of its elements.
+fn check_list(
+    kind: &ParameterKind,
+    min_length: u32,
+    max_length: u32,
+    values: &[ParameterValue],
+) -> Result<(), ParameterViolation> {
+    let length = u32::try_from(values.len()).unwrap_or(u32::MAX);
+    if length < min_length {
+        return Err(ParameterViolation::MinLength(min_length));
+    }
+    if length > max_length {
+        return Err(ParameterViolation::MaxLength(max_length));
+    }
+    for (index, value) in values.iter().enumerate() {
+        kind.validate(value)
+            .map_err(|violation| ParameterViolation::Element {
+                index,
+                violation: Box::new(violation),
+            })?;
+    }
+    Ok(())
+}
+
+// Checks that an object contains exactly the declared fields, and that
+// each of them is valid.
+fn check_object(
+    parameters: &ParametersData,
+    values: &ParametersValues,
+) -> Result<(), ParameterViolation> {
+    if let Some((name, _)) = values
+        .iter()
+        .find(|(name, _)| parameters.get(name).is_none())
+    {
+        return Err(ParameterViolation::UnknownField(String::from(
+            name.as_ref(),
+        )));
+    }
+    for (name, kind) in parameters {
+        let value = values
+            .get(name.as_str())
+            .ok_or_else(|| ParameterViolation::MissingField(name.clone()))?;
+        kind.validate(value)
+            .map_err(|violation| ParameterViolation::Field {
+                name: name.clone(),
+                violation: Box::new(violation),
+            })?;
+    }
+    Ok(())
+}
+
+// Checks the limits of an integer value.
+//
+// When the minimum is greater than the maximum, the value has no limits.
+fn check_integer<T>(
+    value: T,
+    min: T,
+    max: T,
+    step: Option<T>,
+    into_value: fn(T) -> ParameterValue,
+) -> Result<(), ParameterViolation>
+where
+    T: Copy + PartialOrd + Into<i128>,
+{
+    if min > max {
+        return Ok(());
+    }
+    if value < min {
+        return Err(ParameterViolation::Min(into_value(min)));
+    }
+    if value > max {
+        return Err(ParameterViolation::Max(into_value(max)));
+    }
+    match step {
+        // Widen the values to avoid overflowing with signed limits.
+        Some(step) if step.into() != 0 && (value.into() - min.into()) % step.into() != 0 => {
+            Err(ParameterViolation::Step(into_value(step)))
+        }
+        _ => Ok(()),
+    }
+}
+
+// Checks the limits of a floating-point value.
+//
+// When the minimum is greater than the maximum, the value has no limits,
+// and the step is counted from zero.
+fn check_float(
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    into_value: fn(f64) -> ParameterValue,
+) -> Result<(), ParameterViolation> {
+    let limited = min <= max;
+    if limited && value < min {
+        return Err(ParameterViolation::Min(into_value(min)));
+    }
+    if limited && value > max {
+        return Err(ParameterViolation::Max(into_value(max)));
+    }
+    if step > 0. {
+        let start = if limited { min } else { 0. };
+        let fraction = ((value - start) / step % 1.).abs();
+        // Tolerate the rounding errors of floating-point values.
+        if fraction.min(1. - fraction) > STEP_TOLERANCE {
+            return Err(ParameterViolation::Step(into_value(step)));
         }
     }
+    Ok(())
+}
+
+// Bounds of `f32` parameters are always representable as `f32` values.
+#[allow(clippy::cast_possible_truncation)]
+const fn f32_value(bound: f64) -> ParameterValue {
+    ParameterValue::F32(bound as f32)
 }
 
+// Tolerance on the number of steps of a floating-point value.
+pub(crate) const STEP_TOLERANCE: f64 = 1e-4;
+
 /// The decimal precision of a floating-point value.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
@@ -312,6 +963,73 @@ impl DecimalPrecision {
     }
 }
 
+/// The shape of a parameter.
+///
+/// A shape groups all the [`ParameterKind`]s which represent the same
+/// kind of value, regardless of their width, limits and defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParameterShape {
+    /// A [`bool`] value.
+    Bool,
+    /// An integer or a floating point value, including ranges.
+    Number,
+    /// A sequence of characters, including a choice among options.
+    Text,
+    /// A color.
+    Color,
+    /// A color temperature.
+    ColorTemperature,
+    /// A duration.
+    Duration,
+    /// A wall-clock time of the day.
+    TimeOfDay,
+    /// A point in time.
+    Timestamp,
+    /// A list of values.
+    List,
+    /// An object.
+    Object,
+}
+
+impl ParameterShape {
+    /// Returns the [`ParameterShape`] of a [`ParameterKind`].
+    #[must_use]
+    pub const fn from_parameter_kind(parameter_kind: &ParameterKind) -> Self {
+        match parameter_kind {
+            ParameterKind::Bool { .. } => Self::Bool,
+            ParameterKind::U8 { .. }
+            | ParameterKind::U16 { .. }
+            | ParameterKind::U32 { .. }
+            | ParameterKind::U64 { .. }
+            | ParameterKind::I8 { .. }
+            | ParameterKind::I16 { .. }
+            | ParameterKind::I32 { .. }
+            | ParameterKind::I64 { .. }
+            | ParameterKind::F32 { .. }
+            | ParameterKind::F64 { .. }
+            | ParameterKind::RangeU32 { .. }
+            | ParameterKind::RangeU64 { .. }
+            | ParameterKind::RangeI32 { .. }
+            | ParameterKind::RangeI64 { .. }
+            | ParameterKind::RangeF64 { .. } => Self::Number,
+            ParameterKind::CharsSequence { .. } | ParameterKind::Choice { .. } => Self::Text,
+            ParameterKind::Color { .. } => Self::Color,
+            ParameterKind::ColorTemperature { .. } => Self::ColorTemperature,
+            ParameterKind::Duration { .. } => Self::Duration,
+            ParameterKind::TimeOfDay { .. } => Self::TimeOfDay,
+            ParameterKind::Timestamp { .. } => Self::Timestamp,
+            ParameterKind::List { .. } => Self::List,
+            ParameterKind::Object { .. } => Self::Object,
+        }
+    }
+
+    /// Checks whether a [`ParameterKind`] has this [`ParameterShape`].
+    #[must_use]
+    pub fn matches(self, parameter_kind: &ParameterKind) -> bool {
+        Self::from_parameter_kind(parameter_kind) == self
+    }
+}
+
 map! {
   /// A map associating each parameter name with its
   /// corresponding [`ParameterKind`].
@@ -328,6 +1046,27 @@ impl ParametersData {
     pub fn get(&self, key: &str) -> Option<&ParameterKind> {
         self.0.get(key)
     }
+
+    /// Decodes a `JSON` object into [`ParametersValues`], interpreting each
+    /// field according to its [`ParameterKind`].
+    ///
+    /// Returns [`None`] when the `JSON` value is not an object, or when one
+    /// of its fields does not represent the declared kind.
+    #[must_use]
+    pub fn values_from_json(&self, value: &Value) -> Option<ParametersValues<'static>> {
+        object_from_json(self, value.as_object()?)
+    }
+
+    /// Validates [`ParametersValues`] which must contain exactly
+    /// the declared parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`ParameterViolation`] found.
+    #[inline]
+    pub fn validate(&self, values: &ParametersValues) -> Result<(), ParameterViolation> {
+        check_object(self, values)
+    }
 }
 
 /// Route parameters.
@@ -365,6 +1104,7 @@ impl Parameters {
                 default,
                 min: u8::MAX,
                 max: u8::MIN,
+                unit: None,
             },
         )
     }
@@ -373,7 +1113,15 @@ impl Parameters {
     #[must_use]
     #[inline]
     pub fn u8_with_limits(self, name: &'static str, default: u8, min: u8, max: u8) -> Self {
-        self.create_parameter(name, ParameterKind::U8 { default, min, max })
+        self.create_parameter(
+            name,
+            ParameterKind::U8 {
+                default,
+                min,
+                max,
+                unit: None,
+            },
+        )
     }
 
     /// Adds an [`u16`] parameter.
@@ -386,6 +1134,7 @@ impl Parameters {
                 default,
                 min: u16::MAX,
                 max: u16::MIN,
+                unit: None,
             },
         )
     }
@@ -394,7 +1143,15 @@ impl Parameters {
     #[must_use]
     #[inline]
     pub fn u16_with_limits(self, name: &'static str, default: u16, min: u16, max: u16) -> Self {
-        self.create_parameter(name, ParameterKind::U16 { default, min, max })
+        self.create_parameter(
+            name,
+            ParameterKind::U16 {
+                default,
+                min,
+                max,
+                unit: None,
+            },
+        )
     }
 
     /// Adds an [`u32`] parameter.
@@ -407,6 +1164,7 @@ impl Parameters {
                 default,
                 min: u32::MAX,
                 max: u32::MIN,
+                unit: None,
             },
         )
     }
@@ -415,7 +1173,15 @@ impl Parameters {
     #[must_use]
     #[inline]
     pub fn u32_with_limits(self, name: &'static str, default: u32, min: u32, max: u32) -> Self {
-        self.create_parameter(name, ParameterKind::U32 { default, min, max })
+        self.create_parameter(
+            name,
+            ParameterKind::U32 {
+                default,
+                min,
+                max,
+                unit: None,
+            },
+        )
     }
 
     /// Adds an [`u64`] parameter.
@@ -428,6 +1194,7 @@ impl Parameters {
                 default,
                 min: u64::MAX,
                 max: u64::MIN,
+                unit: None,
             },
         )
     }
@@ -436,99 +1203,231 @@ impl Parameters {
     #[must_use]
     #[inline]
     pub fn u64_with_limits(self, name: &'static str, default: u64, min: u64, max: u64) -> Self {
-        self.create_parameter(name, ParameterKind::U64 { default, min, max })
+        self.create_parameter(
+            name,
+            ParameterKind::U64 {
+                default,
+                min,
+                max,
+                unit: None,
+            },
+        )
     }
 
-    /// Adds a [`f32`] parameter.
+    /// Adds an [`i8`] parameter.
     #[must_use]
     #[inline]
-    pub fn f32(self, name: &'static str, default: f32) -> Self {
+    pub fn i8(self, name: &'static str, default: i8) -> Self {
         self.create_parameter(
             name,
-            ParameterKind::F32 {
+            ParameterKind::I8 {
                 default,
-                min: f32::MAX,
-                max: f32::MIN,
-                step: 0.,
+                min: i8::MAX,
+                max: i8::MIN,
+                unit: None,
             },
         )
     }
 
-    /// Adds a [`f32`] parameter with specified limits.
+    /// Adds an [`i8`] parameter with specified limits.
     #[must_use]
     #[inline]
-    pub fn f32_with_limits(
-        self,
-        name: &'static str,
-        default: f32,
-        min: f32,
-        max: f32,
-        decimal_precision: DecimalPrecision,
-    ) -> Self {
+    pub fn i8_with_limits(self, name: &'static str, default: i8, min: i8, max: i8) -> Self {
         self.create_parameter(
             name,
-            ParameterKind::F32 {
+            ParameterKind::I8 {
                 default,
                 min,
                 max,
-                step: decimal_precision.to_f32(),
+                unit: None,
             },
         )
     }
 
-    /// Adds a [`f64`] parameter.
+    /// Adds an [`i16`] parameter.
     #[must_use]
     #[inline]
-    pub fn f64(self, name: &'static str, default: f64) -> Self {
+    pub fn i16(self, name: &'static str, default: i16) -> Self {
         self.create_parameter(
             name,
-            ParameterKind::F64 {
+            ParameterKind::I16 {
                 default,
-                min: f64::MAX,
-                max: f64::MIN,
-                step: 0.,
+                min: i16::MAX,
+                max: i16::MIN,
+                unit: None,
             },
         )
     }
 
-    /// Adds a [`f64`] parameter with specified limits.
+    /// Adds an [`i16`] parameter with specified limits.
     #[must_use]
     #[inline]
-    pub fn f64_with_limits(
-        self,
-        name: &'static str,
-        default: f64,
-        min: f64,
-        max: f64,
-        decimal_precision: DecimalPrecision,
-    ) -> Self {
+    pub fn i16_with_limits(self, name: &'static str, default: i16, min: i16, max: i16) -> Self {
         self.create_parameter(
             name,
-            ParameterKind::F64 {
+            ParameterKind::I16 {
                 default,
                 min,
                 max,
-                step: decimal_precision.to_f64(),
+                unit: None,
             },
         )
     }
 
-    /// Adds an [`u32`] range without a default value.
+    /// Adds an [`i32`] parameter.
     #[must_use]
     #[inline]
-    pub fn rangeu32(self, name: &'static str, range: (u32, u32, u32)) -> Self {
-        self.rangeu32_with_default(name, range, 0)
+    pub fn i32(self, name: &'static str, default: i32) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::I32 {
+                default,
+                min: i32::MAX,
+                max: i32::MIN,
+                unit: None,
+            },
+        )
     }
 
-    /// Adds an [`u32`] range with a default value.
+    /// Adds an [`i32`] parameter with specified limits.
     #[must_use]
     #[inline]
-    pub fn rangeu32_with_default(
-        self,
-        name: &'static str,
-        range: (u32, u32, u32),
-        default: u32,
-    ) -> Self {
+    pub fn i32_with_limits(self, name: &'static str, default: i32, min: i32, max: i32) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::I32 {
+                default,
+                min,
+                max,
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds an [`i64`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn i64(self, name: &'static str, default: i64) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::I64 {
+                default,
+                min: i64::MAX,
+                max: i64::MIN,
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds an [`i64`] parameter with specified limits.
+    #[must_use]
+    #[inline]
+    pub fn i64_with_limits(self, name: &'static str, default: i64, min: i64, max: i64) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::I64 {
+                default,
+                min,
+                max,
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds a [`f32`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn f32(self, name: &'static str, default: f32) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::F32 {
+                default,
+                min: f32::MAX,
+                max: f32::MIN,
+                step: 0.,
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds a [`f32`] parameter with specified limits.
+    #[must_use]
+    #[inline]
+    pub fn f32_with_limits(
+        self,
+        name: &'static str,
+        default: f32,
+        min: f32,
+        max: f32,
+        decimal_precision: DecimalPrecision,
+    ) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::F32 {
+                default,
+                min,
+                max,
+                step: decimal_precision.to_f32(),
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds a [`f64`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn f64(self, name: &'static str, default: f64) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::F64 {
+                default,
+                min: f64::MAX,
+                max: f64::MIN,
+                step: 0.,
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds a [`f64`] parameter with specified limits.
+    #[must_use]
+    #[inline]
+    pub fn f64_with_limits(
+        self,
+        name: &'static str,
+        default: f64,
+        min: f64,
+        max: f64,
+        decimal_precision: DecimalPrecision,
+    ) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::F64 {
+                default,
+                min,
+                max,
+                step: decimal_precision.to_f64(),
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds an [`u32`] range without a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangeu32(self, name: &'static str, range: (u32, u32, u32)) -> Self {
+        self.rangeu32_with_default(name, range, 0)
+    }
+
+    /// Adds an [`u32`] range with a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangeu32_with_default(
+        self,
+        name: &'static str,
+        range: (u32, u32, u32),
+        default: u32,
+    ) -> Self {
         self.create_parameter(
             name,
             ParameterKind::RangeU32 {
@@ -536,6 +1435,7 @@ impl Parameters {
                 max: range.1,
                 step: range.2,
                 default,
+                unit: None,
             },
         )
     }
@@ -563,6 +1463,63 @@ impl Parameters {
                 max: range.1,
                 step: range.2,
                 default,
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds an [`i32`] range without a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangei32(self, name: &'static str, range: (i32, i32, i32)) -> Self {
+        self.rangei32_with_default(name, range, 0)
+    }
+
+    /// Adds an [`i32`] range with a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangei32_with_default(
+        self,
+        name: &'static str,
+        range: (i32, i32, i32),
+        default: i32,
+    ) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::RangeI32 {
+                min: range.0,
+                max: range.1,
+                step: range.2.saturating_abs(),
+                default,
+                unit: None,
+            },
+        )
+    }
+
+    /// Adds an [`i64`] range without a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangei64(self, name: &'static str, range: (i64, i64, i64)) -> Self {
+        self.rangei64_with_default(name, range, 0)
+    }
+
+    /// Adds an [`i64`] range with a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangei64_with_default(
+        self,
+        name: &'static str,
+        range: (i64, i64, i64),
+        default: i64,
+    ) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::RangeI64 {
+                min: range.0,
+                max: range.1,
+                step: range.2.saturating_abs(),
+                default,
+                unit: None,
             },
         )
     }
@@ -590,6 +1547,7 @@ impl Parameters {
                 max: range.1,
                 step: range.2.abs(),
                 default,
+                unit: None,
             },
         )
     }
@@ -610,6 +1568,174 @@ impl Parameters {
         )
     }
 
+    /// Adds a choice among a fixed set of options.
+    ///
+    /// The first option is used as default value.
+    #[must_use]
+    #[inline]
+    pub fn choice<I, O>(self, name: &'static str, options: I) -> Self
+    where
+        I: IntoIterator<Item = O>,
+        O: Into<Cow<'static, str>>,
+    {
+        let options: Vec<Cow<'static, str>> = options.into_iter().map(Into::into).collect();
+        let default = options.first().cloned().unwrap_or_default();
+        self.create_parameter(name, ParameterKind::Choice { default, options })
+    }
+
+    /// Adds a choice among a fixed set of options with a default value.
+    ///
+    /// When the default value is not among the options,
+    /// it is added to them.
+    #[must_use]
+    #[inline]
+    pub fn choice_with_default<I, O>(
+        self,
+        name: &'static str,
+        options: I,
+        default: impl Into<Cow<'static, str>>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = O>,
+        O: Into<Cow<'static, str>>,
+    {
+        let default = default.into();
+        let mut options: Vec<Cow<'static, str>> = options.into_iter().map(Into::into).collect();
+        if !options.contains(&default) {
+            options.push(default.clone());
+        }
+        self.create_parameter(name, ParameterKind::Choice { default, options })
+    }
+
+    /// Adds a [`Color`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn color(self, name: &'static str, default: Color) -> Self {
+        self.create_parameter(name, ParameterKind::Color { default })
+    }
+
+    /// Adds a color temperature parameter, expressed in Kelvin.
+    #[must_use]
+    #[inline]
+    pub fn color_temperature(self, name: &'static str, default: u16) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::ColorTemperature {
+                default,
+                min: u16::MAX,
+                max: u16::MIN,
+            },
+        )
+    }
+
+    /// Adds a color temperature parameter, expressed in Kelvin,
+    /// with specified limits.
+    #[must_use]
+    #[inline]
+    pub fn color_temperature_with_limits(
+        self,
+        name: &'static str,
+        default: u16,
+        min: u16,
+        max: u16,
+    ) -> Self {
+        self.create_parameter(name, ParameterKind::ColorTemperature { default, min, max })
+    }
+
+    /// Adds a [`Duration`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn duration(self, name: &'static str, default: Duration) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::Duration {
+                default,
+                min: DURATION_MAX,
+                max: Duration::ZERO,
+            },
+        )
+    }
+
+    /// Adds a [`Duration`] parameter with specified limits.
+    #[must_use]
+    #[inline]
+    pub fn duration_with_limits(
+        self,
+        name: &'static str,
+        default: Duration,
+        min: Duration,
+        max: Duration,
+    ) -> Self {
+        self.create_parameter(name, ParameterKind::Duration { default, min, max })
+    }
+
+    /// Adds a [`TimeOfDay`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn time_of_day(self, name: &'static str, default: TimeOfDay) -> Self {
+        self.create_parameter(name, ParameterKind::TimeOfDay { default })
+    }
+
+    /// Adds a [`Timestamp`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn timestamp(self, name: &'static str, default: Timestamp) -> Self {
+        self.create_parameter(name, ParameterKind::Timestamp { default })
+    }
+
+    /// Adds a list whose elements are of the given [`ParameterKind`].
+    #[must_use]
+    #[inline]
+    pub fn list(self, name: &'static str, kind: ParameterKind) -> Self {
+        self.list_with_length(name, kind, u32::MIN, u32::MAX)
+    }
+
+    /// Adds a list whose elements are of the given [`ParameterKind`],
+    /// with a minimum and a maximum number of elements.
+    #[must_use]
+    #[inline]
+    pub fn list_with_length(
+        self,
+        name: &'static str,
+        kind: ParameterKind,
+        min_length: u32,
+        max_length: u32,
+    ) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::List {
+                kind: Box::new(kind),
+                min_length,
+                max_length,
+            },
+        )
+    }
+
+    /// Adds an object made of the given [`Parameters`].
+    #[must_use]
+    #[inline]
+    pub fn object(self, name: &'static str, parameters: Parameters) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::Object {
+                parameters: parameters.serialize_data(),
+            },
+        )
+    }
+
+    /// Sets the measurement [`Unit`] of an already added numeric parameter.
+    ///
+    /// The unit is ignored when the parameter does not exist or
+    /// it is not numeric.
+    #[must_use]
+    #[inline]
+    pub fn unit(mut self, name: &'static str, unit: Unit) -> Self {
+        if let Some(slot) = self.0.get_mut(name).and_then(ParameterKind::unit_mut) {
+            *slot = Some(unit);
+        }
+        self
+    }
+
     /// Serializes [`Parameters`] data.
     ///
     /// **It consumes the parameter.**
@@ -645,7 +1771,7 @@ impl Parameters {
 
 /// All supported parameter values extracted from the payload of an incoming
 /// request linked to a route.
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ParameterValue {
     /// A [`bool`] value.
@@ -658,12 +1784,32 @@ pub enum ParameterValue {
     U32(u32),
     /// An [`u64`] value.
     U64(u64),
+    /// An [`i8`] value.
+    I8(i8),
+    /// An [`i16`] value.
+    I16(i16),
+    /// An [`i32`] value.
+    I32(i32),
+    /// An [`i64`] value.
+    I64(i64),
     /// A [`f32`] value.
     F32(f32),
     /// A [`f64`] value.
     F64(f64),
     /// A sequence of characters.
     CharsSequence(Cow<'static, str>),
+    /// A [`Color`] value.
+    Color(Color),
+    /// A [`Duration`] value.
+    Duration(#[serde(with = "duration_text")] Duration),
+    /// A [`TimeOfDay`] value.
+    TimeOfDay(TimeOfDay),
+    /// A [`Timestamp`] value.
+    Timestamp(Timestamp),
+    /// A list of values.
+    List(Vec<ParameterValue>),
+    /// An object made of named values.
+    Object(ParametersValues<'static>),
 }
 
 impl core::fmt::Display for ParameterValue {
@@ -675,9 +1821,38 @@ impl core::fmt::Display for ParameterValue {
             Self::U16(v) => v.fmt(f),
             Self::U32(v) => v.fmt(f),
             Self::U64(v) => v.fmt(f),
+            Self::I8(v) => v.fmt(f),
+            Self::I16(v) => v.fmt(f),
+            Self::I32(v) => v.fmt(f),
+            Self::I64(v) => v.fmt(f),
             Self::F32(v) => v.fmt(f),
             Self::F64(v) => v.fmt(f),
             Self::CharsSequence(v) => v.fmt(f),
+            Self::Color(v) => v.fmt(f),
+            Self::Duration(v) => write!(f, "{}ms", v.as_millis()),
+            Self::TimeOfDay(v) => v.fmt(f),
+            Self::Timestamp(v) => v.fmt(f),
+            Self::List(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    value.fmt_nested(f)?;
+                }
+                write!(f, "]")
+            }
+            Self::Object(values) => {
+                write!(f, "{{")?;
+                for (index, (name, value)) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{name:?}:")?;
+                    value.fmt_nested(f)?;
+                }
+                write!(f, "}}")
+            }
         }
     }
 }
@@ -689,18 +1864,61 @@ impl ParameterValue {
         match parameter_kind {
             ParameterKind::Bool { default } => Self::Bool(*default),
             ParameterKind::U8 { default, .. } => Self::U8(*default),
-            ParameterKind::U16 { default, .. } => Self::U16(*default),
+            ParameterKind::U16 { default, .. }
+            | ParameterKind::ColorTemperature { default, .. } => Self::U16(*default),
             ParameterKind::U32 { default, .. } | ParameterKind::RangeU32 { default, .. } => {
                 Self::U32(*default)
             }
             ParameterKind::U64 { default, .. } | ParameterKind::RangeU64 { default, .. } => {
                 Self::U64(*default)
             }
+            ParameterKind::I8 { default, .. } => Self::I8(*default),
+            ParameterKind::I16 { default, .. } => Self::I16(*default),
+            ParameterKind::I32 { default, .. } | ParameterKind::RangeI32 { default, .. } => {
+                Self::I32(*default)
+            }
+            ParameterKind::I64 { default, .. } | ParameterKind::RangeI64 { default, .. } => {
+                Self::I64(*default)
+            }
             ParameterKind::F32 { default, .. } => Self::F32(*default),
             ParameterKind::F64 { default, .. } | ParameterKind::RangeF64 { default, .. } => {
                 Self::F64(*default)
             }
-            ParameterKind::CharsSequence { default, .. } => Self::CharsSequence(default.clone()),
+            ParameterKind::CharsSequence { default, .. }
+            | ParameterKind::Choice { default, .. } => Self::CharsSequence(default.clone()),
+            ParameterKind::Color { default } => Self::Color(*default),
+            ParameterKind::Duration { default, .. } => Self::Duration(*default),
+            ParameterKind::TimeOfDay { default } => Self::TimeOfDay(*default),
+            ParameterKind::Timestamp { default } => Self::Timestamp(*default),
+            ParameterKind::List {
+                kind, min_length, ..
+            } => Self::List(
+                (0..*min_length)
+                    .map(|_| Self::from_parameter_kind(kind))
+                    .collect(),
+            ),
+            ParameterKind::Object { parameters } => {
+                let mut values = ParametersValues::new();
+                for (name, kind) in parameters {
+                    values.parameter_value(name.clone(), Self::from_parameter_kind(kind));
+                }
+                Self::Object(values)
+            }
+        }
+    }
+
+    // Formats a value contained in a list or in an object,
+    // quoting values represented as text.
+    //
+    // The output is a compact `JSON` text, so that lists and objects can
+    // also be placed inside a route path.
+    fn fmt_nested(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CharsSequence(v) => write!(f, "{v:?}"),
+            Self::Color(_) | Self::Duration(_) | Self::TimeOfDay(_) | Self::Timestamp(_) => {
+                write!(f, "\"{self}\"")
+            }
+            _ => core::fmt::Display::fmt(self, f),
         }
     }
 
@@ -713,9 +1931,19 @@ impl ParameterValue {
             Self::U16(_) => "u16",
             Self::U32(_) => "u32",
             Self::U64(_) => "u64",
+            Self::I8(_) => "i8",
+            Self::I16(_) => "i16",
+            Self::I32(_) => "i32",
+            Self::I64(_) => "i64",
             Self::F32(_) => "f32",
             Self::F64(_) => "f64",
             Self::CharsSequence(_) => "String",
+            Self::Color(_) => "Color",
+            Self::Duration(_) => "Duration",
+            Self::TimeOfDay(_) => "TimeOfDay",
+            Self::Timestamp(_) => "Timestamp",
+            Self::List(_) => "List",
+            Self::Object(_) => "Object",
         }
     }
 
@@ -726,7 +1954,10 @@ impl ParameterValue {
             (self, parameter_kind),
             (Self::Bool(_), ParameterKind::Bool { .. })
                 | (Self::U8(_), ParameterKind::U8 { .. })
-                | (Self::U16(_), ParameterKind::U16 { .. })
+                | (
+                    Self::U16(_),
+                    ParameterKind::U16 { .. } | ParameterKind::ColorTemperature { .. }
+                )
                 | (
                     Self::U32(_),
                     ParameterKind::U32 { .. } | ParameterKind::RangeU32 { .. }
@@ -735,19 +1966,146 @@ impl ParameterValue {
                     Self::U64(_),
                     ParameterKind::U64 { .. } | ParameterKind::RangeU64 { .. }
                 )
+                | (Self::I8(_), ParameterKind::I8 { .. })
+                | (Self::I16(_), ParameterKind::I16 { .. })
+                | (
+                    Self::I32(_),
+                    ParameterKind::I32 { .. } | ParameterKind::RangeI32 { .. }
+                )
+                | (
+                    Self::I64(_),
+                    ParameterKind::I64 { .. } | ParameterKind::RangeI64 { .. }
+                )
                 | (Self::F32(_), ParameterKind::F32 { .. })
                 | (
                     Self::F64(_),
                     ParameterKind::F64 { .. } | ParameterKind::RangeF64 { .. }
                 )
-                | (Self::CharsSequence(_), ParameterKind::CharsSequence { .. })
+                | (
+                    Self::CharsSequence(_),
+                    ParameterKind::CharsSequence { .. } | ParameterKind::Choice { .. }
+                )
+                | (Self::Color(_), ParameterKind::Color { .. })
+                | (Self::Duration(_), ParameterKind::Duration { .. })
+                | (Self::TimeOfDay(_), ParameterKind::TimeOfDay { .. })
+                | (Self::Timestamp(_), ParameterKind::Timestamp { .. })
+                | (Self::List(_), ParameterKind::List { .. })
+                | (Self::Object(_), ParameterKind::Object { .. })
         )
     }
+
+    /// Decodes a `JSON` value into a [`ParameterValue`] of the given
+    /// [`ParameterKind`].
+    ///
+    /// Colors, durations and times are decoded from their textual format.
+    /// Undeclared object fields are maintained, so that
+    /// [`ParameterKind::validate`] can report them.
+    ///
+    /// Returns [`None`] when the `JSON` value does not represent
+    /// the given kind.
+    #[must_use]
+    pub fn from_json(parameter_kind: &ParameterKind, value: &Value) -> Option<Self> {
+        fn integer<T: TryFrom<i128>>(value: &Value) -> Option<T> {
+            value
+                .as_u64()
+                .map(i128::from)
+                .or_else(|| value.as_i64().map(i128::from))
+                .and_then(|v| T::try_from(v).ok())
+        }
+
+        fn text<T: core::str::FromStr>(value: &Value) -> Option<T> {
+            value.as_str()?.parse().ok()
+        }
+
+        Some(match parameter_kind {
+            ParameterKind::Bool { .. } => Self::Bool(value.as_bool()?),
+            ParameterKind::U8 { .. } => Self::U8(integer(value)?),
+            ParameterKind::U16 { .. } | ParameterKind::ColorTemperature { .. } => {
+                Self::U16(integer(value)?)
+            }
+            ParameterKind::U32 { .. } | ParameterKind::RangeU32 { .. } => {
+                Self::U32(integer(value)?)
+            }
+            ParameterKind::U64 { .. } | ParameterKind::RangeU64 { .. } => {
+                Self::U64(integer(value)?)
+            }
+            ParameterKind::I8 { .. } => Self::I8(integer(value)?),
+            ParameterKind::I16 { .. } => Self::I16(integer(value)?),
+            ParameterKind::I32 { .. } | ParameterKind::RangeI32 { .. } => {
+                Self::I32(integer(value)?)
+            }
+            ParameterKind::I64 { .. } | ParameterKind::RangeI64 { .. } => {
+                Self::I64(integer(value)?)
+            }
+            // Values exceeding the `f32` limits are rejected by validation.
+            #[allow(clippy::cast_possible_truncation)]
+            ParameterKind::F32 { .. } => Self::F32(value.as_f64()? as f32),
+            ParameterKind::F64 { .. } | ParameterKind::RangeF64 { .. } => {
+                Self::F64(value.as_f64()?)
+            }
+            ParameterKind::CharsSequence { .. } | ParameterKind::Choice { .. } => {
+                Self::CharsSequence(String::from(value.as_str()?).into())
+            }
+            ParameterKind::Color { .. } => Self::Color(text(value)?),
+            ParameterKind::Duration { .. } => Self::Duration(parse_duration(value.as_str()?).ok()?),
+            ParameterKind::TimeOfDay { .. } => Self::TimeOfDay(text(value)?),
+            ParameterKind::Timestamp { .. } => Self::Timestamp(text(value)?),
+            ParameterKind::List { kind, .. } => Self::List(
+                value
+                    .as_array()?
+                    .iter()
+                    .map(|value| Self::from_json(kind, value))
+                    .collect::<Option<_>>()?,
+            ),
+            ParameterKind::Object { parameters } => {
+                Self::Object(object_from_json(parameters, value.as_object()?)?)
+            }
+        })
+    }
+
+    /// Decodes a path segment into a [`ParameterValue`] of the given
+    /// [`ParameterKind`].
+    ///
+    /// Sequences of characters, colors, durations and times are the segment
+    /// text itself, while any other value is `JSON`-encoded, such as numbers,
+    /// booleans, lists and objects.
+    ///
+    /// Returns [`None`] when the segment does not represent the given kind.
+    #[must_use]
+    pub fn from_path_segment(parameter_kind: &ParameterKind, segment: &str) -> Option<Self> {
+        let value = match parameter_kind {
+            ParameterKind::CharsSequence { .. }
+            | ParameterKind::Choice { .. }
+            | ParameterKind::Color { .. }
+            | ParameterKind::Duration { .. }
+            | ParameterKind::TimeOfDay { .. }
+            | ParameterKind::Timestamp { .. } => Value::String(segment.into()),
+            _ => serde_json::from_str(segment).ok()?,
+        };
+        Self::from_json(parameter_kind, &value)
+    }
+}
+
+// Decodes the fields of a `JSON` object according to their declared kinds,
+// maintaining the undeclared ones.
+fn object_from_json(
+    parameters: &ParametersData,
+    fields: &Map<String, Value>,
+) -> Option<ParametersValues<'static>> {
+    let mut values = ParametersValues::new();
+    for (name, value) in fields {
+        let value = match parameters.get(name) {
+            Some(kind) => ParameterValue::from_json(kind, value)?,
+            None => ParameterValue::deserialize(value).ok()?,
+        };
+        values.parameter_value(name.clone(), value);
+    }
+    Some(values)
 }
 
 /// A map associating each parameter name with its
 /// corresponding [`ParameterValue`].
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParametersValues<'a>(IndexMap<Cow<'a, str>, ParameterValue, DefaultHashBuilder>);
 
 impl Default for ParametersValues<'_> {
@@ -823,6 +2181,30 @@ impl<'a> ParametersValues<'a> {
         self.parameter_value(name, ParameterValue::U64(value))
     }
 
+    /// Adds an [`i8`] parameter.
+    #[inline]
+    pub fn i8(&mut self, name: impl Into<Cow<'a, str>>, value: i8) -> &mut Self {
+        self.parameter_value(name, ParameterValue::I8(value))
+    }
+
+    /// Adds an [`i16`] parameter.
+    #[inline]
+    pub fn i16(&mut self, name: impl Into<Cow<'a, str>>, value: i16) -> &mut Self {
+        self.parameter_value(name, ParameterValue::I16(value))
+    }
+
+    /// Adds an [`i32`] parameter.
+    #[inline]
+    pub fn i32(&mut self, name: impl Into<Cow<'a, str>>, value: i32) -> &mut Self {
+        self.parameter_value(name, ParameterValue::I32(value))
+    }
+
+    /// Adds an [`i64`] parameter.
+    #[inline]
+    pub fn i64(&mut self, name: impl Into<Cow<'a, str>>, value: i64) -> &mut Self {
+        self.parameter_value(name, ParameterValue::I64(value))
+    }
+
     /// Adds a [`f32`] parameter.
     #[inline]
     pub fn f32(&mut self, name: impl Into<Cow<'a, str>>, value: f32) -> &mut Self {
@@ -845,6 +2227,56 @@ impl<'a> ParametersValues<'a> {
         self.parameter_value(name, ParameterValue::CharsSequence(value.into()))
     }
 
+    /// Adds a [`Color`] value.
+    #[inline]
+    pub fn color(&mut self, name: impl Into<Cow<'a, str>>, value: Color) -> &mut Self {
+        self.parameter_value(name, ParameterValue::Color(value))
+    }
+
+    /// Adds a color temperature value, expressed in Kelvin.
+    #[inline]
+    pub fn color_temperature(&mut self, name: impl Into<Cow<'a, str>>, value: u16) -> &mut Self {
+        self.parameter_value(name, ParameterValue::U16(value))
+    }
+
+    /// Adds a [`Duration`] value.
+    #[inline]
+    pub fn duration(&mut self, name: impl Into<Cow<'a, str>>, value: Duration) -> &mut Self {
+        self.parameter_value(name, ParameterValue::Duration(value))
+    }
+
+    /// Adds a [`TimeOfDay`] value.
+    #[inline]
+    pub fn time_of_day(&mut self, name: impl Into<Cow<'a, str>>, value: TimeOfDay) -> &mut Self {
+        self.parameter_value(name, ParameterValue::TimeOfDay(value))
+    }
+
+    /// Adds a [`Timestamp`] value.
+    #[inline]
+    pub fn timestamp(&mut self, name: impl Into<Cow<'a, str>>, value: Timestamp) -> &mut Self {
+        self.parameter_value(name, ParameterValue::Timestamp(value))
+    }
+
+    /// Adds a list of values.
+    #[inline]
+    pub fn list(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        values: Vec<ParameterValue>,
+    ) -> &mut Self {
+        self.parameter_value(name, ParameterValue::List(values))
+    }
+
+    /// Adds an object made of the given values.
+    #[inline]
+    pub fn object(
+        &mut self,
+        name: impl Into<Cow<'a, str>>,
+        values: ParametersValues<'static>,
+    ) -> &mut Self {
+        self.parameter_value(name, ParameterValue::Object(values))
+    }
+
     /// Retrieves a [`ParameterValue`] by name.
     ///
     /// Returns [`None`] if the parameter does not exist.
@@ -912,11 +2344,20 @@ impl<'a> ParametersPayloads<'a> {
 #[cfg(test)]
 #[cfg(feature = "deserialize")]
 mod tests {
-    use alloc::string::String;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
 
     use crate::{deserialize, serialize};
 
-    use super::{ParameterKind, Parameters, ParametersData, ParametersValues};
+    use core::time::Duration;
+
+    use crate::units::Unit;
+    use crate::values::{Color, TimeOfDay, Timestamp};
+
+    use super::{
+        DURATION_MAX, DecimalPrecision, ParameterKind, ParameterShape, ParameterValue,
+        ParameterViolation, Parameters, ParametersData, ParametersValues,
+    };
 
     fn expected_parameters_data() -> ParametersData {
         ParametersData::new()
@@ -927,6 +2368,7 @@ mod tests {
                     default: 0,
                     min: u8::MIN,
                     max: u8::MAX,
+                    unit: None,
                 },
             )
             .insert(
@@ -935,6 +2377,7 @@ mod tests {
                     default: 0,
                     min: u16::MIN,
                     max: u16::MAX,
+                    unit: None,
                 },
             )
             .insert(
@@ -943,6 +2386,7 @@ mod tests {
                     default: 0,
                     min: u32::MIN,
                     max: u32::MAX,
+                    unit: None,
                 },
             )
             .insert(
@@ -951,6 +2395,7 @@ mod tests {
                     default: 0,
                     min: u64::MIN,
                     max: u64::MAX,
+                    unit: None,
                 },
             )
             .insert(
@@ -960,6 +2405,7 @@ mod tests {
                     min: f32::MIN,
                     max: f32::MAX,
                     step: 0.,
+                    unit: None,
                 },
             )
             .insert(
@@ -969,6 +2415,7 @@ mod tests {
                     min: f64::MIN,
                     max: f64::MAX,
                     step: 0.,
+                    unit: None,
                 },
             )
             .insert(
@@ -978,6 +2425,7 @@ mod tests {
                     max: 20,
                     step: 1,
                     default: 5,
+                    unit: None,
                 },
             )
             .insert(
@@ -987,6 +2435,7 @@ mod tests {
                     max: 20,
                     step: 1,
                     default: 5,
+                    unit: None,
                 },
             )
             .insert(
@@ -996,6 +2445,7 @@ mod tests {
                     max: 20.,
                     step: 0.1,
                     default: 5.,
+                    unit: None,
                 },
             )
             .insert(
@@ -1037,19 +2487,588 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_signed_parameters() {
+        let parameters = Parameters::new()
+            .i8("i8", -1)
+            .i16_with_limits("i16", -1, -100, 100)
+            .i32("i32", -1)
+            .i64("i64", -1)
+            .rangei32("rangei32", (-20, 20, -2))
+            .rangei64_with_default("rangei64", (-20, 20, 2), -4);
+
+        let expected = ParametersData::new()
+            .insert(
+                "i8".into(),
+                ParameterKind::I8 {
+                    default: -1,
+                    min: i8::MIN,
+                    max: i8::MAX,
+                    unit: None,
+                },
+            )
+            .insert(
+                "i16".into(),
+                ParameterKind::I16 {
+                    default: -1,
+                    min: -100,
+                    max: 100,
+                    unit: None,
+                },
+            )
+            .insert(
+                "i32".into(),
+                ParameterKind::I32 {
+                    default: -1,
+                    min: i32::MIN,
+                    max: i32::MAX,
+                    unit: None,
+                },
+            )
+            .insert(
+                "i64".into(),
+                ParameterKind::I64 {
+                    default: -1,
+                    min: i64::MIN,
+                    max: i64::MAX,
+                    unit: None,
+                },
+            )
+            .insert(
+                "rangei32".into(),
+                ParameterKind::RangeI32 {
+                    min: -20,
+                    max: 20,
+                    step: 2,
+                    default: 0,
+                    unit: None,
+                },
+            )
+            .insert(
+                "rangei64".into(),
+                ParameterKind::RangeI64 {
+                    min: -20,
+                    max: 20,
+                    step: 2,
+                    default: -4,
+                    unit: None,
+                },
+            );
+
+        assert_eq!(
+            deserialize::<ParametersData>(serialize(parameters.serialize_data())),
+            expected,
+        );
+    }
+
+    #[test]
+    fn test_parameter_units() {
+        let parameters = Parameters::new()
+            .f32_with_limits("temperature", 20., 5., 30., DecimalPrecision::OneDigit)
+            .unit("temperature", Unit::Celsius)
+            .rangeu32("brightness", (0, 100, 1))
+            .unit("brightness", Unit::Percent)
+            .u16_with_limits("speed", 0, 0, 3000)
+            .unit("speed", Unit::custom("rpm"))
+            .bool("on", false)
+            // Units of non-numeric or missing parameters are ignored.
+            .unit("on", Unit::Watt)
+            .unit("missing", Unit::Watt)
+            .serialize_data();
+
+        assert_eq!(
+            parameters.get("temperature").and_then(ParameterKind::unit),
+            Some(&Unit::Celsius)
+        );
+        assert_eq!(
+            parameters.get("speed").and_then(ParameterKind::unit),
+            Some(&Unit::custom("rpm"))
+        );
+        assert_eq!(parameters.get("on").and_then(ParameterKind::unit), None);
+        assert!(parameters.get("missing").is_none());
+
+        let value = serialize(&parameters);
+        assert_eq!(value["brightness"]["RangeU32"]["unit"], "%");
+        assert!(value["on"]["Bool"].get("unit").is_none());
+        assert_eq!(deserialize::<ParametersData>(value), parameters);
+    }
+
+    #[test]
+    fn test_choice_parameters() {
+        let parameters = Parameters::new()
+            .choice("mode", ["auto", "low", "high"])
+            .choice_with_default("format", ["random", "exact"], "closest");
+
+        let expected = ParametersData::new()
+            .insert(
+                "mode".into(),
+                ParameterKind::Choice {
+                    default: "auto".into(),
+                    options: vec!["auto".into(), "low".into(), "high".into()],
+                },
+            )
+            .insert(
+                "format".into(),
+                ParameterKind::Choice {
+                    default: "closest".into(),
+                    options: vec!["random".into(), "exact".into(), "closest".into()],
+                },
+            );
+
+        let data = deserialize::<ParametersData>(serialize(parameters.serialize_data()));
+        assert_eq!(data, expected);
+
+        let mode = data.get("mode").unwrap();
+        assert_eq!(
+            ParameterValue::from_parameter_kind(mode),
+            ParameterValue::CharsSequence("auto".into())
+        );
+        assert_eq!(
+            mode.validate(&ParameterValue::CharsSequence("low".into())),
+            Ok(())
+        );
+
+        let violation = mode
+            .validate(&ParameterValue::CharsSequence("turbo".into()))
+            .unwrap_err();
+        assert_eq!(
+            violation.to_string(),
+            "value not among the allowed options: `auto`, `low`, `high`"
+        );
+        assert_eq!(
+            mode.validate(&ParameterValue::U8(1)),
+            Err(ParameterViolation::Type {
+                expected: "String",
+                found: "u8"
+            })
+        );
+    }
+
+    #[test]
+    fn test_list_and_object_parameters() {
+        let parameters = Parameters::new()
+            .list_with_length(
+                "zones",
+                ParameterKind::U8 {
+                    default: 1,
+                    min: 1,
+                    max: 8,
+                    unit: None,
+                },
+                1,
+                3,
+            )
+            .object(
+                "time",
+                Parameters::new()
+                    .u8_with_limits("hour", 0, 0, 23)
+                    .u8_with_limits("minute", 0, 0, 59),
+            );
+
+        let data = deserialize::<ParametersData>(serialize(parameters.serialize_data()));
+
+        let zones = data.get("zones").unwrap();
+        let time = data.get("time").unwrap();
+
+        // Defaults.
+        assert_eq!(
+            ParameterValue::from_parameter_kind(zones),
+            ParameterValue::List(vec![ParameterValue::U8(1)])
+        );
+        assert_eq!(
+            ParameterValue::from_parameter_kind(time).to_string(),
+            r#"{"hour":0,"minute":0}"#
+        );
+
+        // Lists.
+        let list = |values: &[u8]| {
+            ParameterValue::List(values.iter().copied().map(ParameterValue::U8).collect())
+        };
+        assert_eq!(zones.validate(&list(&[1, 8])), Ok(()));
+        assert_eq!(
+            zones.validate(&list(&[])),
+            Err(ParameterViolation::MinLength(1))
+        );
+        assert_eq!(
+            zones.validate(&list(&[1, 2, 3, 4])),
+            Err(ParameterViolation::MaxLength(3))
+        );
+        assert_eq!(
+            zones.validate(&list(&[1, 9])).unwrap_err().to_string(),
+            "element `1`: value greater than the maximum `8`"
+        );
+
+        // Objects.
+        let mut values = ParametersValues::new();
+        values.u8("hour", 7).u8("minute", 30);
+        assert_eq!(time.validate(&ParameterValue::Object(values)), Ok(()));
+
+        let mut values = ParametersValues::new();
+        values.u8("hour", 24).u8("minute", 30);
+        assert_eq!(
+            time.validate(&ParameterValue::Object(values))
+                .unwrap_err()
+                .to_string(),
+            "field `hour`: value greater than the maximum `23`"
+        );
+
+        let mut values = ParametersValues::new();
+        values.u8("hour", 7);
+        assert_eq!(
+            time.validate(&ParameterValue::Object(values)),
+            Err(ParameterViolation::MissingField("minute".into()))
+        );
+
+        let mut values = ParametersValues::new();
+        values.u8("hour", 7).u8("minute", 30).u8("second", 0);
+        assert_eq!(
+            time.validate(&ParameterValue::Object(values)),
+            Err(ParameterViolation::UnknownField("second".into()))
+        );
+    }
+
+    #[test]
+    fn test_parameter_shapes() {
+        let data = Parameters::new()
+            .bool("on", false)
+            .u8("level", 0)
+            .rangef64("brightness", (0., 1., 0.1))
+            .choice("mode", ["eco", "comfort"])
+            .color("color", Color::rgb(255, 255, 255))
+            .color_temperature("temperature", 2700)
+            .serialize_data();
+
+        let shapes = [
+            ("on", ParameterShape::Bool),
+            ("level", ParameterShape::Number),
+            ("brightness", ParameterShape::Number),
+            ("mode", ParameterShape::Text),
+            ("color", ParameterShape::Color),
+            ("temperature", ParameterShape::ColorTemperature),
+        ];
+
+        for (name, shape) in shapes {
+            let kind = data.get(name).unwrap();
+            assert_eq!(ParameterShape::from_parameter_kind(kind), shape);
+            assert!(shape.matches(kind));
+        }
+
+        assert!(!ParameterShape::Number.matches(data.get("color").unwrap()));
+    }
+
+    #[test]
+    fn test_color_and_time_parameters() {
+        let parameters = Parameters::new()
+            .color("color", Color::rgb(255, 255, 255))
+            .color_temperature_with_limits("temperature", 2700, 2000, 6500)
+            .duration_with_limits(
+                "fade",
+                Duration::from_millis(500),
+                Duration::ZERO,
+                Duration::from_secs(10),
+            )
+            .duration("delay", Duration::from_secs(1))
+            .time_of_day("alarm", TimeOfDay::new(7, 30, 0).unwrap())
+            .timestamp("start", Timestamp::UNIX_EPOCH);
+
+        let serialized = serialize(parameters.serialize_data());
+        assert_eq!(
+            serialized["fade"],
+            serde_json::json!({"Duration": {"default": "500ms", "min": "0ms", "max": "10000ms"}})
+        );
+        assert_eq!(
+            serialized["alarm"],
+            serde_json::json!({"TimeOfDay": {"default": "07:30:00"}})
+        );
+
+        let data = deserialize::<ParametersData>(serialized);
+        assert_eq!(
+            data.get("delay"),
+            Some(&ParameterKind::Duration {
+                default: Duration::from_secs(1),
+                min: Duration::ZERO,
+                max: DURATION_MAX,
+            })
+        );
+
+        let check = |name: &str, value: ParameterValue| data.get(name).unwrap().validate(&value);
+
+        assert_eq!(
+            check("color", ParameterValue::Color(Color::rgb(0, 0, 0))),
+            Ok(())
+        );
+        assert_eq!(
+            check("temperature", ParameterValue::U16(7000)),
+            Err(ParameterViolation::Max(ParameterValue::U16(6500)))
+        );
+        assert_eq!(
+            check("fade", ParameterValue::Duration(Duration::from_secs(11)))
+                .unwrap_err()
+                .to_string(),
+            "value greater than the maximum `10000ms`"
+        );
+        assert_eq!(
+            check("start", ParameterValue::Timestamp(Timestamp::from_unix(10))),
+            Ok(())
+        );
+        assert_eq!(
+            check("alarm", ParameterValue::CharsSequence("07:30".into())),
+            Err(ParameterViolation::Type {
+                expected: "TimeOfDay",
+                found: "String"
+            })
+        );
+
+        // Values are encoded in their textual format.
+        let mut values = ParametersValues::new();
+        values
+            .color("color", Color::hsv(120, 50, 100).unwrap())
+            .duration("fade", Duration::from_millis(250))
+            .list(
+                "alarms",
+                vec![ParameterValue::TimeOfDay(TimeOfDay::MIDNIGHT)],
+            );
+        assert_eq!(
+            serialize(&values),
+            serde_json::json!({"color": "hsv(120,50,100)", "fade": "250ms", "alarms": ["00:00:00"]})
+        );
+        assert_eq!(values.get("alarms").unwrap().to_string(), r#"["00:00:00"]"#);
+    }
+
     #[test]
     fn test_deserialize_parameters_values() {
         let mut parameters = ParametersValues::new();
         parameters.bool("one", true);
         parameters.u8("two", 8);
         parameters.f32("three", 3.0);
+        parameters.i16("four", -300);
+        parameters.list(
+            "five",
+            vec![
+                ParameterValue::CharsSequence("a".into()),
+                ParameterValue::CharsSequence("b".into()),
+            ],
+        );
+        let mut time = ParametersValues::new();
+        time.u8("hour", 7).u8("minute", 30);
+        parameters.object("six", time);
 
         let json_value = serde_json::json!({
             "one": true,
             "two": 8,
             "three": 3.0,
+            "four": -300,
+            "five": ["a", "b"],
+            "six": {"hour": 7, "minute": 30},
         });
 
         assert_eq!(deserialize::<ParametersValues>(json_value), parameters);
     }
+
+    #[test]
+    fn test_validate_parameters() {
+        let data = Parameters::new()
+            .u8("u8", 0)
+            .u8_with_limits("u8_limits", 5, 1, 10)
+            .f32_with_limits("f32_limits", 0.5, 0., 1., DecimalPrecision::OneDigit)
+            .f64("f64", 0.)
+            .i8_with_limits("i8_limits", 0, -10, 10)
+            .i64("i64", 0)
+            .rangeu32_with_default("rangeu32", (2, 20, 3), 5)
+            .rangei32_with_default("rangei32", (-100, 100, 15), -10)
+            .rangef64_with_default("rangef64", (0., 20., 0.5), 5.)
+            .serialize_data();
+
+        let check = |name: &str, value: ParameterValue| data.get(name).unwrap().validate(&value);
+
+        // Parameters without limits.
+        assert_eq!(check("u8", ParameterValue::U8(u8::MAX)), Ok(()));
+        assert_eq!(check("f64", ParameterValue::F64(-1e10)), Ok(()));
+        assert_eq!(check("i64", ParameterValue::I64(i64::MIN)), Ok(()));
+
+        // Wrong type.
+        assert_eq!(
+            check("u8", ParameterValue::Bool(true)),
+            Err(ParameterViolation::Type {
+                expected: "u8",
+                found: "bool"
+            })
+        );
+
+        // Limits.
+        assert_eq!(check("u8_limits", ParameterValue::U8(10)), Ok(()));
+        assert_eq!(
+            check("u8_limits", ParameterValue::U8(0)),
+            Err(ParameterViolation::Min(ParameterValue::U8(1)))
+        );
+        assert_eq!(
+            check("u8_limits", ParameterValue::U8(11)),
+            Err(ParameterViolation::Max(ParameterValue::U8(10)))
+        );
+
+        assert_eq!(check("i8_limits", ParameterValue::I8(-10)), Ok(()));
+        assert_eq!(
+            check("i8_limits", ParameterValue::I8(-11)),
+            Err(ParameterViolation::Min(ParameterValue::I8(-10)))
+        );
+        assert_eq!(
+            check("i8_limits", ParameterValue::I8(i8::MAX)),
+            Err(ParameterViolation::Max(ParameterValue::I8(10)))
+        );
+
+        // Decimal precision.
+        assert_eq!(check("f32_limits", ParameterValue::F32(0.3)), Ok(()));
+        assert!(matches!(
+            check("f32_limits", ParameterValue::F32(0.35)),
+            Err(ParameterViolation::Step(_))
+        ));
+        assert!(matches!(
+            check("f32_limits", ParameterValue::F32(1.5)),
+            Err(ParameterViolation::Max(_))
+        ));
+
+        // Ranges.
+        assert_eq!(check("rangeu32", ParameterValue::U32(20)), Ok(()));
+        assert_eq!(
+            check("rangeu32", ParameterValue::U32(4)),
+            Err(ParameterViolation::Step(ParameterValue::U32(3)))
+        );
+        assert_eq!(check("rangei32", ParameterValue::I32(-10)), Ok(()));
+        assert_eq!(check("rangei32", ParameterValue::I32(95)), Ok(()));
+        assert_eq!(
+            check("rangei32", ParameterValue::I32(0)),
+            Err(ParameterViolation::Step(ParameterValue::I32(15)))
+        );
+        assert_eq!(check("rangef64", ParameterValue::F64(19.5)), Ok(()));
+        assert_eq!(
+            check("rangef64", ParameterValue::F64(19.7)),
+            Err(ParameterViolation::Step(ParameterValue::F64(0.5)))
+        );
+
+        assert_eq!(
+            ParameterViolation::Max(ParameterValue::U8(10)).to_string(),
+            "value greater than the maximum `10`"
+        );
+    }
+
+    #[test]
+    fn test_values_from_json() {
+        let data = Parameters::new()
+            .u32("u32", 0)
+            .f32("f32", 0.)
+            .duration("duration", Duration::ZERO)
+            .list(
+                "list",
+                ParameterKind::I16 {
+                    default: 0,
+                    min: i16::MAX,
+                    max: i16::MIN,
+                    unit: None,
+                },
+            )
+            .object("object", Parameters::new().choice("mode", ["warm", "cold"]))
+            .serialize_data();
+
+        let mut object = ParametersValues::new();
+        object.characters_sequence("mode", "cold".into());
+        let mut expected = ParametersValues::new();
+        expected
+            .u32("u32", 7)
+            .f32("f32", 0.5)
+            .duration("duration", Duration::from_secs(2))
+            .list(
+                "list",
+                vec![ParameterValue::I16(-1), ParameterValue::I16(2)],
+            )
+            .object("object", object);
+
+        let values = data
+            .values_from_json(&serde_json::json!({
+                "u32": 7,
+                "f32": 0.5,
+                "duration": "2s",
+                "list": [-1, 2],
+                "object": { "mode": "cold" },
+            }))
+            .unwrap();
+        assert_eq!(values, expected);
+        assert_eq!(data.validate(&values), Ok(()));
+
+        // Values which do not represent their kinds.
+        assert_eq!(
+            data.values_from_json(&serde_json::json!({ "u32": -7 })),
+            None
+        );
+        assert_eq!(
+            data.values_from_json(&serde_json::json!({ "list": [1, "2"] })),
+            None
+        );
+        assert_eq!(data.values_from_json(&serde_json::json!([7])), None);
+
+        // Undeclared and missing fields are reported by the validation.
+        let values = data
+            .values_from_json(&serde_json::json!({ "unknown": true }))
+            .unwrap();
+        assert_eq!(
+            data.validate(&values),
+            Err(ParameterViolation::UnknownField("unknown".into()))
+        );
+        assert_eq!(
+            data.validate(&ParametersValues::new()),
+            Err(ParameterViolation::MissingField("u32".into()))
+        );
+    }
+
+    #[test]
+    fn test_value_from_path_segment() {
+        let data = Parameters::new()
+            .bool("bool", false)
+            .i16("i16", 0)
+            .f64("f64", 0.)
+            .characters_sequence("text", "")
+            .duration("duration", Duration::ZERO)
+            .list(
+                "list",
+                ParameterKind::U8 {
+                    default: 0,
+                    min: u8::MAX,
+                    max: u8::MIN,
+                    unit: None,
+                },
+            )
+            .serialize_data();
+        let kind = |name| data.get(name).unwrap();
+
+        assert_eq!(
+            ParameterValue::from_path_segment(kind("bool"), "true"),
+            Some(ParameterValue::Bool(true))
+        );
+        assert_eq!(
+            ParameterValue::from_path_segment(kind("i16"), "-3"),
+            Some(ParameterValue::I16(-3))
+        );
+        assert_eq!(
+            ParameterValue::from_path_segment(kind("f64"), "1"),
+            Some(ParameterValue::F64(1.))
+        );
+        assert_eq!(
+            ParameterValue::from_path_segment(kind("text"), "42"),
+            Some(ParameterValue::CharsSequence("42".into()))
+        );
+        assert_eq!(
+            ParameterValue::from_path_segment(kind("duration"), "2s"),
+            Some(ParameterValue::Duration(Duration::from_secs(2)))
+        );
+        assert_eq!(
+            ParameterValue::from_path_segment(kind("list"), "[1,2]"),
+            Some(ParameterValue::List(vec![
+                ParameterValue::U8(1),
+                ParameterValue::U8(2)
+            ]))
+        );
+
+        // Segments which do not represent their kinds.
+        assert_eq!(ParameterValue::from_path_segment(kind("i16"), "a"), None);
+        assert_eq!(ParameterValue::from_path_segment(kind("list"), "1,2"), None);
+    }
 }
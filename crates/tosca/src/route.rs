@@ -8,8 +8,8 @@ use log::error;
 
 use serde::Serialize;
 
-use crate::hazards::{Hazard, Hazards};
-use crate::parameters::{Parameters, ParametersData};
+use crate::hazards::{ALL_HAZARDS, Hazard, HazardRisk, HazardRisks, Hazards};
+use crate::parameters::{ParameterShape, Parameters, ParametersData};
 use crate::response::ResponseKind;
 
 use crate::macros::set;
@@ -55,10 +55,18 @@ pub struct RouteData {
     #[serde(skip_serializing_if = "Hazards::is_empty")]
     #[serde(default = "Hazards::new")]
     pub hazards: Hazards,
+    /// Risks overriding the default ones of the route hazards.
+    #[serde(skip_serializing_if = "HazardRisks::is_empty")]
+    #[serde(default = "HazardRisks::new")]
+    pub risks: HazardRisks,
     /// Route parameters.
     #[serde(skip_serializing_if = "ParametersData::is_empty")]
     #[serde(default = "ParametersData::new")]
     pub parameters: ParametersData,
+    /// Fields of the payload sent by a serial response.
+    #[serde(skip_serializing_if = "ParametersData::is_empty")]
+    #[serde(default = "ParametersData::new")]
+    pub serial_payload: ParametersData,
 }
 
 impl PartialEq for RouteData {
@@ -68,13 +76,16 @@ impl PartialEq for RouteData {
 }
 
 impl RouteData {
-    fn new(route: Route) -> Self {
+    fn new(mut route: Route) -> Self {
+        route.risks.retain(&route.hazards);
         Self {
             name: route.name.into(),
             path: route.path.into(),
             description: route.description.map(core::convert::Into::into),
             hazards: route.hazards,
+            risks: route.risks,
             parameters: route.parameters.serialize_data(),
+            serial_payload: route.serial_payload.serialize_data(),
         }
     }
 }
@@ -159,8 +170,12 @@ pub struct Route {
     description: Option<&'static str>,
     // Input route parameters.
     parameters: Parameters,
+    // Fields of the serial response payload.
+    serial_payload: Parameters,
     // Hazards.
     hazards: Hazards,
+    // Risks overriding the default ones of the hazards.
+    risks: HazardRisks,
 }
 
 impl PartialEq for Route {
@@ -252,6 +267,16 @@ impl Route {
         self
     }
 
+    /// Overrides the default [`HazardRisk`] of a [`Hazard`] for a [`Route`].
+    ///
+    /// The risk is discarded when the hazard is not contained in the route.
+    #[must_use]
+    #[inline]
+    pub fn with_hazard_risk(mut self, hazard: Hazard, risk: HazardRisk) -> Self {
+        self.risks.add(hazard, risk);
+        self
+    }
+
     /// Adds [`Parameters`] to a [`Route`].
     #[must_use]
     #[inline]
@@ -260,6 +285,17 @@ impl Route {
         self
     }
 
+    /// Describes the payload of a serial response through its fields.
+    ///
+    /// Each field is described by a [`ParameterKind`](crate::parameters::ParameterKind),
+    /// whose default value is not meaningful for a payload.
+    #[must_use]
+    #[inline]
+    pub fn with_serial_payload(mut self, serial_payload: Parameters) -> Self {
+        self.serial_payload = serial_payload;
+        self
+    }
+
     /// Returns the route path.
     #[must_use]
     pub const fn route(&self) -> &str {
@@ -278,12 +314,24 @@ impl Route {
         &self.hazards
     }
 
+    /// Returns the [`HazardRisks`] overriding the default ones.
+    #[must_use]
+    pub const fn hazard_risks(&self) -> &HazardRisks {
+        &self.risks
+    }
+
     /// Returns [`Parameters`].
     #[must_use]
     pub const fn parameters(&self) -> &Parameters {
         &self.parameters
     }
 
+    /// Returns the fields of the serial response payload.
+    #[must_use]
+    pub const fn serial_payload(&self) -> &Parameters {
+        &self.serial_payload
+    }
+
     /// Removes prohibited [`Hazard`]s returning an updated [`Route`].
     #[must_use]
     #[inline]
@@ -296,6 +344,7 @@ impl Route {
                 error!("Hazards not allowed, removed: {hazard}");
             }
         }
+        self.risks.retain(&hazards);
         self.hazards = hazards;
         self
     }
@@ -316,7 +365,9 @@ impl Route {
             rest_kind,
             description: None,
             hazards: Hazards::new(),
+            risks: HazardRisks::new(),
             parameters: Parameters::new(),
+            serial_payload: Parameters::new(),
         }
     }
 }
@@ -327,13 +378,385 @@ set! {
   pub struct Routes(IndexSet<Route, DefaultHashBuilder>);
 }
 
-mandatory_route!(LightOnRoute, "/on", methods: [post, put]);
-mandatory_route!(LightOffRoute, "/off", methods: [post, put]);
+/// The path and the allowed methods of a mandatory route.
+///
+/// Every route defined through [`mandatory_route!`](crate::mandatory_route)
+/// exposes this data through its `MANDATORY` associated constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MandatoryRoute {
+    // Path.
+    path: &'static str,
+    // Allowed REST kinds.
+    rest_kinds: &'static [RestKind],
+}
+
+impl MandatoryRoute {
+    /// Creates a [`MandatoryRoute`].
+    #[must_use]
+    pub const fn new(path: &'static str, rest_kinds: &'static [RestKind]) -> Self {
+        Self { path, rest_kinds }
+    }
+
+    /// Returns the route path.
+    #[must_use]
+    pub const fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Returns the allowed [`RestKind`]s.
+    #[must_use]
+    pub const fn rest_kinds(&self) -> &'static [RestKind] {
+        self.rest_kinds
+    }
+
+    /// Checks whether a [`RouteConfig`] implements the [`MandatoryRoute`].
+    #[must_use]
+    pub fn is_implemented_by(&self, route_config: &RouteConfig) -> bool {
+        route_config.data.path == self.path && self.rest_kinds.contains(&route_config.rest_kind)
+    }
+}
+
+/// The mandatory routes and the allowed hazards of a standard device kind.
+///
+/// A device of a standard kind must implement all its mandatory routes, and
+/// its routes can only contain the allowed hazards. Hence, a controller can
+/// operate any device of a known kind through its mandatory routes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceKindRoutes {
+    /// Mandatory routes.
+    pub mandatory_routes: &'static [MandatoryRoute],
+    /// Allowed hazards.
+    pub allowed_hazards: &'static [Hazard],
+}
+
+impl DeviceKindRoutes {
+    /// Checks whether the given [`RouteConfigs`] implement all
+    /// the mandatory routes.
+    #[must_use]
+    pub fn is_implemented_by(&self, route_configs: &RouteConfigs) -> bool {
+        self.mandatory_routes.iter().all(|mandatory_route| {
+            route_configs
+                .iter()
+                .any(|route_config| mandatory_route.is_implemented_by(route_config))
+        })
+    }
+}
+
+mandatory_route!(
+    /// Turns a light on.
+    LightOnRoute,
+    "/on",
+    methods: [post, put]
+);
+mandatory_route!(
+    /// Turns a light off.
+    LightOffRoute,
+    "/off",
+    methods: [post, put]
+);
+
+/// Routes of a light.
+pub const LIGHT_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[LightOnRoute::MANDATORY, LightOffRoute::MANDATORY],
+    allowed_hazards: &[
+        Hazard::FireHazard,
+        Hazard::ElectricEnergyConsumption,
+        Hazard::LogEnergyConsumption,
+    ],
+};
+
+mandatory_route!(
+    /// Turns a switch or a smart plug on.
+    SwitchOnRoute,
+    "/on",
+    methods: [post, put]
+);
+mandatory_route!(
+    /// Turns a switch or a smart plug off.
+    SwitchOffRoute,
+    "/off",
+    methods: [post, put]
+);
+
+/// Routes of a switch or a smart plug.
+pub const SWITCH_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[SwitchOnRoute::MANDATORY, SwitchOffRoute::MANDATORY],
+    allowed_hazards: &[
+        Hazard::ElectricEnergyConsumption,
+        Hazard::FireHazard,
+        Hazard::LogEnergyConsumption,
+        Hazard::LogUsageTime,
+        Hazard::PowerOutage,
+        Hazard::PowerSurge,
+    ],
+};
+
+mandatory_route!(
+    /// Sets the target temperature of a thermostat.
+    ThermostatSetpointRoute,
+    "/setpoint",
+    methods: [post, put]
+);
+mandatory_route!(
+    /// Reads the current temperature.
+    TemperatureRoute,
+    "/temperature",
+    methods: [get]
+);
+
+/// Routes of a thermostat.
+pub const THERMOSTAT_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[
+        ThermostatSetpointRoute::MANDATORY,
+        TemperatureRoute::MANDATORY,
+    ],
+    allowed_hazards: &[
+        Hazard::ElectricEnergyConsumption,
+        Hazard::FireHazard,
+        Hazard::GasConsumption,
+        Hazard::LogEnergyConsumption,
+        Hazard::LogUsageTime,
+        Hazard::RecordUserPreferences,
+    ],
+};
+
+mandatory_route!(
+    /// Reads the current relative humidity.
+    HumidityRoute,
+    "/humidity",
+    methods: [get]
+);
+
+/// Routes of a temperature and humidity sensor.
+pub const TEMPERATURE_HUMIDITY_SENSOR_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[TemperatureRoute::MANDATORY, HumidityRoute::MANDATORY],
+    allowed_hazards: &[
+        Hazard::ElectricEnergyConsumption,
+        Hazard::LogEnergyConsumption,
+    ],
+};
+
+mandatory_route!(
+    /// Reads whether a motion has been detected.
+    MotionRoute,
+    "/motion",
+    methods: [get]
+);
+
+/// Routes of a motion sensor.
+pub const MOTION_SENSOR_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[MotionRoute::MANDATORY],
+    allowed_hazards: &[
+        Hazard::ElectricEnergyConsumption,
+        Hazard::LogEnergyConsumption,
+        Hazard::LogUsageTime,
+    ],
+};
+
+mandatory_route!(
+    /// Takes a picture with a camera.
+    CameraSnapshotRoute,
+    "/snapshot",
+    methods: [get]
+);
+mandatory_route!(
+    /// Streams the video of a camera.
+    CameraStreamRoute,
+    "/stream",
+    methods: [get]
+);
+
+/// Routes of a camera.
+pub const CAMERA_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[CameraSnapshotRoute::MANDATORY, CameraStreamRoute::MANDATORY],
+    allowed_hazards: &[
+        Hazard::AudioVideoDisplay,
+        Hazard::AudioVideoRecordAndStore,
+        Hazard::ElectricEnergyConsumption,
+        Hazard::LogEnergyConsumption,
+        Hazard::TakeDeviceScreenshots,
+        Hazard::TakePictures,
+        Hazard::VideoDisplay,
+        Hazard::VideoRecordAndStore,
+    ],
+};
+
+mandatory_route!(
+    /// Locks a door.
+    LockRoute,
+    "/lock",
+    methods: [post, put]
+);
+mandatory_route!(
+    /// Unlocks a door.
+    UnlockRoute,
+    "/unlock",
+    methods: [post, put]
+);
+
+/// Routes of a door lock.
+pub const DOOR_LOCK_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[LockRoute::MANDATORY, UnlockRoute::MANDATORY],
+    allowed_hazards: &[
+        Hazard::ElectricEnergyConsumption,
+        Hazard::LogUsageTime,
+        Hazard::RecordIssuedCommands,
+        Hazard::UnauthorisedPhysicalAccess,
+    ],
+};
+
+mandatory_route!(
+    /// Opens a blind or a cover.
+    CoverOpenRoute,
+    "/open",
+    methods: [post, put]
+);
+mandatory_route!(
+    /// Closes a blind or a cover.
+    CoverCloseRoute,
+    "/close",
+    methods: [post, put]
+);
+
+/// Routes of a blind or a cover.
+pub const COVER_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[CoverOpenRoute::MANDATORY, CoverCloseRoute::MANDATORY],
+    allowed_hazards: &[
+        Hazard::ElectricEnergyConsumption,
+        Hazard::LogEnergyConsumption,
+        Hazard::RecordUserPreferences,
+        Hazard::UnauthorisedPhysicalAccess,
+    ],
+};
+
+/// Routes of a device of unknown kind.
+///
+/// It has no mandatory routes and all hazards are allowed.
+pub const UNKNOWN_ROUTES: DeviceKindRoutes = DeviceKindRoutes {
+    mandatory_routes: &[],
+    allowed_hazards: ALL_HAZARDS,
+};
+
+/// A route required by a device capability.
+///
+/// Besides its path and methods, a capability route requires some input
+/// parameters, each one identified by its name and [`ParameterShape`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapabilityRoute {
+    // Mandatory route.
+    route: MandatoryRoute,
+    // Required parameters along with their shapes.
+    parameters: &'static [(&'static str, ParameterShape)],
+}
+
+impl CapabilityRoute {
+    /// Creates a [`CapabilityRoute`].
+    #[must_use]
+    pub const fn new(
+        route: MandatoryRoute,
+        parameters: &'static [(&'static str, ParameterShape)],
+    ) -> Self {
+        Self { route, parameters }
+    }
+
+    /// Returns the [`MandatoryRoute`].
+    #[must_use]
+    pub const fn route(&self) -> &MandatoryRoute {
+        &self.route
+    }
+
+    /// Returns the required parameters along with their shapes.
+    #[must_use]
+    pub const fn parameters(&self) -> &'static [(&'static str, ParameterShape)] {
+        self.parameters
+    }
+
+    /// Checks whether a [`RouteConfig`] implements the [`CapabilityRoute`].
+    #[must_use]
+    pub fn is_implemented_by(&self, route_config: &RouteConfig) -> bool {
+        self.route.is_implemented_by(route_config)
+            && self.parameters.iter().all(|(name, shape)| {
+                route_config
+                    .data
+                    .parameters
+                    .get(name)
+                    .is_some_and(|kind| shape.matches(kind))
+            })
+    }
+}
+
+mandatory_route!(
+    /// Turns a device on.
+    TurnOnRoute,
+    "/on",
+    methods: [post, put]
+);
+mandatory_route!(
+    /// Turns a device off.
+    TurnOffRoute,
+    "/off",
+    methods: [post, put]
+);
+
+/// Routes of the on/off capability.
+pub const ON_OFF_ROUTES: &[CapabilityRoute] = &[
+    CapabilityRoute::new(TurnOnRoute::MANDATORY, &[]),
+    CapabilityRoute::new(TurnOffRoute::MANDATORY, &[]),
+];
+
+mandatory_route!(
+    /// Sets the brightness of a device through a numeric
+    /// `brightness` parameter.
+    BrightnessRoute,
+    "/brightness",
+    methods: [post, put]
+);
+
+/// Routes of the dimmable capability.
+pub const DIMMABLE_ROUTES: &[CapabilityRoute] = &[CapabilityRoute::new(
+    BrightnessRoute::MANDATORY,
+    &[("brightness", ParameterShape::Number)],
+)];
+
+mandatory_route!(
+    /// Sets the color of a device through a `color` parameter.
+    ColorRoute,
+    "/color",
+    methods: [post, put]
+);
+
+/// Routes of the color capability.
+pub const COLOR_ROUTES: &[CapabilityRoute] = &[CapabilityRoute::new(
+    ColorRoute::MANDATORY,
+    &[("color", ParameterShape::Color)],
+)];
+
+mandatory_route!(
+    /// Reads the energy consumed by a device.
+    EnergyRoute,
+    "/energy",
+    methods: [get]
+);
+
+/// Routes of the energy metering capability.
+pub const ENERGY_METERING_ROUTES: &[CapabilityRoute] =
+    &[CapabilityRoute::new(EnergyRoute::MANDATORY, &[])];
+
+mandatory_route!(
+    /// Reads the battery level of a device.
+    BatteryRoute,
+    "/battery",
+    methods: [get]
+);
+
+/// Routes of the battery capability.
+pub const BATTERY_ROUTES: &[CapabilityRoute] =
+    &[CapabilityRoute::new(BatteryRoute::MANDATORY, &[])];
 
 #[cfg(test)]
 #[cfg(feature = "deserialize")]
 mod tests {
-    use crate::hazards::{Hazard, Hazards};
+    use crate::hazards::{Hazard, HazardRisk, HazardRisks, Hazards, Likelihood, Severity};
     use crate::parameters::{ParameterKind, Parameters, ParametersData};
     use crate::response::ResponseKind;
     use crate::{deserialize, serialize};
@@ -366,7 +789,9 @@ mod tests {
                 path: "/route".into(),
                 description: Some(desc.into()),
                 hazards,
+                risks: HazardRisks::new(),
                 parameters,
+                serial_payload: ParametersData::new(),
             },
         }
     }
@@ -463,6 +888,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hazard_risks() {
+        let risk = HazardRisk::new(Severity::Low, Likelihood::Rare);
+
+        // Risks of hazards missing from the route are discarded.
+        let route_config = Route::get("Route", "/route")
+            .with_array_of_hazards([Hazard::FireHazard, Hazard::LogUsageTime])
+            .with_hazard_risk(Hazard::FireHazard, risk)
+            .with_hazard_risk(Hazard::SpendMoney, risk)
+            .serialize_data();
+        assert_eq!(
+            route_config.data.risks,
+            HazardRisks::init(Hazard::FireHazard, risk)
+        );
+        assert_eq!(
+            deserialize::<RouteConfig>(serialize(&route_config))
+                .data
+                .risks,
+            route_config.data.risks
+        );
+
+        // Routes without overriding risks do not serialize them.
+        assert!(
+            serialize(
+                Route::get("Route", "/route")
+                    .with_hazard(Hazard::FireHazard)
+                    .serialize_data()
+            )
+            .get("risks")
+            .is_none()
+        );
+    }
+
     #[test]
     fn test_all_parameters() {
         let expected = route_config_parameters(
@@ -476,6 +934,7 @@ mod tests {
                     max: 20,
                     step: 1,
                     default: 5,
+                    unit: None,
                 },
             ),
         );
@@ -494,11 +953,55 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn test_serial_payload() {
+        let route_config = deserialize::<RouteConfig>(serialize(
+            Route::get("Route", "/route")
+                .with_serial_payload(
+                    Parameters::new()
+                        .i16_with_limits("temperature", 0, -40, 80)
+                        .u8_with_limits("humidity", 0, 0, 100),
+                )
+                .serialize_data(),
+        ));
+
+        assert_eq!(
+            route_config.data.serial_payload,
+            ParametersData::new()
+                .insert(
+                    "temperature".into(),
+                    ParameterKind::I16 {
+                        default: 0,
+                        min: -40,
+                        max: 80,
+                        unit: None,
+                    },
+                )
+                .insert(
+                    "humidity".into(),
+                    ParameterKind::U8 {
+                        default: 0,
+                        min: 0,
+                        max: 100,
+                        unit: None,
+                    },
+                )
+        );
+
+        // Routes without a serial payload do not serialize it.
+        assert!(
+            serialize(Route::get("Route", "/route").serialize_data())
+                .get("serial_payload")
+                .is_none()
+        );
+    }
 }
 
 #[cfg(test)]
 #[cfg(not(feature = "deserialize"))]
 mod tests {
+    use crate::hazards::{HazardRisk, Likelihood, Severity};
     use crate::route::{Hazard, Hazards};
 
     use super::Route;
@@ -514,12 +1017,17 @@ mod tests {
                 Hazards::new()
                     .insert(Hazard::FireHazard)
                     .insert(Hazard::AirPoisoning),
+            )
+            .with_hazard_risk(
+                Hazard::AirPoisoning,
+                HazardRisk::new(Severity::High, Likelihood::Rare),
             );
 
         let expected_hazards = Hazards::init(Hazard::FireHazard);
-        assert_eq!(
-            route.remove_prohibited_hazards(ALLOWED_HAZARDS).hazards,
-            expected_hazards
-        );
+        let route = route.remove_prohibited_hazards(ALLOWED_HAZARDS);
+        assert_eq!(route.hazards, expected_hazards);
+
+        // The risks of the removed hazards are removed too.
+        assert!(route.hazard_risks().is_empty());
     }
 }
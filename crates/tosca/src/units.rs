@@ -0,0 +1,205 @@
+use serde::{Serialize, Serializer};
+
+/// A measurement unit.
+///
+/// A unit is serialized as its symbol, so a [`Unit::Custom`] unit whose
+/// symbol matches a standard unit is deserialized as the standard one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(not(feature = "deserialize"), derive(Copy))]
+pub enum Unit {
+    /// Degree Celsius.
+    Celsius,
+    /// Degree Fahrenheit.
+    Fahrenheit,
+    /// Kelvin.
+    Kelvin,
+    /// Percentage.
+    Percent,
+    /// Relative humidity percentage.
+    RelativeHumidity,
+    /// Lux.
+    Lux,
+    /// Watt.
+    Watt,
+    /// Kilowatt.
+    Kilowatt,
+    /// Watt-hour.
+    WattHour,
+    /// Kilowatt-hour.
+    KilowattHour,
+    /// Volt.
+    Volt,
+    /// Ampere.
+    Ampere,
+    /// Hertz.
+    Hertz,
+    /// Pascal.
+    Pascal,
+    /// Hectopascal.
+    Hectopascal,
+    /// Meter.
+    Meter,
+    /// Centimeter.
+    Centimeter,
+    /// Millimeter.
+    Millimeter,
+    /// Second.
+    Second,
+    /// Millisecond.
+    Millisecond,
+    /// Degree of angle.
+    Degree,
+    /// Decibel.
+    Decibel,
+    /// Parts per million.
+    PartsPerMillion,
+    /// Microgram per cubic meter.
+    MicrogramPerCubicMeter,
+    /// A custom unit identified by its symbol.
+    #[cfg(not(feature = "deserialize"))]
+    Custom(&'static str),
+    /// A custom unit identified by its symbol.
+    #[cfg(feature = "deserialize")]
+    Custom(alloc::borrow::Cow<'static, str>),
+}
+
+// All standard units.
+const STANDARD_UNITS: &[Unit] = &[
+    Unit::Celsius,
+    Unit::Fahrenheit,
+    Unit::Kelvin,
+    Unit::Percent,
+    Unit::RelativeHumidity,
+    Unit::Lux,
+    Unit::Watt,
+    Unit::Kilowatt,
+    Unit::WattHour,
+    Unit::KilowattHour,
+    Unit::Volt,
+    Unit::Ampere,
+    Unit::Hertz,
+    Unit::Pascal,
+    Unit::Hectopascal,
+    Unit::Meter,
+    Unit::Centimeter,
+    Unit::Millimeter,
+    Unit::Second,
+    Unit::Millisecond,
+    Unit::Degree,
+    Unit::Decibel,
+    Unit::PartsPerMillion,
+    Unit::MicrogramPerCubicMeter,
+];
+
+impl Unit {
+    /// Creates a [`Unit::Custom`] unit from its symbol.
+    #[must_use]
+    pub const fn custom(symbol: &'static str) -> Self {
+        #[cfg(not(feature = "deserialize"))]
+        {
+            Self::Custom(symbol)
+        }
+        #[cfg(feature = "deserialize")]
+        {
+            Self::Custom(alloc::borrow::Cow::Borrowed(symbol))
+        }
+    }
+
+    /// Returns the [`Unit`] symbol.
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+            Self::Kelvin => "K",
+            Self::Percent => "%",
+            Self::RelativeHumidity => "%RH",
+            Self::Lux => "lx",
+            Self::Watt => "W",
+            Self::Kilowatt => "kW",
+            Self::WattHour => "Wh",
+            Self::KilowattHour => "kWh",
+            Self::Volt => "V",
+            Self::Ampere => "A",
+            Self::Hertz => "Hz",
+            Self::Pascal => "Pa",
+            Self::Hectopascal => "hPa",
+            Self::Meter => "m",
+            Self::Centimeter => "cm",
+            Self::Millimeter => "mm",
+            Self::Second => "s",
+            Self::Millisecond => "ms",
+            Self::Degree => "°",
+            Self::Decibel => "dB",
+            Self::PartsPerMillion => "ppm",
+            Self::MicrogramPerCubicMeter => "µg/m³",
+            Self::Custom(symbol) => symbol,
+        }
+    }
+
+    /// Returns the standard [`Unit`] associated with the given symbol.
+    ///
+    /// If [`None`], the symbol does not identify any standard unit.
+    #[must_use]
+    // Units are `Copy` only when the `deserialize` feature is disabled.
+    #[allow(clippy::cloned_instead_of_copied)]
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        STANDARD_UNITS
+            .iter()
+            .find(|unit| unit.symbol() == symbol)
+            .cloned()
+    }
+}
+
+impl core::fmt::Display for Unit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.symbol().fmt(f)
+    }
+}
+
+impl Serialize for Unit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.symbol())
+    }
+}
+
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for Unit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = alloc::string::String::deserialize(deserializer)?;
+        Ok(Self::from_symbol(&symbol).unwrap_or_else(|| Self::Custom(symbol.into())))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "deserialize")]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{deserialize, serialize};
+
+    use super::{STANDARD_UNITS, Unit};
+
+    #[test]
+    fn test_units() {
+        for unit in STANDARD_UNITS {
+            assert_eq!(serialize(unit), unit.symbol());
+            assert_eq!(deserialize::<Unit>(serialize(unit)), *unit);
+            assert_eq!(Unit::from_symbol(unit.symbol()).as_ref(), Some(unit));
+        }
+
+        assert_eq!(Unit::Celsius.to_string(), "°C");
+        assert_eq!(Unit::MicrogramPerCubicMeter.to_string(), "µg/m³");
+
+        let knot = Unit::custom("kn");
+        assert_eq!(knot.to_string(), "kn");
+        assert_eq!(Unit::from_symbol("kn"), None);
+        assert_eq!(deserialize::<Unit>(serialize(&knot)), knot);
+
+        // A custom unit with a standard symbol becomes the standard unit.
+        assert_eq!(
+            deserialize::<Unit>(serialize(Unit::custom("lx"))),
+            Unit::Lux
+        );
+    }
+}
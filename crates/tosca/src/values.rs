@@ -0,0 +1,504 @@
+use core::str::FromStr;
+use core::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Seconds in a day.
+const DAY_SECONDS: i64 = 86_400;
+// Days between `0000-03-01` and `1970-01-01`.
+const EPOCH_DAYS: i64 = 719_468;
+// Days in a 400 years era.
+const ERA_DAYS: i64 = 146_097;
+
+/// An error occurred while parsing a value from its textual format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseValueError(&'static str);
+
+impl core::fmt::Display for ParseValueError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl core::error::Error for ParseValueError {}
+
+// Implements `Serialize` and `Deserialize` through the textual format.
+macro_rules! text_serde {
+    ($name:ident) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let text = alloc::string::String::deserialize(deserializer)?;
+                text.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// A color expressed in the `HSV` model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hsv {
+    hue: u16,
+    saturation: u8,
+    value: u8,
+}
+
+impl Hsv {
+    /// Creates an [`Hsv`] color.
+    ///
+    /// Returns [`None`] when the hue is greater than `359` degrees, or
+    /// when the saturation or the value are greater than `100` percent.
+    #[must_use]
+    pub const fn new(hue: u16, saturation: u8, value: u8) -> Option<Self> {
+        if hue < 360 && saturation <= 100 && value <= 100 {
+            Some(Self {
+                hue,
+                saturation,
+                value,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the hue in degrees.
+    #[must_use]
+    pub const fn hue(&self) -> u16 {
+        self.hue
+    }
+
+    /// Returns the saturation in percent.
+    #[must_use]
+    pub const fn saturation(&self) -> u8 {
+        self.saturation
+    }
+
+    /// Returns the value in percent.
+    #[must_use]
+    pub const fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+/// A color.
+///
+/// Its textual format is `#rrggbb` for the `RGB` model, and
+/// `hsv(hue,saturation,value)` for the `HSV` model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// A color expressed in the `RGB` model.
+    Rgb {
+        /// Red component.
+        red: u8,
+        /// Green component.
+        green: u8,
+        /// Blue component.
+        blue: u8,
+    },
+    /// A color expressed in the `HSV` model.
+    Hsv(Hsv),
+}
+
+impl Color {
+    /// Creates a [`Color`] in the `RGB` model.
+    #[must_use]
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::Rgb { red, green, blue }
+    }
+
+    /// Creates a [`Color`] in the `HSV` model.
+    ///
+    /// Returns [`None`] when the components are out of range.
+    #[must_use]
+    pub const fn hsv(hue: u16, saturation: u8, value: u8) -> Option<Self> {
+        match Hsv::new(hue, saturation, value) {
+            Some(hsv) => Some(Self::Hsv(hsv)),
+            None => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Rgb { red, green, blue } => write!(f, "#{red:02x}{green:02x}{blue:02x}"),
+            Self::Hsv(hsv) => write!(f, "hsv({},{},{})", hsv.hue, hsv.saturation, hsv.value),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(hex) = s.strip_prefix('#') {
+            if hex.len() != 6 || !hex.is_ascii() {
+                return Err(ParseValueError("expected a color in the `#rrggbb` format"));
+            }
+            let component = |index: usize| {
+                u8::from_str_radix(&hex[index..index + 2], 16)
+                    .map_err(|_| ParseValueError("invalid hexadecimal color component"))
+            };
+            return Ok(Self::rgb(component(0)?, component(2)?, component(4)?));
+        }
+
+        let components = s
+            .strip_prefix("hsv(")
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(ParseValueError(
+                "expected a color in the `#rrggbb` or `hsv(hue,saturation,value)` format",
+            ))?;
+
+        let mut components = components.split(',').map(str::trim);
+        let mut next = || {
+            components
+                .next()
+                .ok_or(ParseValueError("missing `HSV` color component"))
+        };
+        let hue = next()?
+            .parse()
+            .map_err(|_| ParseValueError("invalid hue"))?;
+        let saturation = next()?
+            .parse()
+            .map_err(|_| ParseValueError("invalid saturation"))?;
+        let value = next()?
+            .parse()
+            .map_err(|_| ParseValueError("invalid value"))?;
+        if components.next().is_some() {
+            return Err(ParseValueError("too many `HSV` color components"));
+        }
+
+        Self::hsv(hue, saturation, value).ok_or(ParseValueError("`HSV` component out of range"))
+    }
+}
+
+text_serde!(Color);
+
+/// A wall-clock time of the day.
+///
+/// Its textual format is `HH:MM:SS`, where seconds can be omitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeOfDay {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl TimeOfDay {
+    /// Midnight.
+    pub const MIDNIGHT: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+
+    /// Creates a [`TimeOfDay`].
+    ///
+    /// Returns [`None`] when a component is out of range.
+    #[must_use]
+    pub const fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if hour < 24 && minute < 60 && second < 60 {
+            Some(Self {
+                hour,
+                minute,
+                second,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the hour.
+    #[must_use]
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute.
+    #[must_use]
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second.
+    #[must_use]
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+impl core::fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+impl FromStr for TimeOfDay {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split(':');
+        let mut next = |required: bool| match components.next() {
+            Some(component) if component.len() == 2 => component
+                .parse::<u8>()
+                .map_err(|_| ParseValueError("invalid time component")),
+            None if !required => Ok(0),
+            _ => Err(ParseValueError("expected a time in the `HH:MM:SS` format")),
+        };
+        let hour = next(true)?;
+        let minute = next(true)?;
+        let second = next(false)?;
+        if components.next().is_some() {
+            return Err(ParseValueError("expected a time in the `HH:MM:SS` format"));
+        }
+
+        Self::new(hour, minute, second).ok_or(ParseValueError("time component out of range"))
+    }
+}
+
+text_serde!(TimeOfDay);
+
+/// A point in time, with a resolution of one second.
+///
+/// Its textual format is `RFC 3339` in `UTC`, as in `2024-05-01T12:30:00Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    /// The `UNIX` epoch.
+    pub const UNIX_EPOCH: Self = Self(0);
+
+    /// Creates a [`Timestamp`] from the seconds elapsed since
+    /// the `UNIX` epoch.
+    #[must_use]
+    pub const fn from_unix(seconds: i64) -> Self {
+        Self(seconds)
+    }
+
+    /// Returns the seconds elapsed since the `UNIX` epoch.
+    #[must_use]
+    pub const fn unix(&self) -> i64 {
+        self.0
+    }
+}
+
+// Converts a date into the number of days since the `UNIX` epoch.
+const fn days_from_date(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * ERA_DAYS + day_of_era - EPOCH_DAYS
+}
+
+// Converts the number of days since the `UNIX` epoch into a date.
+const fn date_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + EPOCH_DAYS;
+    let era = days.div_euclid(ERA_DAYS);
+    let day_of_era = days.rem_euclid(ERA_DAYS);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+const fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl core::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (year, month, day) = date_from_days(self.0.div_euclid(DAY_SECONDS));
+        let seconds = self.0.rem_euclid(DAY_SECONDS);
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ParseValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const FORMAT_ERROR: ParseValueError =
+            ParseValueError("expected a timestamp in the `YYYY-MM-DDTHH:MM:SSZ` format");
+
+        let bytes = s.as_bytes();
+        if bytes.len() != 20
+            || !s.is_ascii()
+            || bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || !matches!(bytes[10], b'T' | b't')
+            || bytes[13] != b':'
+            || bytes[16] != b':'
+            || !matches!(bytes[19], b'Z' | b'z')
+        {
+            return Err(FORMAT_ERROR);
+        }
+
+        let number = |start: usize, end: usize| {
+            let digits = &s[start..end];
+            if digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                digits.parse::<i64>().map_err(|_| FORMAT_ERROR)
+            } else {
+                Err(FORMAT_ERROR)
+            }
+        };
+
+        let year = number(0, 4)?;
+        let month = number(5, 7)?;
+        let day = number(8, 10)?;
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return Err(ParseValueError("date out of range"));
+        }
+
+        let time = TimeOfDay::from_str(&s[11..19])?;
+
+        Ok(Self(
+            days_from_date(year, month, day) * DAY_SECONDS
+                + i64::from(time.hour) * 3600
+                + i64::from(time.minute) * 60
+                + i64::from(time.second),
+        ))
+    }
+}
+
+text_serde!(Timestamp);
+
+/// Formats a [`Duration`] in its textual format.
+///
+/// The textual format is an integer number of milliseconds followed by
+/// the `ms` unit, as in `1500ms`.
+#[must_use]
+pub fn format_duration(duration: Duration) -> alloc::string::String {
+    alloc::format!("{}ms", duration.as_millis())
+}
+
+/// Parses a [`Duration`] from its textual format.
+///
+/// The textual format is an integer number followed by one of the
+/// `ms`, `s`, `m` and `h` units, as in `1500ms` or `90s`.
+///
+/// # Errors
+///
+/// Returns an error when the text is not a valid duration.
+pub fn parse_duration(s: &str) -> Result<Duration, ParseValueError> {
+    let split = s
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or(ParseValueError("missing duration unit"))?;
+    let (amount, unit) = s.split_at(split);
+
+    let amount = amount
+        .parse::<u64>()
+        .map_err(|_| ParseValueError("invalid duration amount"))?;
+
+    let seconds = |factor: u64| {
+        amount
+            .checked_mul(factor)
+            .map(Duration::from_secs)
+            .ok_or(ParseValueError("duration too large"))
+    };
+
+    match unit {
+        "ms" => Ok(Duration::from_millis(amount)),
+        "s" => seconds(1),
+        "m" => seconds(60),
+        "h" => seconds(3600),
+        _ => Err(ParseValueError(
+            "expected one of the `ms`, `s`, `m` and `h` duration units",
+        )),
+    }
+}
+
+// Serializes durations through their textual format.
+pub(crate) mod duration_text {
+    use core::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{}ms", duration.as_millis()))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        let text = alloc::string::String::deserialize(deserializer)?;
+        super::parse_duration(&text).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use core::time::Duration;
+
+    use super::{Color, TimeOfDay, Timestamp, format_duration, parse_duration};
+
+    #[test]
+    fn test_colors() {
+        assert_eq!("#ff8000".parse(), Ok(Color::rgb(255, 128, 0)));
+        assert_eq!(Color::rgb(255, 128, 0).to_string(), "#ff8000");
+
+        let hsv = Color::hsv(120, 50, 100).unwrap();
+        assert_eq!("hsv(120, 50, 100)".parse(), Ok(hsv));
+        assert_eq!(hsv.to_string(), "hsv(120,50,100)");
+
+        assert!(Color::hsv(360, 50, 100).is_none());
+        assert!("#ff80".parse::<Color>().is_err());
+        assert!("hsv(120,101,0)".parse::<Color>().is_err());
+        assert!("red".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_times() {
+        assert_eq!("07:30".parse(), Ok(TimeOfDay::new(7, 30, 0).unwrap()));
+        assert_eq!(TimeOfDay::new(23, 59, 1).unwrap().to_string(), "23:59:01");
+        assert!("24:00".parse::<TimeOfDay>().is_err());
+        assert!("7:30".parse::<TimeOfDay>().is_err());
+
+        let timestamp: Timestamp = "2024-02-29T12:30:05Z".parse().unwrap();
+        assert_eq!(timestamp, Timestamp::from_unix(1_709_209_805));
+        assert_eq!(timestamp.to_string(), "2024-02-29T12:30:05Z");
+        assert_eq!(Timestamp::UNIX_EPOCH.to_string(), "1970-01-01T00:00:00Z");
+        assert_eq!(Timestamp::from_unix(-1).to_string(), "1969-12-31T23:59:59Z");
+        assert!("2023-02-29T12:30:05Z".parse::<Timestamp>().is_err());
+        assert!("2024-02-29 12:30:05".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn test_durations() {
+        assert_eq!(parse_duration("1500ms"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+        assert_eq!(format_duration(Duration::from_secs(2)), "2000ms");
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("10d").is_err());
+        assert!(parse_duration("-1s").is_err());
+    }
+}